@@ -0,0 +1,60 @@
+"""
+Python script to generate Rust source code of the Unicode full case folding table
+from the Unicode Character Database bundled with Python (`str.casefold`)
+"""
+
+from sys import stdout, argv
+from pathlib import Path
+import unicodedata
+
+
+def collect_foldings():
+    """
+    Collect characters changed by the full case folding
+
+    Returns a list of `(character, folded string)`
+    """
+    foldings = []
+    for code_point in range(0x110000):
+        if 0xD800 <= code_point <= 0xDFFF:
+            continue
+        c = chr(code_point)
+        folded = c.casefold()
+        if folded != c:
+            foldings.append((c, folded))
+    return foldings
+
+
+def print_table(foldings, f=stdout):
+    """
+    Generate and print the definition of the table
+    """
+    print(
+        f"""\
+//! Definition of the Unicode full case folding table
+//!
+//! Generated based on the Unicode Character Database {unicodedata.unidata_version}
+
+/// Characters changed by the full case folding (C + F in `CaseFolding.txt`): (character, folded)
+///
+/// Sorted by characters so that it can be binary-searched.
+pub static CASE_FOLDING_TABLE: &[(char, &str)] = &[""",
+        file=f,
+    )
+    for c, folded in foldings:
+        print(
+            f"    ('\\u{{{ord(c):04X}}}', \""
+            + "".join(f"\\u{{{ord(x):04X}}}" for x in folded)
+            + '"),',
+            file=f,
+        )
+    print("];", file=f)
+
+
+if __name__ == "__main__":
+    src_dir = Path(argv[0]).parent / "src"
+    src_dir.mkdir(exist_ok=True)
+    with (src_dir / "case_folding_table.rs").open(
+        "w", encoding="utf-8", newline="\n"
+    ) as f:
+        print_table(collect_foldings(), f)
@@ -0,0 +1,84 @@
+"""
+Python script to generate Rust source code of the Unicode canonical combining class table
+from the Unicode Character Database bundled with Python (`unicodedata`)
+"""
+
+from sys import stdout, argv
+from pathlib import Path
+import unicodedata
+from generate_table import print_buckets
+
+
+def collect_classes(database=unicodedata):
+    """
+    Collect code points with non-zero canonical combining classes in `database`
+
+    Returns a list of `(char, class)` sorted by the code points
+    """
+    classes = []
+    for code_point in range(0x110000):
+        combining_class = database.combining(chr(code_point))
+        if combining_class != 0:
+            classes.append((chr(code_point), combining_class))
+    return classes
+
+
+def print_header(f=stdout):
+    """
+    Print the header of the file
+    """
+    print(
+        f"""\
+//! Definition of the Unicode canonical combining class tables
+//!
+//! Generated based on the Unicode Character Database {unicodedata.unidata_version} and \
+{unicodedata.ucd_3_2_0.unidata_version}
+
+use super::reverse_tree::CharMap;""",
+        file=f,
+    )
+
+
+def print_table(name, doc, classes, f=stdout):
+    """
+    Generate and print the definition of the table
+    """
+    print(
+        f"""
+{doc}
+pub static {name}: CharMap<u8> = CharMap {{
+    entries: &[""",
+        file=f,
+    )
+    for c, combining_class in classes:
+        print(f"        ('\\u{{{ord(c):04X}}}', {combining_class}),", file=f)
+    print("    ],", file=f)
+    print_buckets([c for c, _ in classes], f)
+    print("};", file=f)
+
+
+if __name__ == "__main__":
+    src_dir = Path(argv[0]).parent / "src"
+    src_dir.mkdir(exist_ok=True)
+    with (src_dir / "combining_class_table.rs").open(
+        "w", encoding="utf-8", newline="\n"
+    ) as f:
+        print_header(f)
+        print_table(
+            "COMBINING_CLASS_TABLE",
+            """\
+/// Map from code points to their non-zero canonical combining classes
+///
+/// Most code points (e.g. kana and CJK ideographs) fall in empty buckets, so they are looked up without searching.""",
+            collect_classes(),
+            f,
+        )
+        print_table(
+            "COMBINING_CLASS_TABLE_UNICODE32",
+            """\
+/// Map from code points to their non-zero canonical combining classes in Unicode 3.2
+///
+/// The classes of the marks assigned in Unicode 3.2 have not changed since, but the later ones are 0 here.""",
+            collect_classes(unicodedata.ucd_3_2_0),
+            f,
+        )
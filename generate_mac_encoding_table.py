@@ -0,0 +1,84 @@
+"""
+Python script to generate Rust source code of the legacy Mac OS text encoding tables
+from the codecs bundled with Python
+"""
+
+from sys import stdout, argv, version_info
+from pathlib import Path
+
+# (Rust name, Python codec, description)
+SINGLE_BYTE_ENCODINGS = [
+    ("MAC_ROMAN", "mac_roman", "MacRoman"),
+    ("MAC_GREEK", "mac_greek", "MacGreek"),
+    ("MAC_CYRILLIC", "mac_cyrillic", "MacCyrillic (including MacUkrainian)"),
+    ("MAC_CENTRAL_EUR_ROMAN", "mac_latin2", "MacCentralEurRoman"),
+    ("MAC_TURKISH", "mac_turkish", "MacTurkish"),
+    ("MAC_CROATIAN", "mac_croatian", "MacCroatian"),
+    ("MAC_ICELANDIC", "mac_iceland", "MacIcelandic"),
+    ("MAC_ROMANIAN", "mac_romanian", "MacRomanian"),
+]
+
+
+def print_pre(f=stdout):
+    """
+    Generate and print the code before the definition of tables
+    """
+    print(
+        f"""\
+//! Definition of the legacy Mac OS text encoding tables
+//!
+//! Generated based on the codecs of Python {version_info.major}.{version_info.minor}""",
+        file=f,
+    )
+
+
+def print_single_byte_table(name, codec, description, f=stdout):
+    """
+    Generate and print the definition of the upper half of a single-byte encoding
+    """
+    for code in range(0x80):
+        assert bytes([code]).decode(codec) == chr(code)
+    print(
+        f"""
+/// Characters of the bytes 0x80-0xFF in {description}
+pub static {name}: [char; 128] = [""",
+        file=f,
+    )
+    for code in range(0x80, 0x100):
+        c = bytes([code]).decode(codec)
+        print(f"    '\\u{{{ord(c):04X}}}', // 0x{code:02X}", file=f)
+    print("];", file=f)
+
+
+def print_japanese_table(f=stdout):
+    """
+    Generate and print the definition of the double-byte characters of MacJapanese
+    """
+    print(
+        """
+/// Double-byte characters of MacJapanese (JIS X 0208 encoded in Shift_JIS): (code, character)
+///
+/// Sorted by codes so that it can be binary-searched.
+pub static MAC_JAPANESE_DOUBLE_BYTE: &[(u16, char)] = &[""",
+        file=f,
+    )
+    for lead in list(range(0x81, 0xA0)) + list(range(0xE0, 0xFD)):
+        for trail in range(0x40, 0xFD):
+            try:
+                c = bytes([lead, trail]).decode("shift_jis")
+            except UnicodeDecodeError:
+                continue
+            print(f"    (0x{lead:02X}{trail:02X}, '\\u{{{ord(c):04X}}}'),", file=f)
+    print("];", file=f)
+
+
+if __name__ == "__main__":
+    src_dir = Path(argv[0]).parent / "src"
+    src_dir.mkdir(exist_ok=True)
+    with (src_dir / "mac_encoding_table.rs").open(
+        "w", encoding="utf-8", newline="\n"
+    ) as f:
+        print_pre(f)
+        for name, codec, description in SINGLE_BYTE_ENCODINGS:
+            print_single_byte_table(name, codec, description, f)
+        print_japanese_table(f)
@@ -0,0 +1,86 @@
+"""
+Python script to generate Rust source code of the decomposition dictionaries of the Unicode-based normalization profiles
+from the Unicode Character Database bundled with Python (`unicodedata`)
+
+The dictionaries have the same layout as `src/code_table.rs` generated by `generate_table.py`.
+"""
+
+from sys import stdout, argv
+from pathlib import Path
+import unicodedata
+from generate_table import print_encoding_dic, print_decoding_dic
+
+
+def is_excluded(code_point):
+    """
+    Whether a character is left undecomposed in HFS+ and APFS
+    """
+    return (
+        0x2000 <= code_point <= 0x2FFF
+        or 0xF900 <= code_point <= 0xFAFF
+        or 0x2F800 <= code_point <= 0x2FAFF
+    )
+
+
+def build_tables(ucd):
+    """
+    Build the encoding (decomposition) and decoding (composition) dictionaries from a Unicode Character Database
+
+    Hangul syllables are excluded because they are decomposed algorithmically.
+    If two or more characters are decomposed into the same sequence, it is composed into the one NFC prefers.
+    Characters decomposed into a single character are not composed.
+    """
+    encoding = {}
+    for code_point in range(0x110000):
+        if 0xAC00 <= code_point <= 0xD7A3 or is_excluded(code_point):
+            continue
+        c = chr(code_point)
+        decomposed = ucd.normalize("NFD", c)
+        if decomposed != c:
+            encoding[c] = decomposed
+
+    candidates = {}
+    for c, decomposed in encoding.items():
+        if len(decomposed) >= 2:
+            candidates.setdefault(decomposed, []).append(c)
+    decoding = {}
+    for decomposed, chars in candidates.items():
+        composed = ucd.normalize("NFC", decomposed)
+        if composed not in chars:
+            composed = min(chars)
+        node = decoding
+        for index, c in enumerate(decomposed):
+            entry = node.setdefault(c, {"current": None, "next": {}})
+            if index == len(decomposed) - 1:
+                entry["current"] = composed
+            node = entry["next"]
+    return encoding, decoding
+
+
+def print_pre(version, f=stdout):
+    """
+    Generate and print the code before the definition of dictionaries
+    """
+    print(
+        f"""\
+//! Definition of Unicode decomposition dictionaries
+//!
+//! Generated based on the Unicode Character Database {version}
+
+use super::reverse_tree::{{CharMap, ReverseTreeNode}};""",
+        file=f,
+    )
+
+
+if __name__ == "__main__":
+    src_dir = Path(argv[0]).parent / "src"
+    src_dir.mkdir(exist_ok=True)
+    for module, ucd in [
+        ("code_table_unicode32", unicodedata.ucd_3_2_0),
+        ("code_table_unicode_latest", unicodedata),
+    ]:
+        encoding, decoding = build_tables(ucd)
+        with (src_dir / f"{module}.rs").open("w", encoding="utf-8", newline="\n") as f:
+            print_pre(ucd.unidata_version, f)
+            print_encoding_dic(encoding, f)
+            print_decoding_dic(decoding, f)
@@ -19,20 +19,34 @@ def print_pre(timestamp: str, f=stdout):
 //! Generated based on https://developer.apple.com/library/archive/technotes/tn/tn1150table.html
 //! fetched at {timestamp}
 
-use super::reverse_tree::ReverseTreeNode;
-use ahash::AHashMap;
-use lazy_static::lazy_static;
-lazy_static! """
-        "{",
+use super::reverse_tree::{{CharMap, ReverseTreeNode}};""",
         file=f,
     )
 
 
-def print_post(f=stdout):
+# Must be the same as `BUCKET_SHIFT` in `src/reverse_tree.rs`
+BUCKET_SHIFT = 5
+
+
+def _char_literal(c):
+    return f"'\\u{{{ord(c):04X}}}'"
+
+
+def print_buckets(keys, f=stdout):
     """
-    Generate and print the code after the definition of dictionaries
+    Generate and print the index of the buckets of the sorted keys of a root dictionary
     """
-    print("}", file=f)
+    last_bucket = ord(keys[-1]) >> BUCKET_SHIFT
+    buckets = []
+    index = 0
+    for bucket in range(last_bucket + 2):
+        while index < len(keys) and ord(keys[index]) >> BUCKET_SHIFT < bucket:
+            index += 1
+        buckets.append(index)
+    print("    buckets: &[", file=f)
+    for start in range(0, len(buckets), 16):
+        print("        " + " ".join(f"{b}," for b in buckets[start : start + 16]), file=f)
+    print("    ],", file=f)
 
 
 def print_encoding_dic(obj, f=stdout):
@@ -40,78 +54,112 @@ def print_encoding_dic(obj, f=stdout):
     Generate and print the definition of encoding dictionary
     """
     print(
-        """\
-    /// map from composed character (normal) to decomposed components (HFS+)
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// assert_eq!((*MAP_TO_HFS).get(&'\\u{00E9}').unwrap(), "e\\u{0301}");
-    /// ```
-    pub static ref MAP_TO_HFS: AHashMap<char, &'static str> = {
-        let mut map = AHashMap::new();""",
+        """
+/// map from composed character (normal) to decomposed components (HFS+)
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(MAP_TO_HFS.get(&'\\u{00E9}').unwrap(), &"e\\u{0301}");
+/// ```
+pub static MAP_TO_HFS: CharMap<&str> = CharMap {
+    entries: &[""",
         file=f,
     )
-    for (compose, decompose) in obj.items():
+    items = sorted(obj.items(), key=lambda item: ord(item[0]))
+    for compose, decompose in items:
         print(
-            f"        map.insert('\\u{{{ord(compose):04X}}}', \""
+            f"        ({_char_literal(compose)}, \""
             + "".join((f"\\u{{{ord(c):04X}}}" for c in decompose))
-            + '");',
+            + '"),',
             file=f,
         )
-    print("        return map;\n    };", file=f)
+    print("    ],", file=f)
+    print_buckets([compose for compose, _ in items], f)
+    print("};", file=f)
 
 
-def _print_de_(dic, var_name, f=stdout):
+def _print_de_(dic, name, f=stdout):
     """
     Body of generator of decoding table
 
-    Use recursion because the depth of recursive calls is limited.
+    Subdictionaries are defined as constants before the dictionaries referencing them.
     """
-    print(f"        let mut {var_name} = AHashMap::new();", file=f)
-    for char, result_obj in dic.items():
-        char_hexcode = f"{ord(char):04x}"
+    entries = []
+    items = sorted(dic.items(), key=lambda item: ord(item[0]))
+    for char, result_obj in items:
         current = (
-            f"""Some('\\u{{{ord(result_obj["current"]):04X}}}')"""
+            f"Some({_char_literal(result_obj['current'])})"
             if ("current" in result_obj and result_obj["current"])
             else "None"
         )
         if result_obj["next"]:
-            new_var_name = (
-                f"u{char_hexcode}"
-                if var_name == "root"
-                else f"{var_name}_{char_hexcode}"
-            )
-            _print_de_(result_obj["next"], new_var_name, f)
-            print(
-                f"        {var_name}.insert('\\u{{{char_hexcode.upper()}}}', ReverseTreeNode::new({current}, Some(Box::new({new_var_name}))));",
-                file=f,
-            )
+            char_hexcode = f"{ord(char):04X}"
+            sub_name = f"U{char_hexcode}" if name == "MAP_TO_NORMAL" else f"{name}_{char_hexcode}"
+            _print_de_(result_obj["next"], sub_name, f)
+            next_dic = f"Some({sub_name})"
         else:
-            print(
-                f"        {var_name}.insert('\\u{{{char_hexcode.upper()}}}', ReverseTreeNode::new({current}, None));",
-                file=f,
-            )
+            next_dic = "None"
+        entries.append(
+            f"        ({_char_literal(char)}, ReverseTreeNode::new({current}, {next_dic})),"
+        )
+    if name == "MAP_TO_NORMAL":
+        print(
+            """
+/// Dictionary (map) from decomposed components to sub dictionaries and composed characters
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(MAP_TO_NORMAL.get(&'e').unwrap().next.unwrap().get(&'\\u{0301}').unwrap().current.unwrap(), '\\u{00E9}');
+/// ```
+pub static MAP_TO_NORMAL: CharMap<ReverseTreeNode> = CharMap {""",
+            file=f,
+        )
+    else:
+        print(f"\nconst {name}: CharMap<ReverseTreeNode> = CharMap {{", file=f)
+    print("    entries: &[", file=f)
+    for entry in entries:
+        print(entry, file=f)
+    print("    ],", file=f)
+    if name == "MAP_TO_NORMAL":
+        print_buckets([char for char, _ in items], f)
+    else:
+        print("    buckets: &[],", file=f)
+    print("};", file=f)
 
 
 def print_decoding_dic(dic, f=stdout):
     """
     Generate and print the definition of decoding dictionary
     """
+    _print_de_(dic, "MAP_TO_NORMAL", f)
+
+
+def print_trailing_components(dic, f=stdout):
+    """
+    Generate and print the definition of the set of the characters following the first one in the decompositions
+    """
+    components = set()
+
+    def collect(dic):
+        for char, result_obj in dic.items():
+            components.add(char)
+            collect(result_obj["next"] or {})
+
+    for result_obj in dic.values():
+        collect(result_obj["next"] or {})
     print(
-        """\
-    /// Dictionary (map) from decomposed components to sub dictionaries and composed characters
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// assert_eq!((*MAP_TO_NORMAL).get(&'e').unwrap().next.unwrap().get(&'\\u{0301}').unwrap().current.unwrap(), '\\u{00E9}');
-    /// ```
-    pub static ref MAP_TO_NORMAL: AHashMap<char, ReverseTreeNode> = {""",
+        """
+/// Characters following the first one in the decompositions (i.e. combining marks)
+///
+/// Sorted so that it can be binary-searched.
+pub static TRAILING_COMPONENTS: &[char] = &[""",
         file=f,
     )
-    _print_de_(dic, "root", f)
-    print("        return root;\n    };", file=f)
+    for char in sorted(components, key=ord):
+        print(f"    {_char_literal(char)},", file=f)
+    print("];", file=f)
 
 
 if __name__ == "__main__":
@@ -126,4 +174,4 @@ if __name__ == "__main__":
         print_pre(hfs_table["created"], f)
         print_encoding_dic(hfs_table["encoding"], f)
         print_decoding_dic(hfs_table["decoding"], f)
-        print_post(f)
+        print_trailing_components(hfs_table["decoding"], f)
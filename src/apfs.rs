@@ -0,0 +1,235 @@
+//! Hash of names in APFS directory records (`j_drec_hashed_key_t`) of normalization-insensitive volumes
+//!
+//! The hash is the CRC-32C of the name normalized in NFD (and case-folded on case-insensitive volumes) and encoded in
+//! UTF-32LE, without the final complement, truncated to 22 bits.
+
+use super::case_folding_table::CASE_FOLDING_TABLE;
+use super::decomposer::Decomposer;
+use super::profile::NormalizationProfile;
+use core::fmt::{self, Write};
+
+/// Mask of the name length in `name_len_and_hash`
+const NAME_LEN_MASK: u32 = 0x0000_03FF;
+/// Mask of the hash after shifted out of `name_len_and_hash`
+const HASH_MASK: u32 = 0x003F_FFFF;
+/// Bit position of the hash in `name_len_and_hash`
+const HASH_SHIFT: u32 = 10;
+
+/// Lookup table of CRC-32C (Castagnoli, reflected polynomial 0x82F63B78)
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0x82F6_3B78
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+};
+
+/// CRC-32C of the UTF-32LE encoding of the written characters, without the final complement
+struct Crc32cWriter(u32);
+
+impl Crc32cWriter {
+    fn new() -> Self {
+        Self(0xFFFF_FFFF)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 >> 8) ^ CRC32C_TABLE[((self.0 ^ byte as u32) & 0xFF) as usize];
+        }
+    }
+}
+
+impl Write for Crc32cWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.update(&(c as u32).to_le_bytes());
+        }
+        Ok(())
+    }
+}
+
+/// Returns the full case folding of a character, if changed
+fn case_fold_char(c: char) -> Option<&'static str> {
+    CASE_FOLDING_TABLE
+        .binary_search_by_key(&c, |&(c, _)| c)
+        .ok()
+        .map(|index| CASE_FOLDING_TABLE[index].1)
+}
+
+/// Computes the 22-bit hash of a name in directory records of normalization-insensitive APFS volumes
+///
+/// The name is decomposed according to [`NormalizationProfile::UnicodeLatest`].
+///
+/// # Arguments
+///
+/// * `name` - A file name
+/// * `case_insensitive` - Whether the volume is case-insensitive, where names are case-folded before hashed
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::apfs_name_hash;
+/// assert_eq!(apfs_name_hash("Pok\u{00E9}mon", false), apfs_name_hash("Poke\u{0301}mon", false));
+/// assert_eq!(apfs_name_hash("Pok\u{00E9}mon", true), apfs_name_hash("POK\u{00C9}MON", true));
+/// assert!(apfs_name_hash("Pok\u{00E9}mon", false) < 1 << 22);
+/// ```
+pub fn apfs_name_hash(name: &str, case_insensitive: bool) -> u32 {
+    apfs_name_hash_with_profile(name, case_insensitive, NormalizationProfile::UnicodeLatest)
+}
+
+/// Computes the 22-bit hash of a name in directory records, decomposing the name according to `profile`
+///
+/// APFS itself always uses [`NormalizationProfile::UnicodeLatest`]. The other profiles are for the hashes computed by
+/// older implementations.
+///
+/// # Arguments
+///
+/// * `name` - A file name
+/// * `case_insensitive` - Whether the volume is case-insensitive, where names are case-folded before hashed
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{apfs_name_hash, apfs_name_hash_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     apfs_name_hash_with_profile("\u{1B06}", false, NormalizationProfile::UnicodeLatest),
+///     apfs_name_hash("\u{1B05}\u{1B35}", false)
+/// );
+/// assert_ne!(
+///     apfs_name_hash_with_profile("\u{1B06}", false, NormalizationProfile::Unicode32),
+///     apfs_name_hash("\u{1B05}\u{1B35}", false)
+/// );
+/// ```
+pub fn apfs_name_hash_with_profile(
+    name: &str,
+    case_insensitive: bool,
+    profile: NormalizationProfile,
+) -> u32 {
+    let mut crc = Crc32cWriter::new();
+    let mut decomposer = Decomposer::with_profile(profile);
+    for c in name.chars() {
+        match case_fold_char(c).filter(|_| case_insensitive) {
+            Some(folded) => {
+                for c in folded.chars() {
+                    decomposer.push(c, &mut crc).expect("hashing never fails");
+                }
+            }
+            None => decomposer.push(c, &mut crc).expect("hashing never fails"),
+        }
+    }
+    decomposer.finish(&mut crc).expect("hashing never fails");
+    crc.0 & HASH_MASK
+}
+
+/// Computes the `name_len_and_hash` field of `j_drec_hashed_key_t`, used as the key of directory records of
+/// normalization-insensitive APFS volumes
+///
+/// The lower 10 bits are the length of the UTF-8 name in bytes including the terminating NUL, and the upper 22 bits are
+/// [`apfs_name_hash`]. Returns `None` if the length does not fit in the 10 bits, i.e. the name is longer than 1022
+/// bytes.
+///
+/// # Arguments
+///
+/// * `name` - A file name
+/// * `case_insensitive` - Whether the volume is case-insensitive
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{apfs_name_hash, apfs_name_len_and_hash};
+/// let value = apfs_name_len_and_hash("Pok\u{00E9}mon", true).unwrap();
+/// assert_eq!(value & 0x3FF, 9);
+/// assert_eq!(value >> 10, apfs_name_hash("Pok\u{00E9}mon", true));
+/// assert_eq!(apfs_name_len_and_hash(&"a".repeat(1023), true), None);
+/// ```
+pub fn apfs_name_len_and_hash(name: &str, case_insensitive: bool) -> Option<u32> {
+    apfs_name_len_and_hash_with_profile(name, case_insensitive, NormalizationProfile::UnicodeLatest)
+}
+
+/// Computes the `name_len_and_hash` field of `j_drec_hashed_key_t` with the hash of
+/// [`apfs_name_hash_with_profile`]
+///
+/// Returns `None` if the name is longer than 1022 bytes.
+///
+/// # Arguments
+///
+/// * `name` - A file name
+/// * `case_insensitive` - Whether the volume is case-insensitive
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{apfs_name_hash_with_profile, apfs_name_len_and_hash_with_profile, NormalizationProfile};
+/// let profile = NormalizationProfile::Unicode32;
+/// let value = apfs_name_len_and_hash_with_profile("\u{1B06}", false, profile).unwrap();
+/// assert_eq!(value & 0x3FF, 4);
+/// assert_eq!(value >> 10, apfs_name_hash_with_profile("\u{1B06}", false, profile));
+/// ```
+pub fn apfs_name_len_and_hash_with_profile(
+    name: &str,
+    case_insensitive: bool,
+    profile: NormalizationProfile,
+) -> Option<u32> {
+    if name.len() >= NAME_LEN_MASK as usize {
+        return None;
+    }
+    let len = name.len() as u32 + 1;
+    Some((apfs_name_hash_with_profile(name, case_insensitive, profile) << HASH_SHIFT) | len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn crc32c_test() {
+        // The check value of CRC-32C
+        let mut crc = Crc32cWriter::new();
+        crc.update(b"123456789");
+        assert_eq!(!crc.0, 0xE306_9283);
+    }
+
+    #[test]
+    fn apfs_hash_test() {
+        let mut crc = Crc32cWriter::new();
+        crc.update(&[b'a', 0, 0, 0, 0x01, 0x03, 0, 0]);
+        assert_eq!(apfs_name_hash("\u{00E1}", false), crc.0 & HASH_MASK);
+
+        let equivalents = ["Stra\u{00DF}e", "STRASSE", "strasse", "Strasse"];
+        for name in &equivalents {
+            assert_eq!(apfs_name_hash(name, true), apfs_name_hash("strasse", true));
+        }
+        assert_ne!(
+            apfs_name_hash("STRASSE", false),
+            apfs_name_hash("strasse", false)
+        );
+        assert_eq!(
+            apfs_name_hash("\u{1E69}", false),
+            apfs_name_hash("s\u{0307}\u{0323}", false)
+        );
+        assert_eq!(
+            apfs_name_hash("\u{1E68}", true),
+            apfs_name_hash("s\u{0323}\u{0307}", true)
+        );
+        let longest_name = "a".repeat(1022);
+        assert_eq!(
+            apfs_name_len_and_hash(&longest_name, false),
+            Some((apfs_name_hash(&longest_name, false) << HASH_SHIFT) | NAME_LEN_MASK)
+        );
+        assert_eq!(apfs_name_len_and_hash(&(longest_name + "a"), false), None);
+    }
+}
@@ -0,0 +1,364 @@
+//! Command-line tool converting text and file names with the Unicode normalization similar to NFD used in HFS+
+
+use hfs_nfd::{compose_bytes, decompose_bytes, Direction, TreeRenamer};
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process;
+
+const USAGE: &str = "\
+Usage: hfs-nfd (--decompose | --compose) [OPTIONS] [FILE]...
+       hfs-nfd (--decompose | --compose) [OPTIONS] --args [TEXT]...
+       hfs-nfd (--decompose | --compose) [OPTIONS] --rename DIRECTORY...
+
+Converts FILEs (or the standard input if none or `-` is given) and writes the result to the standard output.
+With --rename, renames the files and directories under each DIRECTORY instead, and writes the log.
+
+Options:
+  -d, --decompose  Apply the decomposition used in HFS+ (e.g. names to be put on a Mac)
+  -c, --compose    Restore the commonly encoded form (e.g. names copied from a Mac)
+  -0, --null       Records are delimited by NUL instead of newline (e.g. the output of `find -print0`)
+  -a, --args       Convert the arguments themselves instead of reading files
+  -r, --rename     Rename the entries under the directories recursively
+  -n, --dry-run    With --rename, only show what would be renamed
+  -h, --help       Print this help
+  -V, --version    Print the version
+
+Bytes that are not valid UTF-8 are passed through untouched.";
+
+fn convert(direction: Direction, input: &[u8]) -> Cow<'_, [u8]> {
+    match direction {
+        Direction::Decompose => decompose_bytes(input),
+        Direction::Compose => compose_bytes(input),
+    }
+}
+
+/// What the operands are
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    /// Files whose contents are converted
+    Files,
+    /// Texts to be converted
+    Args,
+    /// Directories whose descendants are renamed
+    Rename { dry_run: bool },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    direction: Direction,
+    /// Byte terminating each record
+    delimiter: u8,
+    target: Target,
+    operands: Vec<OsString>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Convert(Options),
+    Help,
+    Version,
+}
+
+fn parse_args<I: IntoIterator<Item = OsString>>(args: I) -> Result<Command, String> {
+    let mut direction = None;
+    let mut delimiter = b'\n';
+    let mut args_as_text = false;
+    let mut rename = false;
+    let mut dry_run = false;
+    let mut operands = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let flags = match arg.to_str() {
+            Some("--") => {
+                operands.extend(args.by_ref());
+                break;
+            }
+            Some(long) if long.starts_with("--") => vec![match long {
+                "--decompose" => 'd',
+                "--compose" => 'c',
+                "--null" => '0',
+                "--args" => 'a',
+                "--rename" => 'r',
+                "--dry-run" => 'n',
+                "--help" => 'h',
+                "--version" => 'V',
+                _ => return Err(format!("unknown option `{}`", long)),
+            }],
+            Some(short) if short.starts_with('-') && short.len() > 1 => {
+                short[1..].chars().collect()
+            }
+            _ => {
+                operands.push(arg);
+                continue;
+            }
+        };
+        for flag in flags {
+            match flag {
+                'd' | 'c' => {
+                    let new_direction = if flag == 'd' {
+                        Direction::Decompose
+                    } else {
+                        Direction::Compose
+                    };
+                    if matches!(direction, Some(direction) if direction != new_direction) {
+                        return Err("--decompose and --compose cannot be used together".to_string());
+                    }
+                    direction = Some(new_direction);
+                }
+                '0' => delimiter = b'\0',
+                'a' => args_as_text = true,
+                'r' => rename = true,
+                'n' => dry_run = true,
+                'h' => return Ok(Command::Help),
+                'V' => return Ok(Command::Version),
+                _ => return Err(format!("unknown option `-{}`", flag)),
+            }
+        }
+    }
+    let direction =
+        direction.ok_or_else(|| "either --decompose or --compose is required".to_string())?;
+    let target = match (args_as_text, rename) {
+        (true, true) => return Err("--args and --rename cannot be used together".to_string()),
+        (true, false) => Target::Args,
+        (false, true) => Target::Rename { dry_run },
+        (false, false) => Target::Files,
+    };
+    if dry_run && !rename {
+        return Err("--dry-run requires --rename".to_string());
+    }
+    if rename && operands.is_empty() {
+        return Err("--rename requires at least one DIRECTORY".to_string());
+    }
+    Ok(Command::Convert(Options {
+        direction,
+        delimiter,
+        target,
+        operands,
+    }))
+}
+
+/// Converts each record in `input` and writes it to `output` keeping the delimiters
+fn convert_stream<R: BufRead, W: Write>(
+    direction: Direction,
+    delimiter: u8,
+    mut input: R,
+    output: &mut W,
+) -> io::Result<()> {
+    let mut record = Vec::new();
+    loop {
+        record.clear();
+        if input.read_until(delimiter, &mut record)? == 0 {
+            return Ok(());
+        }
+        output.write_all(&convert(direction, &record))?;
+    }
+}
+
+#[cfg(unix)]
+fn os_str_bytes(s: &OsStr) -> Cow<'_, [u8]> {
+    use std::os::unix::ffi::OsStrExt;
+    Cow::Borrowed(s.as_bytes())
+}
+
+#[cfg(not(unix))]
+fn os_str_bytes(s: &OsStr) -> Cow<'_, [u8]> {
+    match s.to_string_lossy() {
+        Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+        Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+    }
+}
+
+/// Renames the entries under the directories and writes the log, returning whether all of them succeeded
+///
+/// A directory that cannot be read is reported to the standard error, and the rest are still renamed.
+fn rename_trees<W: Write>(options: &Options, dry_run: bool, output: &mut W) -> io::Result<bool> {
+    let renamer = TreeRenamer::new(options.direction).dry_run(dry_run);
+    let mut succeeded = true;
+    for dir in &options.operands {
+        let records = match renamer.rename(dir) {
+            Ok(records) => records,
+            Err(e) => {
+                eprintln!("hfs-nfd: {}: {}", dir.to_string_lossy(), e);
+                succeeded = false;
+                continue;
+            }
+        };
+        for record in records {
+            succeeded &= record.is_success();
+            writeln!(output, "{}", record)?;
+        }
+    }
+    Ok(succeeded)
+}
+
+fn run<W: Write>(options: &Options, output: &mut W) -> io::Result<bool> {
+    match options.target {
+        Target::Files => {}
+        Target::Args => {
+            for text in &options.operands {
+                output.write_all(&convert(options.direction, &os_str_bytes(text)))?;
+                output.write_all(&[options.delimiter])?;
+            }
+            return Ok(true);
+        }
+        Target::Rename { dry_run } => return rename_trees(options, dry_run, output),
+    }
+    if options.operands.is_empty() {
+        let stdin = io::stdin();
+        convert_stream(options.direction, options.delimiter, stdin.lock(), output)?;
+        return Ok(true);
+    }
+    for path in &options.operands {
+        if path == "-" {
+            let stdin = io::stdin();
+            convert_stream(options.direction, options.delimiter, stdin.lock(), output)?;
+            continue;
+        }
+        let file = File::open(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.to_string_lossy(), e)))?;
+        convert_stream(
+            options.direction,
+            options.delimiter,
+            BufReader::new(file),
+            output,
+        )?;
+    }
+    Ok(true)
+}
+
+fn main() {
+    let options = match parse_args(std::env::args_os().skip(1)) {
+        Ok(Command::Convert(options)) => options,
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            return;
+        }
+        Ok(Command::Version) => {
+            println!("hfs-nfd {}", env!("CARGO_PKG_VERSION"));
+            return;
+        }
+        Err(message) => {
+            eprintln!("hfs-nfd: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    match run(&options, &mut output).and_then(|succeeded| output.flush().map(|_| succeeded)) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(e) => {
+            eprintln!("hfs-nfd: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(OsString::from))
+    }
+
+    #[test]
+    fn parse_args_test() {
+        assert_eq!(
+            parse(&["-c0", "a.txt", "--", "-b.txt"]),
+            Ok(Command::Convert(Options {
+                direction: Direction::Compose,
+                delimiter: b'\0',
+                target: Target::Files,
+                operands: vec!["a.txt".into(), "-b.txt".into()],
+            }))
+        );
+        assert_eq!(
+            parse(&["--args", "Pok\u{00E9}mon", "--decompose", "-"]),
+            Ok(Command::Convert(Options {
+                direction: Direction::Decompose,
+                delimiter: b'\n',
+                target: Target::Args,
+                operands: vec!["Pok\u{00E9}mon".into(), "-".into()],
+            }))
+        );
+        assert_eq!(parse(&["-d", "--help"]), Ok(Command::Help));
+        assert_eq!(parse(&["-V"]), Ok(Command::Version));
+        assert!(parse(&["a.txt"]).is_err());
+        assert!(parse(&["-d", "-c"]).is_err());
+        assert!(parse(&["-d", "--nul"]).is_err());
+        assert!(parse(&["-dx"]).is_err());
+        assert_eq!(
+            parse(&["-crn", "dir"]),
+            Ok(Command::Convert(Options {
+                direction: Direction::Compose,
+                delimiter: b'\n',
+                target: Target::Rename { dry_run: true },
+                operands: vec!["dir".into()],
+            }))
+        );
+        assert!(parse(&["-c", "--dry-run", "dir"]).is_err());
+        assert!(parse(&["-c", "--rename"]).is_err());
+        assert!(parse(&["-cn", "--rename", "--"]).is_err());
+        assert!(parse(&["-c", "--rename", "--args", "dir"]).is_err());
+    }
+
+    #[test]
+    fn convert_stream_test() {
+        let mut output = Vec::new();
+        convert_stream(
+            Direction::Decompose,
+            b'\0',
+            "caf\u{00E9}\0\n\u{00E9}\0".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(output, "cafe\u{0301}\0\ne\u{0301}\0".as_bytes());
+
+        let mut output = Vec::new();
+        convert_stream(
+            Direction::Compose,
+            b'\n',
+            &b"e\xCC\x81\xFF\ncafe\xCC\x81"[..],
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(output, b"\xC3\xA9\xFF\ncaf\xC3\xA9");
+    }
+
+    #[test]
+    fn args_as_text_test() {
+        let options = Options {
+            direction: Direction::Compose,
+            delimiter: b'\0',
+            target: Target::Args,
+            operands: vec!["e\u{0301}".into(), "x".into()],
+        };
+        let mut output = Vec::new();
+        assert!(run(&options, &mut output).unwrap());
+        assert_eq!(output, "\u{00E9}\0x\0".as_bytes());
+    }
+
+    #[test]
+    fn rename_missing_directory_test() {
+        let dir = std::env::temp_dir().join(format!("hfs-nfd-{}", process::id()));
+        std::fs::create_dir_all(dir.join("e\u{0301}")).unwrap();
+        let options = Options {
+            direction: Direction::Compose,
+            delimiter: b'\n',
+            target: Target::Rename { dry_run: false },
+            operands: vec![dir.join("missing").into(), dir.clone().into()],
+        };
+        let mut output = Vec::new();
+        let succeeded = run(&options, &mut output).unwrap();
+        let renamed = dir.join("\u{00E9}").exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+        // The directories after the missing one are still renamed
+        assert!(!succeeded);
+        assert!(renamed);
+        assert_eq!(output.iter().filter(|&&b| b == b'\n').count(), 1);
+    }
+}
@@ -0,0 +1,226 @@
+//! Conversion of raw names which may not be valid UTF-8, e.g. file names read from `readdir` on Unix
+//!
+//! Valid UTF-8 runs are converted with the string functions, and each run is converted independently of the others.
+//! Invalid byte sequences are handled according to [`InvalidUtf8Policy`].
+
+use super::error::Error;
+use super::{compose_from_hfs_nfd_cow, decompose_into_hfs_nfd_cow};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::str;
+
+/// How invalid UTF-8 sequences in byte strings are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InvalidUtf8Policy {
+    /// Invalid bytes are copied to the output untouched
+    #[default]
+    PassThrough,
+    /// Each invalid sequence is replaced with U+FFFD, like [`String::from_utf8_lossy`]
+    Replace,
+    /// The conversion fails with [`Error::InvalidUtf8`]
+    Reject,
+}
+
+fn cow_str_into_bytes(s: Cow<'_, str>) -> Cow<'_, [u8]> {
+    match s {
+        Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+        Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+    }
+}
+
+fn convert_bytes<'a>(
+    input: &'a [u8],
+    policy: InvalidUtf8Policy,
+    convert: fn(&str) -> Cow<'_, str>,
+) -> Result<Cow<'a, [u8]>, Error> {
+    if let Ok(s) = str::from_utf8(input) {
+        return Ok(cow_str_into_bytes(convert(s)));
+    }
+    let mut result = Vec::with_capacity(input.len());
+    let mut changed = false;
+    let mut position = 0;
+    for chunk in input.utf8_chunks() {
+        let converted = convert(chunk.valid());
+        changed |= matches!(converted, Cow::Owned(_));
+        result.extend_from_slice(converted.as_bytes());
+        position += chunk.valid().len();
+        let invalid = chunk.invalid();
+        if !invalid.is_empty() {
+            match policy {
+                InvalidUtf8Policy::PassThrough => result.extend_from_slice(invalid),
+                InvalidUtf8Policy::Replace => {
+                    result.extend_from_slice("\u{FFFD}".as_bytes());
+                    changed = true;
+                }
+                InvalidUtf8Policy::Reject => return Err(Error::InvalidUtf8(position)),
+            }
+            position += invalid.len();
+        }
+    }
+    Ok(if changed {
+        Cow::Owned(result)
+    } else {
+        Cow::Borrowed(input)
+    })
+}
+
+/// Applies the Unicode decomposition similar to NFD used in HFS+ to a byte string, passing invalid UTF-8 sequences
+/// through untouched
+///
+/// # Arguments
+///
+/// * `input` - Bytes to be decomposed
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decompose_bytes;
+/// assert_eq!(&*decompose_bytes(b"caf\xC3\xA9\xFF"), b"cafe\xCC\x81\xFF");
+/// ```
+pub fn decompose_bytes(input: &[u8]) -> Cow<'_, [u8]> {
+    decompose_bytes_with_policy(input, InvalidUtf8Policy::PassThrough)
+        .expect("passing invalid bytes through never fails")
+}
+
+/// Applies the Unicode decomposition similar to NFD used in HFS+ to a byte string, handling invalid UTF-8 sequences
+/// according to `policy`
+///
+/// # Arguments
+///
+/// * `input` - Bytes to be decomposed
+/// * `policy` - How invalid UTF-8 sequences are handled
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidUtf8`] if `input` contains an invalid UTF-8 sequence and `policy` is
+/// [`InvalidUtf8Policy::Reject`].
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_bytes_with_policy, Error, InvalidUtf8Policy};
+/// assert_eq!(
+///     &*decompose_bytes_with_policy(b"\xC3\xA9\xFF", InvalidUtf8Policy::Replace).unwrap(),
+///     "e\u{0301}\u{FFFD}".as_bytes()
+/// );
+/// assert_eq!(
+///     decompose_bytes_with_policy(b"\xC3\xA9\xFF", InvalidUtf8Policy::Reject),
+///     Err(Error::InvalidUtf8(2))
+/// );
+/// ```
+pub fn decompose_bytes_with_policy(
+    input: &[u8],
+    policy: InvalidUtf8Policy,
+) -> Result<Cow<'_, [u8]>, Error> {
+    convert_bytes(input, policy, decompose_into_hfs_nfd_cow)
+}
+
+/// Restores a commonly encoded byte string from the Unicode decomposition similar to NFD used in HFS+, passing
+/// invalid UTF-8 sequences through untouched
+///
+/// # Arguments
+///
+/// * `input` - Bytes to be restored from
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_bytes;
+/// assert_eq!(&*compose_bytes(b"cafe\xCC\x81\xFF"), b"caf\xC3\xA9\xFF");
+/// ```
+pub fn compose_bytes(input: &[u8]) -> Cow<'_, [u8]> {
+    compose_bytes_with_policy(input, InvalidUtf8Policy::PassThrough)
+        .expect("passing invalid bytes through never fails")
+}
+
+/// Restores a commonly encoded byte string from the Unicode decomposition similar to NFD used in HFS+, handling
+/// invalid UTF-8 sequences according to `policy`
+///
+/// # Arguments
+///
+/// * `input` - Bytes to be restored from
+/// * `policy` - How invalid UTF-8 sequences are handled
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidUtf8`] if `input` contains an invalid UTF-8 sequence and `policy` is
+/// [`InvalidUtf8Policy::Reject`].
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_bytes_with_policy, InvalidUtf8Policy};
+/// assert_eq!(
+///     &*compose_bytes_with_policy(b"e\xCC\x81\xFF", InvalidUtf8Policy::Replace).unwrap(),
+///     "\u{00E9}\u{FFFD}".as_bytes()
+/// );
+/// ```
+pub fn compose_bytes_with_policy(
+    input: &[u8],
+    policy: InvalidUtf8Policy,
+) -> Result<Cow<'_, [u8]>, Error> {
+    convert_bytes(input, policy, compose_from_hfs_nfd_cow)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn valid_bytes_test() {
+        assert_eq!(
+            &*decompose_bytes("Pok\u{00E9}mon".as_bytes()),
+            "Poke\u{0301}mon".as_bytes()
+        );
+        assert_eq!(
+            &*compose_bytes("Poke\u{0301}mon".as_bytes()),
+            "Pok\u{00E9}mon".as_bytes()
+        );
+        assert!(matches!(decompose_bytes(b"Pikachu"), Cow::Borrowed(_)));
+        assert!(matches!(compose_bytes(b"Pikachu"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn invalid_bytes_test() {
+        // Lone continuation byte, truncated sequence in the middle, and truncated sequence at the end
+        let input = b"\x80\xC3\xA9\xE3\x81e\xCC\x81\xE3";
+        assert_eq!(
+            &*decompose_bytes(input),
+            b"\x80e\xCC\x81\xE3\x81e\xCC\x81\xE3"
+        );
+        assert_eq!(&*compose_bytes(input), b"\x80\xC3\xA9\xE3\x81\xC3\xA9\xE3");
+        assert_eq!(
+            &*decompose_bytes_with_policy(input, InvalidUtf8Policy::Replace).unwrap(),
+            "\u{FFFD}e\u{0301}\u{FFFD}e\u{0301}\u{FFFD}".as_bytes()
+        );
+        assert_eq!(
+            compose_bytes_with_policy(input, InvalidUtf8Policy::Reject),
+            Err(Error::InvalidUtf8(0))
+        );
+        assert_eq!(
+            decompose_bytes_with_policy(b"e\xCC\x81\xFF", InvalidUtf8Policy::Reject),
+            Err(Error::InvalidUtf8(3))
+        );
+        // Unchanged input with invalid bytes is still borrowed
+        assert!(matches!(decompose_bytes(b"Pika\xFFchu"), Cow::Borrowed(_)));
+        assert!(matches!(compose_bytes(b"Pika\xFFchu"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn scattered_invalid_bytes_test() {
+        // Each run is scanned once, so Latin-1 names with many invalid bytes are not slow
+        let input = b"caf\xE9 e\xCC\x81 ".repeat(100_000);
+        let expected = b"caf\xE9 \xC3\xA9 ".repeat(100_000);
+        assert_eq!(&*compose_bytes(&input), &expected[..]);
+        assert_eq!(
+            compose_bytes_with_policy(&input, InvalidUtf8Policy::Reject),
+            Err(Error::InvalidUtf8(3))
+        );
+    }
+
+    #[test]
+    fn invalid_bytes_split_sequences_test() {
+        // Combining marks are not attached across invalid bytes
+        assert_eq!(&*compose_bytes(b"e\xFF\xCC\x81"), b"e\xFF\xCC\x81");
+    }
+}
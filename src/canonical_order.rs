@@ -0,0 +1,157 @@
+//! Canonical ordering of combining marks in the way HFS+ stores them
+
+use super::combining_class_table::{COMBINING_CLASS_TABLE, COMBINING_CLASS_TABLE_UNICODE32};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Looks up the Unicode canonical combining class of a character
+#[inline]
+pub fn canonical_combining_class(c: char) -> u8 {
+    // Inlined so that the callers skip the table for the most common characters
+    if c < '\u{0300}' {
+        0
+    } else {
+        look_up_combining_class(c)
+    }
+}
+
+fn look_up_combining_class(c: char) -> u8 {
+    COMBINING_CLASS_TABLE.get(&c).copied().unwrap_or(0)
+}
+
+/// Looks up the canonical combining class of a character in Unicode 3.2, where the marks added later are 0
+#[inline]
+pub fn unicode32_combining_class(c: char) -> u8 {
+    if c < '\u{0300}' {
+        0
+    } else {
+        look_up_unicode32_combining_class(c)
+    }
+}
+
+fn look_up_unicode32_combining_class(c: char) -> u8 {
+    COMBINING_CLASS_TABLE_UNICODE32
+        .get(&c)
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Combining class used to sort combining marks in the order of the decompositions in the HFS+ table
+///
+/// The same as [`canonical_combining_class`] except for the marks Apple places differently:
+///
+/// - U+0345 (ypogegrammeni, 240) is placed before the other Greek accents (230)
+/// - U+0F71 (Tibetan vowel sign AA, 129) is placed after the other Tibetan vowel signs (130 & 132)
+#[inline]
+pub fn hfs_combining_class(c: char) -> u8 {
+    match c {
+        '\u{0345}' => 229,
+        '\u{0F71}' => 133,
+        _ => canonical_combining_class(c),
+    }
+}
+
+/// Buffer of a run of combining marks to be sorted stably by [`hfs_combining_class`]
+#[cfg(feature = "alloc")]
+pub struct MarkBuffer {
+    /// The first mark of the run, kept out of `rest` so that a single mark is neither stored in the heap nor sorted
+    first: Option<(u8, char)>,
+    /// The following marks
+    rest: Vec<(u8, char)>,
+}
+
+#[cfg(feature = "alloc")]
+impl MarkBuffer {
+    /// Create an empty buffer.
+    pub fn new() -> Self {
+        Self {
+            first: None,
+            rest: Vec::new(),
+        }
+    }
+
+    /// Checks whether the buffer has no marks.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.first.is_none()
+    }
+
+    /// Adds a combining mark with its (non-zero) combining class.
+    pub fn push(&mut self, class: u8, c: char) {
+        if self.first.is_none() {
+            self.first = Some((class, c));
+        } else {
+            self.rest.push((class, c));
+        }
+    }
+
+    /// Removes and returns all the marks in the canonical order.
+    pub fn drain_sorted(&mut self) -> impl Iterator<Item = char> + '_ {
+        if !self.rest.is_empty() {
+            if let Some(first) = self.first.take() {
+                self.rest.insert(0, first);
+            }
+            self.rest.sort_by_key(|&(class, _)| class);
+        }
+        self.first
+            .take()
+            .into_iter()
+            .chain(self.rest.drain(..))
+            .map(|(_, c)| c)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::code_table::MAP_TO_HFS;
+
+    #[test]
+    fn combining_class_fixed_test() {
+        let examinee = vec![
+            ('a', 0),
+            ('\u{0300}', 230),
+            ('\u{0314}', 230),
+            ('\u{0315}', 232),
+            ('\u{0323}', 220),
+            ('\u{0345}', 240),
+            ('\u{05B0}', 10),
+            ('\u{0F71}', 129),
+            ('\u{3099}', 8),
+            ('\u{30AB}', 0),
+            ('\u{1D165}', 216),
+        ];
+        for (c, class) in examinee {
+            assert_eq!(canonical_combining_class(c), class, "U+{:04X}", c as u32);
+        }
+    }
+
+    #[test]
+    fn unicode32_combining_class_test() {
+        for &(c, class) in COMBINING_CLASS_TABLE_UNICODE32 {
+            assert_eq!(canonical_combining_class(c), class, "U+{:04X}", c as u32);
+        }
+        // U+1DC0 was added in Unicode 5.0
+        assert_eq!(unicode32_combining_class('\u{1DC0}'), 0);
+        assert_eq!(canonical_combining_class('\u{1DC0}'), 230);
+        assert_eq!(unicode32_combining_class('\u{0300}'), 230);
+    }
+
+    #[test]
+    fn hfs_table_in_canonical_order_test() {
+        for (composed, decomposed) in MAP_TO_HFS {
+            let classes = decomposed
+                .chars()
+                .map(hfs_combining_class)
+                .collect::<Vec<_>>();
+            for pair in classes.windows(2) {
+                assert!(
+                    pair[1] == 0 || pair[0] <= pair[1],
+                    "U+{:04X}: {:?}",
+                    *composed as u32,
+                    classes
+                );
+            }
+        }
+    }
+}
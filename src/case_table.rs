@@ -0,0 +1,101 @@
+//! Definition of the lower case mapping used by HFS+ to compare names case-insensitively
+//!
+//! Transcribed from `gLowerCaseTable` in https://developer.apple.com/library/archive/technotes/tn/tn1150.html
+//! Characters decomposed by HFS+ (e.g. U+00C0) are left as is since they never appear in catalog keys.
+
+/// Maps a UTF-16 code unit as `gLowerCaseTable` does
+///
+/// Returns 0 for ignorable code points and 0xFFFF for U+0000 so that it is sorted after any other character.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(hfs_lower_case(0x0041), 0x0061);
+/// assert_eq!(hfs_lower_case(0x200D), 0);
+/// ```
+pub fn hfs_lower_case(c: u16) -> u16 {
+    match c {
+        0x0000 => 0xFFFF,
+        // Basic Latin & Latin-1 Supplement
+        0x0041..=0x005A | 0x00C6 | 0x00D0 | 0x00D8 | 0x00DE => c + 0x20,
+        // Latin Extended-A
+        0x0110 | 0x0126 | 0x0132 | 0x013F | 0x0141 | 0x014A | 0x0152 | 0x0166 => c + 1,
+        // Latin Extended-B
+        0x0181 => 0x0253,
+        0x0186 => 0x0254,
+        0x0189 => 0x0256,
+        0x018A => 0x0257,
+        0x018E => 0x01DD,
+        0x018F => 0x0259,
+        0x0190 => 0x025B,
+        0x0193 => 0x0260,
+        0x0194 => 0x0263,
+        0x0196 => 0x0269,
+        0x0197 => 0x0268,
+        0x019C => 0x026F,
+        0x019D => 0x0272,
+        0x019F => 0x0275,
+        0x01A9 => 0x0283,
+        0x01AE => 0x0288,
+        0x01B1 => 0x028A,
+        0x01B2 => 0x028B,
+        0x01B7 => 0x0292,
+        0x0182 | 0x0184 | 0x0187 | 0x018B | 0x0191 | 0x0198 | 0x01A2 | 0x01A4 | 0x01A7 | 0x01AC
+        | 0x01B3 | 0x01B5 | 0x01B8 | 0x01BC | 0x01E4 => c + 1,
+        0x01C4 | 0x01C7 | 0x01CA | 0x01F1 => c + 2,
+        0x01C5 | 0x01C8 | 0x01CB | 0x01F2 => c + 1,
+        // Greek
+        0x0391..=0x03A1 | 0x03A3..=0x03A9 => c + 0x20,
+        0x03E2 | 0x03E4 | 0x03E6 | 0x03E8 | 0x03EA | 0x03EC | 0x03EE => c + 1,
+        // Cyrillic
+        0x0402 | 0x0404..=0x0406 | 0x0408..=0x040B | 0x040F => c + 0x50,
+        0x0410..=0x0418 | 0x041A..=0x042F => c + 0x20,
+        0x0460..=0x0475 | 0x0478..=0x0481 | 0x0490..=0x04BF if c & 1 == 0 => c + 1,
+        0x04C3 | 0x04C7 | 0x04CB => c + 1,
+        // Armenian
+        0x0531..=0x0556 => c + 0x30,
+        // Georgian
+        0x10A0..=0x10C5 => c + 0x30,
+        // Ignorable (zero-width & directional formatting characters)
+        0x200C..=0x200F | 0x202A..=0x202E | 0x206A..=0x206F | 0xFEFF => 0,
+        // Roman numerals
+        0x2160..=0x216F => c + 0x10,
+        // Fullwidth Latin
+        0xFF21..=0xFF3A => c + 0x20,
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lower_case_fixed_test() {
+        let examinee = vec![
+            (0x0000, 0xFFFF),
+            (0x0041, 0x0061),
+            (0x0061, 0x0061),
+            (0x00C0, 0x00C0),
+            (0x00C6, 0x00E6),
+            (0x0141, 0x0142),
+            (0x01C4, 0x01C6),
+            (0x01C5, 0x01C6),
+            (0x03A3, 0x03C3),
+            (0x0401, 0x0401),
+            (0x0402, 0x0452),
+            (0x0419, 0x0419),
+            (0x0428, 0x0448),
+            (0x0476, 0x0476),
+            (0x0490, 0x0491),
+            (0x0531, 0x0561),
+            (0x200D, 0x0000),
+            (0x2160, 0x2170),
+            (0xFEFF, 0x0000),
+            (0xFF21, 0xFF41),
+        ];
+        for (c, lower) in examinee {
+            assert_eq!(hfs_lower_case(c), lower, "U+{:04X}", c);
+        }
+    }
+}
@@ -3,2455 +3,4365 @@
 //! Generated based on https://developer.apple.com/library/archive/technotes/tn/tn1150table.html
 //! fetched at 2020-09-16T15:19:13+00:00
 
-use super::reverse_tree::ReverseTreeNode;
-use ahash::AHashMap;
-use lazy_static::lazy_static;
-lazy_static! {
-    /// map from composed character (normal) to decomposed components (HFS+)
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// assert_eq!((*MAP_TO_HFS).get(&'\u{00E9}').unwrap(), "e\u{0301}");
-    /// ```
-    pub static ref MAP_TO_HFS: AHashMap<char, &'static str> = {
-        let mut map = AHashMap::new();
-        map.insert('\u{00C0}', "\u{0041}\u{0300}");
-        map.insert('\u{00C1}', "\u{0041}\u{0301}");
-        map.insert('\u{00C2}', "\u{0041}\u{0302}");
-        map.insert('\u{00C3}', "\u{0041}\u{0303}");
-        map.insert('\u{00C4}', "\u{0041}\u{0308}");
-        map.insert('\u{00C5}', "\u{0041}\u{030A}");
-        map.insert('\u{00C7}', "\u{0043}\u{0327}");
-        map.insert('\u{00C8}', "\u{0045}\u{0300}");
-        map.insert('\u{00C9}', "\u{0045}\u{0301}");
-        map.insert('\u{00CA}', "\u{0045}\u{0302}");
-        map.insert('\u{00CB}', "\u{0045}\u{0308}");
-        map.insert('\u{00CC}', "\u{0049}\u{0300}");
-        map.insert('\u{00CD}', "\u{0049}\u{0301}");
-        map.insert('\u{00CE}', "\u{0049}\u{0302}");
-        map.insert('\u{00CF}', "\u{0049}\u{0308}");
-        map.insert('\u{00D1}', "\u{004E}\u{0303}");
-        map.insert('\u{00D2}', "\u{004F}\u{0300}");
-        map.insert('\u{00D3}', "\u{004F}\u{0301}");
-        map.insert('\u{00D4}', "\u{004F}\u{0302}");
-        map.insert('\u{00D5}', "\u{004F}\u{0303}");
-        map.insert('\u{00D6}', "\u{004F}\u{0308}");
-        map.insert('\u{00D9}', "\u{0055}\u{0300}");
-        map.insert('\u{00DA}', "\u{0055}\u{0301}");
-        map.insert('\u{00DB}', "\u{0055}\u{0302}");
-        map.insert('\u{00DC}', "\u{0055}\u{0308}");
-        map.insert('\u{00DD}', "\u{0059}\u{0301}");
-        map.insert('\u{00E0}', "\u{0061}\u{0300}");
-        map.insert('\u{00E1}', "\u{0061}\u{0301}");
-        map.insert('\u{00E2}', "\u{0061}\u{0302}");
-        map.insert('\u{00E3}', "\u{0061}\u{0303}");
-        map.insert('\u{00E4}', "\u{0061}\u{0308}");
-        map.insert('\u{00E5}', "\u{0061}\u{030A}");
-        map.insert('\u{00E7}', "\u{0063}\u{0327}");
-        map.insert('\u{00E8}', "\u{0065}\u{0300}");
-        map.insert('\u{00E9}', "\u{0065}\u{0301}");
-        map.insert('\u{00EA}', "\u{0065}\u{0302}");
-        map.insert('\u{00EB}', "\u{0065}\u{0308}");
-        map.insert('\u{00EC}', "\u{0069}\u{0300}");
-        map.insert('\u{00ED}', "\u{0069}\u{0301}");
-        map.insert('\u{00EE}', "\u{0069}\u{0302}");
-        map.insert('\u{00EF}', "\u{0069}\u{0308}");
-        map.insert('\u{00F1}', "\u{006E}\u{0303}");
-        map.insert('\u{00F2}', "\u{006F}\u{0300}");
-        map.insert('\u{00F3}', "\u{006F}\u{0301}");
-        map.insert('\u{00F4}', "\u{006F}\u{0302}");
-        map.insert('\u{00F5}', "\u{006F}\u{0303}");
-        map.insert('\u{00F6}', "\u{006F}\u{0308}");
-        map.insert('\u{00F9}', "\u{0075}\u{0300}");
-        map.insert('\u{00FA}', "\u{0075}\u{0301}");
-        map.insert('\u{00FB}', "\u{0075}\u{0302}");
-        map.insert('\u{00FC}', "\u{0075}\u{0308}");
-        map.insert('\u{00FD}', "\u{0079}\u{0301}");
-        map.insert('\u{00FF}', "\u{0079}\u{0308}");
-        map.insert('\u{0100}', "\u{0041}\u{0304}");
-        map.insert('\u{0101}', "\u{0061}\u{0304}");
-        map.insert('\u{0102}', "\u{0041}\u{0306}");
-        map.insert('\u{0103}', "\u{0061}\u{0306}");
-        map.insert('\u{0104}', "\u{0041}\u{0328}");
-        map.insert('\u{0105}', "\u{0061}\u{0328}");
-        map.insert('\u{0106}', "\u{0043}\u{0301}");
-        map.insert('\u{0107}', "\u{0063}\u{0301}");
-        map.insert('\u{0108}', "\u{0043}\u{0302}");
-        map.insert('\u{0109}', "\u{0063}\u{0302}");
-        map.insert('\u{010A}', "\u{0043}\u{0307}");
-        map.insert('\u{010B}', "\u{0063}\u{0307}");
-        map.insert('\u{010C}', "\u{0043}\u{030C}");
-        map.insert('\u{010D}', "\u{0063}\u{030C}");
-        map.insert('\u{010E}', "\u{0044}\u{030C}");
-        map.insert('\u{010F}', "\u{0064}\u{030C}");
-        map.insert('\u{0112}', "\u{0045}\u{0304}");
-        map.insert('\u{0113}', "\u{0065}\u{0304}");
-        map.insert('\u{0114}', "\u{0045}\u{0306}");
-        map.insert('\u{0115}', "\u{0065}\u{0306}");
-        map.insert('\u{0116}', "\u{0045}\u{0307}");
-        map.insert('\u{0117}', "\u{0065}\u{0307}");
-        map.insert('\u{0118}', "\u{0045}\u{0328}");
-        map.insert('\u{0119}', "\u{0065}\u{0328}");
-        map.insert('\u{011A}', "\u{0045}\u{030C}");
-        map.insert('\u{011B}', "\u{0065}\u{030C}");
-        map.insert('\u{011C}', "\u{0047}\u{0302}");
-        map.insert('\u{011D}', "\u{0067}\u{0302}");
-        map.insert('\u{011E}', "\u{0047}\u{0306}");
-        map.insert('\u{011F}', "\u{0067}\u{0306}");
-        map.insert('\u{0120}', "\u{0047}\u{0307}");
-        map.insert('\u{0121}', "\u{0067}\u{0307}");
-        map.insert('\u{0122}', "\u{0047}\u{0327}");
-        map.insert('\u{0123}', "\u{0067}\u{0327}");
-        map.insert('\u{0124}', "\u{0048}\u{0302}");
-        map.insert('\u{0125}', "\u{0068}\u{0302}");
-        map.insert('\u{0128}', "\u{0049}\u{0303}");
-        map.insert('\u{0129}', "\u{0069}\u{0303}");
-        map.insert('\u{012A}', "\u{0049}\u{0304}");
-        map.insert('\u{012B}', "\u{0069}\u{0304}");
-        map.insert('\u{012C}', "\u{0049}\u{0306}");
-        map.insert('\u{012D}', "\u{0069}\u{0306}");
-        map.insert('\u{012E}', "\u{0049}\u{0328}");
-        map.insert('\u{012F}', "\u{0069}\u{0328}");
-        map.insert('\u{0130}', "\u{0049}\u{0307}");
-        map.insert('\u{0134}', "\u{004A}\u{0302}");
-        map.insert('\u{0135}', "\u{006A}\u{0302}");
-        map.insert('\u{0136}', "\u{004B}\u{0327}");
-        map.insert('\u{0137}', "\u{006B}\u{0327}");
-        map.insert('\u{0139}', "\u{004C}\u{0301}");
-        map.insert('\u{013A}', "\u{006C}\u{0301}");
-        map.insert('\u{013B}', "\u{004C}\u{0327}");
-        map.insert('\u{013C}', "\u{006C}\u{0327}");
-        map.insert('\u{013D}', "\u{004C}\u{030C}");
-        map.insert('\u{013E}', "\u{006C}\u{030C}");
-        map.insert('\u{0143}', "\u{004E}\u{0301}");
-        map.insert('\u{0144}', "\u{006E}\u{0301}");
-        map.insert('\u{0145}', "\u{004E}\u{0327}");
-        map.insert('\u{0146}', "\u{006E}\u{0327}");
-        map.insert('\u{0147}', "\u{004E}\u{030C}");
-        map.insert('\u{0148}', "\u{006E}\u{030C}");
-        map.insert('\u{014C}', "\u{004F}\u{0304}");
-        map.insert('\u{014D}', "\u{006F}\u{0304}");
-        map.insert('\u{014E}', "\u{004F}\u{0306}");
-        map.insert('\u{014F}', "\u{006F}\u{0306}");
-        map.insert('\u{0150}', "\u{004F}\u{030B}");
-        map.insert('\u{0151}', "\u{006F}\u{030B}");
-        map.insert('\u{0154}', "\u{0052}\u{0301}");
-        map.insert('\u{0155}', "\u{0072}\u{0301}");
-        map.insert('\u{0156}', "\u{0052}\u{0327}");
-        map.insert('\u{0157}', "\u{0072}\u{0327}");
-        map.insert('\u{0158}', "\u{0052}\u{030C}");
-        map.insert('\u{0159}', "\u{0072}\u{030C}");
-        map.insert('\u{015A}', "\u{0053}\u{0301}");
-        map.insert('\u{015B}', "\u{0073}\u{0301}");
-        map.insert('\u{015C}', "\u{0053}\u{0302}");
-        map.insert('\u{015D}', "\u{0073}\u{0302}");
-        map.insert('\u{015E}', "\u{0053}\u{0327}");
-        map.insert('\u{015F}', "\u{0073}\u{0327}");
-        map.insert('\u{0160}', "\u{0053}\u{030C}");
-        map.insert('\u{0161}', "\u{0073}\u{030C}");
-        map.insert('\u{0162}', "\u{0054}\u{0327}");
-        map.insert('\u{0163}', "\u{0074}\u{0327}");
-        map.insert('\u{0164}', "\u{0054}\u{030C}");
-        map.insert('\u{0165}', "\u{0074}\u{030C}");
-        map.insert('\u{0168}', "\u{0055}\u{0303}");
-        map.insert('\u{0169}', "\u{0075}\u{0303}");
-        map.insert('\u{016A}', "\u{0055}\u{0304}");
-        map.insert('\u{016B}', "\u{0075}\u{0304}");
-        map.insert('\u{016C}', "\u{0055}\u{0306}");
-        map.insert('\u{016D}', "\u{0075}\u{0306}");
-        map.insert('\u{016E}', "\u{0055}\u{030A}");
-        map.insert('\u{016F}', "\u{0075}\u{030A}");
-        map.insert('\u{0170}', "\u{0055}\u{030B}");
-        map.insert('\u{0171}', "\u{0075}\u{030B}");
-        map.insert('\u{0172}', "\u{0055}\u{0328}");
-        map.insert('\u{0173}', "\u{0075}\u{0328}");
-        map.insert('\u{0174}', "\u{0057}\u{0302}");
-        map.insert('\u{0175}', "\u{0077}\u{0302}");
-        map.insert('\u{0176}', "\u{0059}\u{0302}");
-        map.insert('\u{0177}', "\u{0079}\u{0302}");
-        map.insert('\u{0178}', "\u{0059}\u{0308}");
-        map.insert('\u{0179}', "\u{005A}\u{0301}");
-        map.insert('\u{017A}', "\u{007A}\u{0301}");
-        map.insert('\u{017B}', "\u{005A}\u{0307}");
-        map.insert('\u{017C}', "\u{007A}\u{0307}");
-        map.insert('\u{017D}', "\u{005A}\u{030C}");
-        map.insert('\u{017E}', "\u{007A}\u{030C}");
-        map.insert('\u{01A0}', "\u{004F}\u{031B}");
-        map.insert('\u{01A1}', "\u{006F}\u{031B}");
-        map.insert('\u{01AF}', "\u{0055}\u{031B}");
-        map.insert('\u{01B0}', "\u{0075}\u{031B}");
-        map.insert('\u{01CD}', "\u{0041}\u{030C}");
-        map.insert('\u{01CE}', "\u{0061}\u{030C}");
-        map.insert('\u{01CF}', "\u{0049}\u{030C}");
-        map.insert('\u{01D0}', "\u{0069}\u{030C}");
-        map.insert('\u{01D1}', "\u{004F}\u{030C}");
-        map.insert('\u{01D2}', "\u{006F}\u{030C}");
-        map.insert('\u{01D3}', "\u{0055}\u{030C}");
-        map.insert('\u{01D4}', "\u{0075}\u{030C}");
-        map.insert('\u{01D5}', "\u{0055}\u{0308}\u{0304}");
-        map.insert('\u{01D6}', "\u{0075}\u{0308}\u{0304}");
-        map.insert('\u{01D7}', "\u{0055}\u{0308}\u{0301}");
-        map.insert('\u{01D8}', "\u{0075}\u{0308}\u{0301}");
-        map.insert('\u{01D9}', "\u{0055}\u{0308}\u{030C}");
-        map.insert('\u{01DA}', "\u{0075}\u{0308}\u{030C}");
-        map.insert('\u{01DB}', "\u{0055}\u{0308}\u{0300}");
-        map.insert('\u{01DC}', "\u{0075}\u{0308}\u{0300}");
-        map.insert('\u{01DE}', "\u{0041}\u{0308}\u{0304}");
-        map.insert('\u{01DF}', "\u{0061}\u{0308}\u{0304}");
-        map.insert('\u{01E0}', "\u{0041}\u{0307}\u{0304}");
-        map.insert('\u{01E1}', "\u{0061}\u{0307}\u{0304}");
-        map.insert('\u{01E2}', "\u{00C6}\u{0304}");
-        map.insert('\u{01E3}', "\u{00E6}\u{0304}");
-        map.insert('\u{01E6}', "\u{0047}\u{030C}");
-        map.insert('\u{01E7}', "\u{0067}\u{030C}");
-        map.insert('\u{01E8}', "\u{004B}\u{030C}");
-        map.insert('\u{01E9}', "\u{006B}\u{030C}");
-        map.insert('\u{01EA}', "\u{004F}\u{0328}");
-        map.insert('\u{01EB}', "\u{006F}\u{0328}");
-        map.insert('\u{01EC}', "\u{004F}\u{0328}\u{0304}");
-        map.insert('\u{01ED}', "\u{006F}\u{0328}\u{0304}");
-        map.insert('\u{01EE}', "\u{01B7}\u{030C}");
-        map.insert('\u{01EF}', "\u{0292}\u{030C}");
-        map.insert('\u{01F0}', "\u{006A}\u{030C}");
-        map.insert('\u{01F4}', "\u{0047}\u{0301}");
-        map.insert('\u{01F5}', "\u{0067}\u{0301}");
-        map.insert('\u{01FA}', "\u{0041}\u{030A}\u{0301}");
-        map.insert('\u{01FB}', "\u{0061}\u{030A}\u{0301}");
-        map.insert('\u{01FC}', "\u{00C6}\u{0301}");
-        map.insert('\u{01FD}', "\u{00E6}\u{0301}");
-        map.insert('\u{01FE}', "\u{00D8}\u{0301}");
-        map.insert('\u{01FF}', "\u{00F8}\u{0301}");
-        map.insert('\u{0200}', "\u{0041}\u{030F}");
-        map.insert('\u{0201}', "\u{0061}\u{030F}");
-        map.insert('\u{0202}', "\u{0041}\u{0311}");
-        map.insert('\u{0203}', "\u{0061}\u{0311}");
-        map.insert('\u{0204}', "\u{0045}\u{030F}");
-        map.insert('\u{0205}', "\u{0065}\u{030F}");
-        map.insert('\u{0206}', "\u{0045}\u{0311}");
-        map.insert('\u{0207}', "\u{0065}\u{0311}");
-        map.insert('\u{0208}', "\u{0049}\u{030F}");
-        map.insert('\u{0209}', "\u{0069}\u{030F}");
-        map.insert('\u{020A}', "\u{0049}\u{0311}");
-        map.insert('\u{020B}', "\u{0069}\u{0311}");
-        map.insert('\u{020C}', "\u{004F}\u{030F}");
-        map.insert('\u{020D}', "\u{006F}\u{030F}");
-        map.insert('\u{020E}', "\u{004F}\u{0311}");
-        map.insert('\u{020F}', "\u{006F}\u{0311}");
-        map.insert('\u{0210}', "\u{0052}\u{030F}");
-        map.insert('\u{0211}', "\u{0072}\u{030F}");
-        map.insert('\u{0212}', "\u{0052}\u{0311}");
-        map.insert('\u{0213}', "\u{0072}\u{0311}");
-        map.insert('\u{0214}', "\u{0055}\u{030F}");
-        map.insert('\u{0215}', "\u{0075}\u{030F}");
-        map.insert('\u{0216}', "\u{0055}\u{0311}");
-        map.insert('\u{0217}', "\u{0075}\u{0311}");
-        map.insert('\u{0310}', "\u{0306}\u{0307}");
-        map.insert('\u{0344}', "\u{0308}\u{030D}");
-        map.insert('\u{0385}', "\u{00A8}\u{030D}");
-        map.insert('\u{0386}', "\u{0391}\u{030D}");
-        map.insert('\u{0388}', "\u{0395}\u{030D}");
-        map.insert('\u{0389}', "\u{0397}\u{030D}");
-        map.insert('\u{038A}', "\u{0399}\u{030D}");
-        map.insert('\u{038C}', "\u{039F}\u{030D}");
-        map.insert('\u{038E}', "\u{03A5}\u{030D}");
-        map.insert('\u{038F}', "\u{03A9}\u{030D}");
-        map.insert('\u{0390}', "\u{03B9}\u{0308}\u{030D}");
-        map.insert('\u{03AA}', "\u{0399}\u{0308}");
-        map.insert('\u{03AB}', "\u{03A5}\u{0308}");
-        map.insert('\u{03AC}', "\u{03B1}\u{030D}");
-        map.insert('\u{03AD}', "\u{03B5}\u{030D}");
-        map.insert('\u{03AE}', "\u{03B7}\u{030D}");
-        map.insert('\u{03AF}', "\u{03B9}\u{030D}");
-        map.insert('\u{03B0}', "\u{03C5}\u{0308}\u{030D}");
-        map.insert('\u{03CA}', "\u{03B9}\u{0308}");
-        map.insert('\u{03CB}', "\u{03C5}\u{0308}");
-        map.insert('\u{03CC}', "\u{03BF}\u{030D}");
-        map.insert('\u{03CD}', "\u{03C5}\u{030D}");
-        map.insert('\u{03CE}', "\u{03C9}\u{030D}");
-        map.insert('\u{03D3}', "\u{03D2}\u{030D}");
-        map.insert('\u{03D4}', "\u{03D2}\u{0308}");
-        map.insert('\u{0401}', "\u{0415}\u{0308}");
-        map.insert('\u{0403}', "\u{0413}\u{0301}");
-        map.insert('\u{0407}', "\u{0406}\u{0308}");
-        map.insert('\u{040C}', "\u{041A}\u{0301}");
-        map.insert('\u{040E}', "\u{0423}\u{0306}");
-        map.insert('\u{0419}', "\u{0418}\u{0306}");
-        map.insert('\u{0439}', "\u{0438}\u{0306}");
-        map.insert('\u{0451}', "\u{0435}\u{0308}");
-        map.insert('\u{0453}', "\u{0433}\u{0301}");
-        map.insert('\u{0457}', "\u{0456}\u{0308}");
-        map.insert('\u{045C}', "\u{043A}\u{0301}");
-        map.insert('\u{045E}', "\u{0443}\u{0306}");
-        map.insert('\u{0476}', "\u{0474}\u{030F}");
-        map.insert('\u{0477}', "\u{0475}\u{030F}");
-        map.insert('\u{04C1}', "\u{0416}\u{0306}");
-        map.insert('\u{04C2}', "\u{0436}\u{0306}");
-        map.insert('\u{04D0}', "\u{0410}\u{0306}");
-        map.insert('\u{04D1}', "\u{0430}\u{0306}");
-        map.insert('\u{04D2}', "\u{0410}\u{0308}");
-        map.insert('\u{04D3}', "\u{0430}\u{0308}");
-        map.insert('\u{04D6}', "\u{0415}\u{0306}");
-        map.insert('\u{04D7}', "\u{0435}\u{0306}");
-        map.insert('\u{04DA}', "\u{018F}\u{0308}");
-        map.insert('\u{04DB}', "\u{0259}\u{0308}");
-        map.insert('\u{04DC}', "\u{0416}\u{0308}");
-        map.insert('\u{04DD}', "\u{0436}\u{0308}");
-        map.insert('\u{04DE}', "\u{0417}\u{0308}");
-        map.insert('\u{04DF}', "\u{0437}\u{0308}");
-        map.insert('\u{04E2}', "\u{0418}\u{0304}");
-        map.insert('\u{04E3}', "\u{0438}\u{0304}");
-        map.insert('\u{04E4}', "\u{0418}\u{0308}");
-        map.insert('\u{04E5}', "\u{0438}\u{0308}");
-        map.insert('\u{04E6}', "\u{041E}\u{0308}");
-        map.insert('\u{04E7}', "\u{043E}\u{0308}");
-        map.insert('\u{04EA}', "\u{019F}\u{0308}");
-        map.insert('\u{04EB}', "\u{0275}\u{0308}");
-        map.insert('\u{04EE}', "\u{0423}\u{0304}");
-        map.insert('\u{04EF}', "\u{0443}\u{0304}");
-        map.insert('\u{04F0}', "\u{0423}\u{0308}");
-        map.insert('\u{04F1}', "\u{0443}\u{0308}");
-        map.insert('\u{04F2}', "\u{0423}\u{030B}");
-        map.insert('\u{04F3}', "\u{0443}\u{030B}");
-        map.insert('\u{04F4}', "\u{0427}\u{0308}");
-        map.insert('\u{04F5}', "\u{0447}\u{0308}");
-        map.insert('\u{04F8}', "\u{042B}\u{0308}");
-        map.insert('\u{04F9}', "\u{044B}\u{0308}");
-        map.insert('\u{0929}', "\u{0928}\u{093C}");
-        map.insert('\u{0931}', "\u{0930}\u{093C}");
-        map.insert('\u{0934}', "\u{0933}\u{093C}");
-        map.insert('\u{0958}', "\u{0915}\u{093C}");
-        map.insert('\u{0959}', "\u{0916}\u{093C}");
-        map.insert('\u{095A}', "\u{0917}\u{093C}");
-        map.insert('\u{095B}', "\u{091C}\u{093C}");
-        map.insert('\u{095C}', "\u{0921}\u{093C}");
-        map.insert('\u{095D}', "\u{0922}\u{093C}");
-        map.insert('\u{095E}', "\u{092B}\u{093C}");
-        map.insert('\u{095F}', "\u{092F}\u{093C}");
-        map.insert('\u{09B0}', "\u{09AC}\u{09BC}");
-        map.insert('\u{09CB}', "\u{09C7}\u{09BE}");
-        map.insert('\u{09CC}', "\u{09C7}\u{09D7}");
-        map.insert('\u{09DC}', "\u{09A1}\u{09BC}");
-        map.insert('\u{09DD}', "\u{09A2}\u{09BC}");
-        map.insert('\u{09DF}', "\u{09AF}\u{09BC}");
-        map.insert('\u{0A59}', "\u{0A16}\u{0A3C}");
-        map.insert('\u{0A5A}', "\u{0A17}\u{0A3C}");
-        map.insert('\u{0A5B}', "\u{0A1C}\u{0A3C}");
-        map.insert('\u{0A5C}', "\u{0A21}\u{0A3C}");
-        map.insert('\u{0A5E}', "\u{0A2B}\u{0A3C}");
-        map.insert('\u{0B48}', "\u{0B47}\u{0B56}");
-        map.insert('\u{0B4B}', "\u{0B47}\u{0B3E}");
-        map.insert('\u{0B4C}', "\u{0B47}\u{0B57}");
-        map.insert('\u{0B5C}', "\u{0B21}\u{0B3C}");
-        map.insert('\u{0B5D}', "\u{0B22}\u{0B3C}");
-        map.insert('\u{0B5F}', "\u{0B2F}\u{0B3C}");
-        map.insert('\u{0B94}', "\u{0B92}\u{0BD7}");
-        map.insert('\u{0BCA}', "\u{0BC6}\u{0BBE}");
-        map.insert('\u{0BCB}', "\u{0BC7}\u{0BBE}");
-        map.insert('\u{0BCC}', "\u{0BC6}\u{0BD7}");
-        map.insert('\u{0C48}', "\u{0C46}\u{0C56}");
-        map.insert('\u{0CC0}', "\u{0CBF}\u{0CD5}");
-        map.insert('\u{0CC7}', "\u{0CC6}\u{0CD5}");
-        map.insert('\u{0CC8}', "\u{0CC6}\u{0CD6}");
-        map.insert('\u{0CCA}', "\u{0CC6}\u{0CC2}");
-        map.insert('\u{0CCB}', "\u{0CC6}\u{0CC2}\u{0CD5}");
-        map.insert('\u{0D4A}', "\u{0D46}\u{0D3E}");
-        map.insert('\u{0D4B}', "\u{0D47}\u{0D3E}");
-        map.insert('\u{0D4C}', "\u{0D46}\u{0D57}");
-        map.insert('\u{0E33}', "\u{0E4D}\u{0E32}");
-        map.insert('\u{0EB3}', "\u{0ECD}\u{0EB2}");
-        map.insert('\u{0F43}', "\u{0F42}\u{0FB7}");
-        map.insert('\u{0F4D}', "\u{0F4C}\u{0FB7}");
-        map.insert('\u{0F52}', "\u{0F51}\u{0FB7}");
-        map.insert('\u{0F57}', "\u{0F56}\u{0FB7}");
-        map.insert('\u{0F5C}', "\u{0F5B}\u{0FB7}");
-        map.insert('\u{0F69}', "\u{0F40}\u{0FB5}");
-        map.insert('\u{0F73}', "\u{0F72}\u{0F71}");
-        map.insert('\u{0F75}', "\u{0F74}\u{0F71}");
-        map.insert('\u{0F76}', "\u{0FB2}\u{0F80}");
-        map.insert('\u{0F77}', "\u{0FB2}\u{0F80}\u{0F71}");
-        map.insert('\u{0F78}', "\u{0FB3}\u{0F80}");
-        map.insert('\u{0F79}', "\u{0FB3}\u{0F80}\u{0F71}");
-        map.insert('\u{0F81}', "\u{0F80}\u{0F71}");
-        map.insert('\u{0F93}', "\u{0F92}\u{0FB7}");
-        map.insert('\u{0F9D}', "\u{0F9C}\u{0FB7}");
-        map.insert('\u{0FA2}', "\u{0FA1}\u{0FB7}");
-        map.insert('\u{0FA7}', "\u{0FA6}\u{0FB7}");
-        map.insert('\u{0FAC}', "\u{0FAB}\u{0FB7}");
-        map.insert('\u{0FB9}', "\u{0F90}\u{0FB5}");
-        map.insert('\u{1E00}', "\u{0041}\u{0325}");
-        map.insert('\u{1E01}', "\u{0061}\u{0325}");
-        map.insert('\u{1E02}', "\u{0042}\u{0307}");
-        map.insert('\u{1E03}', "\u{0062}\u{0307}");
-        map.insert('\u{1E04}', "\u{0042}\u{0323}");
-        map.insert('\u{1E05}', "\u{0062}\u{0323}");
-        map.insert('\u{1E06}', "\u{0042}\u{0331}");
-        map.insert('\u{1E07}', "\u{0062}\u{0331}");
-        map.insert('\u{1E08}', "\u{0043}\u{0327}\u{0301}");
-        map.insert('\u{1E09}', "\u{0063}\u{0327}\u{0301}");
-        map.insert('\u{1E0A}', "\u{0044}\u{0307}");
-        map.insert('\u{1E0B}', "\u{0064}\u{0307}");
-        map.insert('\u{1E0C}', "\u{0044}\u{0323}");
-        map.insert('\u{1E0D}', "\u{0064}\u{0323}");
-        map.insert('\u{1E0E}', "\u{0044}\u{0331}");
-        map.insert('\u{1E0F}', "\u{0064}\u{0331}");
-        map.insert('\u{1E10}', "\u{0044}\u{0327}");
-        map.insert('\u{1E11}', "\u{0064}\u{0327}");
-        map.insert('\u{1E12}', "\u{0044}\u{032D}");
-        map.insert('\u{1E13}', "\u{0064}\u{032D}");
-        map.insert('\u{1E14}', "\u{0045}\u{0304}\u{0300}");
-        map.insert('\u{1E15}', "\u{0065}\u{0304}\u{0300}");
-        map.insert('\u{1E16}', "\u{0045}\u{0304}\u{0301}");
-        map.insert('\u{1E17}', "\u{0065}\u{0304}\u{0301}");
-        map.insert('\u{1E18}', "\u{0045}\u{032D}");
-        map.insert('\u{1E19}', "\u{0065}\u{032D}");
-        map.insert('\u{1E1A}', "\u{0045}\u{0330}");
-        map.insert('\u{1E1B}', "\u{0065}\u{0330}");
-        map.insert('\u{1E1C}', "\u{0045}\u{0327}\u{0306}");
-        map.insert('\u{1E1D}', "\u{0065}\u{0327}\u{0306}");
-        map.insert('\u{1E1E}', "\u{0046}\u{0307}");
-        map.insert('\u{1E1F}', "\u{0066}\u{0307}");
-        map.insert('\u{1E20}', "\u{0047}\u{0304}");
-        map.insert('\u{1E21}', "\u{0067}\u{0304}");
-        map.insert('\u{1E22}', "\u{0048}\u{0307}");
-        map.insert('\u{1E23}', "\u{0068}\u{0307}");
-        map.insert('\u{1E24}', "\u{0048}\u{0323}");
-        map.insert('\u{1E25}', "\u{0068}\u{0323}");
-        map.insert('\u{1E26}', "\u{0048}\u{0308}");
-        map.insert('\u{1E27}', "\u{0068}\u{0308}");
-        map.insert('\u{1E28}', "\u{0048}\u{0327}");
-        map.insert('\u{1E29}', "\u{0068}\u{0327}");
-        map.insert('\u{1E2A}', "\u{0048}\u{032E}");
-        map.insert('\u{1E2B}', "\u{0068}\u{032E}");
-        map.insert('\u{1E2C}', "\u{0049}\u{0330}");
-        map.insert('\u{1E2D}', "\u{0069}\u{0330}");
-        map.insert('\u{1E2E}', "\u{0049}\u{0308}\u{0301}");
-        map.insert('\u{1E2F}', "\u{0069}\u{0308}\u{0301}");
-        map.insert('\u{1E30}', "\u{004B}\u{0301}");
-        map.insert('\u{1E31}', "\u{006B}\u{0301}");
-        map.insert('\u{1E32}', "\u{004B}\u{0323}");
-        map.insert('\u{1E33}', "\u{006B}\u{0323}");
-        map.insert('\u{1E34}', "\u{004B}\u{0331}");
-        map.insert('\u{1E35}', "\u{006B}\u{0331}");
-        map.insert('\u{1E36}', "\u{004C}\u{0323}");
-        map.insert('\u{1E37}', "\u{006C}\u{0323}");
-        map.insert('\u{1E38}', "\u{004C}\u{0323}\u{0304}");
-        map.insert('\u{1E39}', "\u{006C}\u{0323}\u{0304}");
-        map.insert('\u{1E3A}', "\u{004C}\u{0331}");
-        map.insert('\u{1E3B}', "\u{006C}\u{0331}");
-        map.insert('\u{1E3C}', "\u{004C}\u{032D}");
-        map.insert('\u{1E3D}', "\u{006C}\u{032D}");
-        map.insert('\u{1E3E}', "\u{004D}\u{0301}");
-        map.insert('\u{1E3F}', "\u{006D}\u{0301}");
-        map.insert('\u{1E40}', "\u{004D}\u{0307}");
-        map.insert('\u{1E41}', "\u{006D}\u{0307}");
-        map.insert('\u{1E42}', "\u{004D}\u{0323}");
-        map.insert('\u{1E43}', "\u{006D}\u{0323}");
-        map.insert('\u{1E44}', "\u{004E}\u{0307}");
-        map.insert('\u{1E45}', "\u{006E}\u{0307}");
-        map.insert('\u{1E46}', "\u{004E}\u{0323}");
-        map.insert('\u{1E47}', "\u{006E}\u{0323}");
-        map.insert('\u{1E48}', "\u{004E}\u{0331}");
-        map.insert('\u{1E49}', "\u{006E}\u{0331}");
-        map.insert('\u{1E4A}', "\u{004E}\u{032D}");
-        map.insert('\u{1E4B}', "\u{006E}\u{032D}");
-        map.insert('\u{1E4C}', "\u{004F}\u{0303}\u{0301}");
-        map.insert('\u{1E4D}', "\u{006F}\u{0303}\u{0301}");
-        map.insert('\u{1E4E}', "\u{004F}\u{0303}\u{0308}");
-        map.insert('\u{1E4F}', "\u{006F}\u{0303}\u{0308}");
-        map.insert('\u{1E50}', "\u{004F}\u{0304}\u{0300}");
-        map.insert('\u{1E51}', "\u{006F}\u{0304}\u{0300}");
-        map.insert('\u{1E52}', "\u{004F}\u{0304}\u{0301}");
-        map.insert('\u{1E53}', "\u{006F}\u{0304}\u{0301}");
-        map.insert('\u{1E54}', "\u{0050}\u{0301}");
-        map.insert('\u{1E55}', "\u{0070}\u{0301}");
-        map.insert('\u{1E56}', "\u{0050}\u{0307}");
-        map.insert('\u{1E57}', "\u{0070}\u{0307}");
-        map.insert('\u{1E58}', "\u{0052}\u{0307}");
-        map.insert('\u{1E59}', "\u{0072}\u{0307}");
-        map.insert('\u{1E5A}', "\u{0052}\u{0323}");
-        map.insert('\u{1E5B}', "\u{0072}\u{0323}");
-        map.insert('\u{1E5C}', "\u{0052}\u{0323}\u{0304}");
-        map.insert('\u{1E5D}', "\u{0072}\u{0323}\u{0304}");
-        map.insert('\u{1E5E}', "\u{0052}\u{0331}");
-        map.insert('\u{1E5F}', "\u{0072}\u{0331}");
-        map.insert('\u{1E60}', "\u{0053}\u{0307}");
-        map.insert('\u{1E61}', "\u{0073}\u{0307}");
-        map.insert('\u{1E62}', "\u{0053}\u{0323}");
-        map.insert('\u{1E63}', "\u{0073}\u{0323}");
-        map.insert('\u{1E64}', "\u{0053}\u{0301}\u{0307}");
-        map.insert('\u{1E65}', "\u{0073}\u{0301}\u{0307}");
-        map.insert('\u{1E66}', "\u{0053}\u{030C}\u{0307}");
-        map.insert('\u{1E67}', "\u{0073}\u{030C}\u{0307}");
-        map.insert('\u{1E68}', "\u{0053}\u{0323}\u{0307}");
-        map.insert('\u{1E69}', "\u{0073}\u{0323}\u{0307}");
-        map.insert('\u{1E6A}', "\u{0054}\u{0307}");
-        map.insert('\u{1E6B}', "\u{0074}\u{0307}");
-        map.insert('\u{1E6C}', "\u{0054}\u{0323}");
-        map.insert('\u{1E6D}', "\u{0074}\u{0323}");
-        map.insert('\u{1E6E}', "\u{0054}\u{0331}");
-        map.insert('\u{1E6F}', "\u{0074}\u{0331}");
-        map.insert('\u{1E70}', "\u{0054}\u{032D}");
-        map.insert('\u{1E71}', "\u{0074}\u{032D}");
-        map.insert('\u{1E72}', "\u{0055}\u{0324}");
-        map.insert('\u{1E73}', "\u{0075}\u{0324}");
-        map.insert('\u{1E74}', "\u{0055}\u{0330}");
-        map.insert('\u{1E75}', "\u{0075}\u{0330}");
-        map.insert('\u{1E76}', "\u{0055}\u{032D}");
-        map.insert('\u{1E77}', "\u{0075}\u{032D}");
-        map.insert('\u{1E78}', "\u{0055}\u{0303}\u{0301}");
-        map.insert('\u{1E79}', "\u{0075}\u{0303}\u{0301}");
-        map.insert('\u{1E7A}', "\u{0055}\u{0304}\u{0308}");
-        map.insert('\u{1E7B}', "\u{0075}\u{0304}\u{0308}");
-        map.insert('\u{1E7C}', "\u{0056}\u{0303}");
-        map.insert('\u{1E7D}', "\u{0076}\u{0303}");
-        map.insert('\u{1E7E}', "\u{0056}\u{0323}");
-        map.insert('\u{1E7F}', "\u{0076}\u{0323}");
-        map.insert('\u{1E80}', "\u{0057}\u{0300}");
-        map.insert('\u{1E81}', "\u{0077}\u{0300}");
-        map.insert('\u{1E82}', "\u{0057}\u{0301}");
-        map.insert('\u{1E83}', "\u{0077}\u{0301}");
-        map.insert('\u{1E84}', "\u{0057}\u{0308}");
-        map.insert('\u{1E85}', "\u{0077}\u{0308}");
-        map.insert('\u{1E86}', "\u{0057}\u{0307}");
-        map.insert('\u{1E87}', "\u{0077}\u{0307}");
-        map.insert('\u{1E88}', "\u{0057}\u{0323}");
-        map.insert('\u{1E89}', "\u{0077}\u{0323}");
-        map.insert('\u{1E8A}', "\u{0058}\u{0307}");
-        map.insert('\u{1E8B}', "\u{0078}\u{0307}");
-        map.insert('\u{1E8C}', "\u{0058}\u{0308}");
-        map.insert('\u{1E8D}', "\u{0078}\u{0308}");
-        map.insert('\u{1E8E}', "\u{0059}\u{0307}");
-        map.insert('\u{1E8F}', "\u{0079}\u{0307}");
-        map.insert('\u{1E90}', "\u{005A}\u{0302}");
-        map.insert('\u{1E91}', "\u{007A}\u{0302}");
-        map.insert('\u{1E92}', "\u{005A}\u{0323}");
-        map.insert('\u{1E93}', "\u{007A}\u{0323}");
-        map.insert('\u{1E94}', "\u{005A}\u{0331}");
-        map.insert('\u{1E95}', "\u{007A}\u{0331}");
-        map.insert('\u{1E96}', "\u{0068}\u{0331}");
-        map.insert('\u{1E97}', "\u{0074}\u{0308}");
-        map.insert('\u{1E98}', "\u{0077}\u{030A}");
-        map.insert('\u{1E99}', "\u{0079}\u{030A}");
-        map.insert('\u{1E9B}', "\u{017F}\u{0307}");
-        map.insert('\u{1EA0}', "\u{0041}\u{0323}");
-        map.insert('\u{1EA1}', "\u{0061}\u{0323}");
-        map.insert('\u{1EA2}', "\u{0041}\u{0309}");
-        map.insert('\u{1EA3}', "\u{0061}\u{0309}");
-        map.insert('\u{1EA4}', "\u{0041}\u{0302}\u{0301}");
-        map.insert('\u{1EA5}', "\u{0061}\u{0302}\u{0301}");
-        map.insert('\u{1EA6}', "\u{0041}\u{0302}\u{0300}");
-        map.insert('\u{1EA7}', "\u{0061}\u{0302}\u{0300}");
-        map.insert('\u{1EA8}', "\u{0041}\u{0302}\u{0309}");
-        map.insert('\u{1EA9}', "\u{0061}\u{0302}\u{0309}");
-        map.insert('\u{1EAA}', "\u{0041}\u{0302}\u{0303}");
-        map.insert('\u{1EAB}', "\u{0061}\u{0302}\u{0303}");
-        map.insert('\u{1EAC}', "\u{0041}\u{0323}\u{0302}");
-        map.insert('\u{1EAD}', "\u{0061}\u{0323}\u{0302}");
-        map.insert('\u{1EAE}', "\u{0041}\u{0306}\u{0301}");
-        map.insert('\u{1EAF}', "\u{0061}\u{0306}\u{0301}");
-        map.insert('\u{1EB0}', "\u{0041}\u{0306}\u{0300}");
-        map.insert('\u{1EB1}', "\u{0061}\u{0306}\u{0300}");
-        map.insert('\u{1EB2}', "\u{0041}\u{0306}\u{0309}");
-        map.insert('\u{1EB3}', "\u{0061}\u{0306}\u{0309}");
-        map.insert('\u{1EB4}', "\u{0041}\u{0306}\u{0303}");
-        map.insert('\u{1EB5}', "\u{0061}\u{0306}\u{0303}");
-        map.insert('\u{1EB6}', "\u{0041}\u{0323}\u{0306}");
-        map.insert('\u{1EB7}', "\u{0061}\u{0323}\u{0306}");
-        map.insert('\u{1EB8}', "\u{0045}\u{0323}");
-        map.insert('\u{1EB9}', "\u{0065}\u{0323}");
-        map.insert('\u{1EBA}', "\u{0045}\u{0309}");
-        map.insert('\u{1EBB}', "\u{0065}\u{0309}");
-        map.insert('\u{1EBC}', "\u{0045}\u{0303}");
-        map.insert('\u{1EBD}', "\u{0065}\u{0303}");
-        map.insert('\u{1EBE}', "\u{0045}\u{0302}\u{0301}");
-        map.insert('\u{1EBF}', "\u{0065}\u{0302}\u{0301}");
-        map.insert('\u{1EC0}', "\u{0045}\u{0302}\u{0300}");
-        map.insert('\u{1EC1}', "\u{0065}\u{0302}\u{0300}");
-        map.insert('\u{1EC2}', "\u{0045}\u{0302}\u{0309}");
-        map.insert('\u{1EC3}', "\u{0065}\u{0302}\u{0309}");
-        map.insert('\u{1EC4}', "\u{0045}\u{0302}\u{0303}");
-        map.insert('\u{1EC5}', "\u{0065}\u{0302}\u{0303}");
-        map.insert('\u{1EC6}', "\u{0045}\u{0323}\u{0302}");
-        map.insert('\u{1EC7}', "\u{0065}\u{0323}\u{0302}");
-        map.insert('\u{1EC8}', "\u{0049}\u{0309}");
-        map.insert('\u{1EC9}', "\u{0069}\u{0309}");
-        map.insert('\u{1ECA}', "\u{0049}\u{0323}");
-        map.insert('\u{1ECB}', "\u{0069}\u{0323}");
-        map.insert('\u{1ECC}', "\u{004F}\u{0323}");
-        map.insert('\u{1ECD}', "\u{006F}\u{0323}");
-        map.insert('\u{1ECE}', "\u{004F}\u{0309}");
-        map.insert('\u{1ECF}', "\u{006F}\u{0309}");
-        map.insert('\u{1ED0}', "\u{004F}\u{0302}\u{0301}");
-        map.insert('\u{1ED1}', "\u{006F}\u{0302}\u{0301}");
-        map.insert('\u{1ED2}', "\u{004F}\u{0302}\u{0300}");
-        map.insert('\u{1ED3}', "\u{006F}\u{0302}\u{0300}");
-        map.insert('\u{1ED4}', "\u{004F}\u{0302}\u{0309}");
-        map.insert('\u{1ED5}', "\u{006F}\u{0302}\u{0309}");
-        map.insert('\u{1ED6}', "\u{004F}\u{0302}\u{0303}");
-        map.insert('\u{1ED7}', "\u{006F}\u{0302}\u{0303}");
-        map.insert('\u{1ED8}', "\u{004F}\u{0323}\u{0302}");
-        map.insert('\u{1ED9}', "\u{006F}\u{0323}\u{0302}");
-        map.insert('\u{1EDA}', "\u{004F}\u{031B}\u{0301}");
-        map.insert('\u{1EDB}', "\u{006F}\u{031B}\u{0301}");
-        map.insert('\u{1EDC}', "\u{004F}\u{031B}\u{0300}");
-        map.insert('\u{1EDD}', "\u{006F}\u{031B}\u{0300}");
-        map.insert('\u{1EDE}', "\u{004F}\u{031B}\u{0309}");
-        map.insert('\u{1EDF}', "\u{006F}\u{031B}\u{0309}");
-        map.insert('\u{1EE0}', "\u{004F}\u{031B}\u{0303}");
-        map.insert('\u{1EE1}', "\u{006F}\u{031B}\u{0303}");
-        map.insert('\u{1EE2}', "\u{004F}\u{031B}\u{0323}");
-        map.insert('\u{1EE3}', "\u{006F}\u{031B}\u{0323}");
-        map.insert('\u{1EE4}', "\u{0055}\u{0323}");
-        map.insert('\u{1EE5}', "\u{0075}\u{0323}");
-        map.insert('\u{1EE6}', "\u{0055}\u{0309}");
-        map.insert('\u{1EE7}', "\u{0075}\u{0309}");
-        map.insert('\u{1EE8}', "\u{0055}\u{031B}\u{0301}");
-        map.insert('\u{1EE9}', "\u{0075}\u{031B}\u{0301}");
-        map.insert('\u{1EEA}', "\u{0055}\u{031B}\u{0300}");
-        map.insert('\u{1EEB}', "\u{0075}\u{031B}\u{0300}");
-        map.insert('\u{1EEC}', "\u{0055}\u{031B}\u{0309}");
-        map.insert('\u{1EED}', "\u{0075}\u{031B}\u{0309}");
-        map.insert('\u{1EEE}', "\u{0055}\u{031B}\u{0303}");
-        map.insert('\u{1EEF}', "\u{0075}\u{031B}\u{0303}");
-        map.insert('\u{1EF0}', "\u{0055}\u{031B}\u{0323}");
-        map.insert('\u{1EF1}', "\u{0075}\u{031B}\u{0323}");
-        map.insert('\u{1EF2}', "\u{0059}\u{0300}");
-        map.insert('\u{1EF3}', "\u{0079}\u{0300}");
-        map.insert('\u{1EF4}', "\u{0059}\u{0323}");
-        map.insert('\u{1EF5}', "\u{0079}\u{0323}");
-        map.insert('\u{1EF6}', "\u{0059}\u{0309}");
-        map.insert('\u{1EF7}', "\u{0079}\u{0309}");
-        map.insert('\u{1EF8}', "\u{0059}\u{0303}");
-        map.insert('\u{1EF9}', "\u{0079}\u{0303}");
-        map.insert('\u{1F00}', "\u{03B1}\u{0313}");
-        map.insert('\u{1F01}', "\u{03B1}\u{0314}");
-        map.insert('\u{1F02}', "\u{03B1}\u{0313}\u{0300}");
-        map.insert('\u{1F03}', "\u{03B1}\u{0314}\u{0300}");
-        map.insert('\u{1F04}', "\u{03B1}\u{0313}\u{0301}");
-        map.insert('\u{1F05}', "\u{03B1}\u{0314}\u{0301}");
-        map.insert('\u{1F06}', "\u{03B1}\u{0313}\u{0342}");
-        map.insert('\u{1F07}', "\u{03B1}\u{0314}\u{0342}");
-        map.insert('\u{1F08}', "\u{0391}\u{0313}");
-        map.insert('\u{1F09}', "\u{0391}\u{0314}");
-        map.insert('\u{1F0A}', "\u{0391}\u{0313}\u{0300}");
-        map.insert('\u{1F0B}', "\u{0391}\u{0314}\u{0300}");
-        map.insert('\u{1F0C}', "\u{0391}\u{0313}\u{0301}");
-        map.insert('\u{1F0D}', "\u{0391}\u{0314}\u{0301}");
-        map.insert('\u{1F0E}', "\u{0391}\u{0313}\u{0342}");
-        map.insert('\u{1F0F}', "\u{0391}\u{0314}\u{0342}");
-        map.insert('\u{1F10}', "\u{03B5}\u{0313}");
-        map.insert('\u{1F11}', "\u{03B5}\u{0314}");
-        map.insert('\u{1F12}', "\u{03B5}\u{0313}\u{0300}");
-        map.insert('\u{1F13}', "\u{03B5}\u{0314}\u{0300}");
-        map.insert('\u{1F14}', "\u{03B5}\u{0313}\u{0301}");
-        map.insert('\u{1F15}', "\u{03B5}\u{0314}\u{0301}");
-        map.insert('\u{1F18}', "\u{0395}\u{0313}");
-        map.insert('\u{1F19}', "\u{0395}\u{0314}");
-        map.insert('\u{1F1A}', "\u{0395}\u{0313}\u{0300}");
-        map.insert('\u{1F1B}', "\u{0395}\u{0314}\u{0300}");
-        map.insert('\u{1F1C}', "\u{0395}\u{0313}\u{0301}");
-        map.insert('\u{1F1D}', "\u{0395}\u{0314}\u{0301}");
-        map.insert('\u{1F20}', "\u{03B7}\u{0313}");
-        map.insert('\u{1F21}', "\u{03B7}\u{0314}");
-        map.insert('\u{1F22}', "\u{03B7}\u{0313}\u{0300}");
-        map.insert('\u{1F23}', "\u{03B7}\u{0314}\u{0300}");
-        map.insert('\u{1F24}', "\u{03B7}\u{0313}\u{0301}");
-        map.insert('\u{1F25}', "\u{03B7}\u{0314}\u{0301}");
-        map.insert('\u{1F26}', "\u{03B7}\u{0313}\u{0342}");
-        map.insert('\u{1F27}', "\u{03B7}\u{0314}\u{0342}");
-        map.insert('\u{1F28}', "\u{0397}\u{0313}");
-        map.insert('\u{1F29}', "\u{0397}\u{0314}");
-        map.insert('\u{1F2A}', "\u{0397}\u{0313}\u{0300}");
-        map.insert('\u{1F2B}', "\u{0397}\u{0314}\u{0300}");
-        map.insert('\u{1F2C}', "\u{0397}\u{0313}\u{0301}");
-        map.insert('\u{1F2D}', "\u{0397}\u{0314}\u{0301}");
-        map.insert('\u{1F2E}', "\u{0397}\u{0313}\u{0342}");
-        map.insert('\u{1F2F}', "\u{0397}\u{0314}\u{0342}");
-        map.insert('\u{1F30}', "\u{03B9}\u{0313}");
-        map.insert('\u{1F31}', "\u{03B9}\u{0314}");
-        map.insert('\u{1F32}', "\u{03B9}\u{0313}\u{0300}");
-        map.insert('\u{1F33}', "\u{03B9}\u{0314}\u{0300}");
-        map.insert('\u{1F34}', "\u{03B9}\u{0313}\u{0301}");
-        map.insert('\u{1F35}', "\u{03B9}\u{0314}\u{0301}");
-        map.insert('\u{1F36}', "\u{03B9}\u{0313}\u{0342}");
-        map.insert('\u{1F37}', "\u{03B9}\u{0314}\u{0342}");
-        map.insert('\u{1F38}', "\u{0399}\u{0313}");
-        map.insert('\u{1F39}', "\u{0399}\u{0314}");
-        map.insert('\u{1F3A}', "\u{0399}\u{0313}\u{0300}");
-        map.insert('\u{1F3B}', "\u{0399}\u{0314}\u{0300}");
-        map.insert('\u{1F3C}', "\u{0399}\u{0313}\u{0301}");
-        map.insert('\u{1F3D}', "\u{0399}\u{0314}\u{0301}");
-        map.insert('\u{1F3E}', "\u{0399}\u{0313}\u{0342}");
-        map.insert('\u{1F3F}', "\u{0399}\u{0314}\u{0342}");
-        map.insert('\u{1F40}', "\u{03BF}\u{0313}");
-        map.insert('\u{1F41}', "\u{03BF}\u{0314}");
-        map.insert('\u{1F42}', "\u{03BF}\u{0313}\u{0300}");
-        map.insert('\u{1F43}', "\u{03BF}\u{0314}\u{0300}");
-        map.insert('\u{1F44}', "\u{03BF}\u{0313}\u{0301}");
-        map.insert('\u{1F45}', "\u{03BF}\u{0314}\u{0301}");
-        map.insert('\u{1F48}', "\u{039F}\u{0313}");
-        map.insert('\u{1F49}', "\u{039F}\u{0314}");
-        map.insert('\u{1F4A}', "\u{039F}\u{0313}\u{0300}");
-        map.insert('\u{1F4B}', "\u{039F}\u{0314}\u{0300}");
-        map.insert('\u{1F4C}', "\u{039F}\u{0313}\u{0301}");
-        map.insert('\u{1F4D}', "\u{039F}\u{0314}\u{0301}");
-        map.insert('\u{1F50}', "\u{03C5}\u{0313}");
-        map.insert('\u{1F51}', "\u{03C5}\u{0314}");
-        map.insert('\u{1F52}', "\u{03C5}\u{0313}\u{0300}");
-        map.insert('\u{1F53}', "\u{03C5}\u{0314}\u{0300}");
-        map.insert('\u{1F54}', "\u{03C5}\u{0313}\u{0301}");
-        map.insert('\u{1F55}', "\u{03C5}\u{0314}\u{0301}");
-        map.insert('\u{1F56}', "\u{03C5}\u{0313}\u{0342}");
-        map.insert('\u{1F57}', "\u{03C5}\u{0314}\u{0342}");
-        map.insert('\u{1F59}', "\u{03A5}\u{0314}");
-        map.insert('\u{1F5B}', "\u{03A5}\u{0314}\u{0300}");
-        map.insert('\u{1F5D}', "\u{03A5}\u{0314}\u{0301}");
-        map.insert('\u{1F5F}', "\u{03A5}\u{0314}\u{0342}");
-        map.insert('\u{1F60}', "\u{03C9}\u{0313}");
-        map.insert('\u{1F61}', "\u{03C9}\u{0314}");
-        map.insert('\u{1F62}', "\u{03C9}\u{0313}\u{0300}");
-        map.insert('\u{1F63}', "\u{03C9}\u{0314}\u{0300}");
-        map.insert('\u{1F64}', "\u{03C9}\u{0313}\u{0301}");
-        map.insert('\u{1F65}', "\u{03C9}\u{0314}\u{0301}");
-        map.insert('\u{1F66}', "\u{03C9}\u{0313}\u{0342}");
-        map.insert('\u{1F67}', "\u{03C9}\u{0314}\u{0342}");
-        map.insert('\u{1F68}', "\u{03A9}\u{0313}");
-        map.insert('\u{1F69}', "\u{03A9}\u{0314}");
-        map.insert('\u{1F6A}', "\u{03A9}\u{0313}\u{0300}");
-        map.insert('\u{1F6B}', "\u{03A9}\u{0314}\u{0300}");
-        map.insert('\u{1F6C}', "\u{03A9}\u{0313}\u{0301}");
-        map.insert('\u{1F6D}', "\u{03A9}\u{0314}\u{0301}");
-        map.insert('\u{1F6E}', "\u{03A9}\u{0313}\u{0342}");
-        map.insert('\u{1F6F}', "\u{03A9}\u{0314}\u{0342}");
-        map.insert('\u{1F70}', "\u{03B1}\u{0300}");
-        map.insert('\u{1F71}', "\u{03B1}\u{0301}");
-        map.insert('\u{1F72}', "\u{03B5}\u{0300}");
-        map.insert('\u{1F73}', "\u{03B5}\u{0301}");
-        map.insert('\u{1F74}', "\u{03B7}\u{0300}");
-        map.insert('\u{1F75}', "\u{03B7}\u{0301}");
-        map.insert('\u{1F76}', "\u{03B9}\u{0300}");
-        map.insert('\u{1F77}', "\u{03B9}\u{0301}");
-        map.insert('\u{1F78}', "\u{03BF}\u{0300}");
-        map.insert('\u{1F79}', "\u{03BF}\u{0301}");
-        map.insert('\u{1F7A}', "\u{03C5}\u{0300}");
-        map.insert('\u{1F7B}', "\u{03C5}\u{0301}");
-        map.insert('\u{1F7C}', "\u{03C9}\u{0300}");
-        map.insert('\u{1F7D}', "\u{03C9}\u{0301}");
-        map.insert('\u{1F80}', "\u{03B1}\u{0345}\u{0313}");
-        map.insert('\u{1F81}', "\u{03B1}\u{0345}\u{0314}");
-        map.insert('\u{1F82}', "\u{03B1}\u{0345}\u{0313}\u{0300}");
-        map.insert('\u{1F83}', "\u{03B1}\u{0345}\u{0314}\u{0300}");
-        map.insert('\u{1F84}', "\u{03B1}\u{0345}\u{0313}\u{0301}");
-        map.insert('\u{1F85}', "\u{03B1}\u{0345}\u{0314}\u{0301}");
-        map.insert('\u{1F86}', "\u{03B1}\u{0345}\u{0313}\u{0342}");
-        map.insert('\u{1F87}', "\u{03B1}\u{0345}\u{0314}\u{0342}");
-        map.insert('\u{1F88}', "\u{0391}\u{0345}\u{0313}");
-        map.insert('\u{1F89}', "\u{0391}\u{0345}\u{0314}");
-        map.insert('\u{1F8A}', "\u{0391}\u{0345}\u{0313}\u{0300}");
-        map.insert('\u{1F8B}', "\u{0391}\u{0345}\u{0314}\u{0300}");
-        map.insert('\u{1F8C}', "\u{0391}\u{0345}\u{0313}\u{0301}");
-        map.insert('\u{1F8D}', "\u{0391}\u{0345}\u{0314}\u{0301}");
-        map.insert('\u{1F8E}', "\u{0391}\u{0345}\u{0313}\u{0342}");
-        map.insert('\u{1F8F}', "\u{0391}\u{0345}\u{0314}\u{0342}");
-        map.insert('\u{1F90}', "\u{03B7}\u{0345}\u{0313}");
-        map.insert('\u{1F91}', "\u{03B7}\u{0345}\u{0314}");
-        map.insert('\u{1F92}', "\u{03B7}\u{0345}\u{0313}\u{0300}");
-        map.insert('\u{1F93}', "\u{03B7}\u{0345}\u{0314}\u{0300}");
-        map.insert('\u{1F94}', "\u{03B7}\u{0345}\u{0313}\u{0301}");
-        map.insert('\u{1F95}', "\u{03B7}\u{0345}\u{0314}\u{0301}");
-        map.insert('\u{1F96}', "\u{03B7}\u{0345}\u{0313}\u{0342}");
-        map.insert('\u{1F97}', "\u{03B7}\u{0345}\u{0314}\u{0342}");
-        map.insert('\u{1F98}', "\u{0397}\u{0345}\u{0313}");
-        map.insert('\u{1F99}', "\u{0397}\u{0345}\u{0314}");
-        map.insert('\u{1F9A}', "\u{0397}\u{0345}\u{0313}\u{0300}");
-        map.insert('\u{1F9B}', "\u{0397}\u{0345}\u{0314}\u{0300}");
-        map.insert('\u{1F9C}', "\u{0397}\u{0345}\u{0313}\u{0301}");
-        map.insert('\u{1F9D}', "\u{0397}\u{0345}\u{0314}\u{0301}");
-        map.insert('\u{1F9E}', "\u{0397}\u{0345}\u{0313}\u{0342}");
-        map.insert('\u{1F9F}', "\u{0397}\u{0345}\u{0314}\u{0342}");
-        map.insert('\u{1FA0}', "\u{03C9}\u{0345}\u{0313}");
-        map.insert('\u{1FA1}', "\u{03C9}\u{0345}\u{0314}");
-        map.insert('\u{1FA2}', "\u{03C9}\u{0345}\u{0313}\u{0300}");
-        map.insert('\u{1FA3}', "\u{03C9}\u{0345}\u{0314}\u{0300}");
-        map.insert('\u{1FA4}', "\u{03C9}\u{0345}\u{0313}\u{0301}");
-        map.insert('\u{1FA5}', "\u{03C9}\u{0345}\u{0314}\u{0301}");
-        map.insert('\u{1FA6}', "\u{03C9}\u{0345}\u{0313}\u{0342}");
-        map.insert('\u{1FA7}', "\u{03C9}\u{0345}\u{0314}\u{0342}");
-        map.insert('\u{1FA8}', "\u{03A9}\u{0345}\u{0313}");
-        map.insert('\u{1FA9}', "\u{03A9}\u{0345}\u{0314}");
-        map.insert('\u{1FAA}', "\u{03A9}\u{0345}\u{0313}\u{0300}");
-        map.insert('\u{1FAB}', "\u{03A9}\u{0345}\u{0314}\u{0300}");
-        map.insert('\u{1FAC}', "\u{03A9}\u{0345}\u{0313}\u{0301}");
-        map.insert('\u{1FAD}', "\u{03A9}\u{0345}\u{0314}\u{0301}");
-        map.insert('\u{1FAE}', "\u{03A9}\u{0345}\u{0313}\u{0342}");
-        map.insert('\u{1FAF}', "\u{03A9}\u{0345}\u{0314}\u{0342}");
-        map.insert('\u{1FB0}', "\u{03B1}\u{0306}");
-        map.insert('\u{1FB1}', "\u{03B1}\u{0304}");
-        map.insert('\u{1FB2}', "\u{03B1}\u{0345}\u{0300}");
-        map.insert('\u{1FB3}', "\u{03B1}\u{0345}");
-        map.insert('\u{1FB4}', "\u{03B1}\u{0345}\u{0301}");
-        map.insert('\u{1FB6}', "\u{03B1}\u{0342}");
-        map.insert('\u{1FB7}', "\u{03B1}\u{0345}\u{0342}");
-        map.insert('\u{1FB8}', "\u{0391}\u{0306}");
-        map.insert('\u{1FB9}', "\u{0391}\u{0304}");
-        map.insert('\u{1FBA}', "\u{0391}\u{0300}");
-        map.insert('\u{1FBB}', "\u{0391}\u{0301}");
-        map.insert('\u{1FBC}', "\u{0391}\u{0345}");
-        map.insert('\u{1FC1}', "\u{00A8}\u{0342}");
-        map.insert('\u{1FC2}', "\u{03B7}\u{0345}\u{0300}");
-        map.insert('\u{1FC3}', "\u{03B7}\u{0345}");
-        map.insert('\u{1FC4}', "\u{03B7}\u{0345}\u{0301}");
-        map.insert('\u{1FC6}', "\u{03B7}\u{0342}");
-        map.insert('\u{1FC7}', "\u{03B7}\u{0345}\u{0342}");
-        map.insert('\u{1FC8}', "\u{0395}\u{0300}");
-        map.insert('\u{1FC9}', "\u{0395}\u{0301}");
-        map.insert('\u{1FCA}', "\u{0397}\u{0300}");
-        map.insert('\u{1FCB}', "\u{0397}\u{0301}");
-        map.insert('\u{1FCC}', "\u{0397}\u{0345}");
-        map.insert('\u{1FCD}', "\u{1FBF}\u{0300}");
-        map.insert('\u{1FCE}', "\u{1FBF}\u{0301}");
-        map.insert('\u{1FCF}', "\u{1FBF}\u{0342}");
-        map.insert('\u{1FD0}', "\u{03B9}\u{0306}");
-        map.insert('\u{1FD1}', "\u{03B9}\u{0304}");
-        map.insert('\u{1FD2}', "\u{03B9}\u{0308}\u{0300}");
-        map.insert('\u{1FD3}', "\u{03B9}\u{0308}\u{0301}");
-        map.insert('\u{1FD6}', "\u{03B9}\u{0342}");
-        map.insert('\u{1FD7}', "\u{03B9}\u{0308}\u{0342}");
-        map.insert('\u{1FD8}', "\u{0399}\u{0306}");
-        map.insert('\u{1FD9}', "\u{0399}\u{0304}");
-        map.insert('\u{1FDA}', "\u{0399}\u{0300}");
-        map.insert('\u{1FDB}', "\u{0399}\u{0301}");
-        map.insert('\u{1FDD}', "\u{1FFE}\u{0300}");
-        map.insert('\u{1FDE}', "\u{1FFE}\u{0301}");
-        map.insert('\u{1FDF}', "\u{1FFE}\u{0342}");
-        map.insert('\u{1FE0}', "\u{03C5}\u{0306}");
-        map.insert('\u{1FE1}', "\u{03C5}\u{0304}");
-        map.insert('\u{1FE2}', "\u{03C5}\u{0308}\u{0300}");
-        map.insert('\u{1FE3}', "\u{03C5}\u{0308}\u{0301}");
-        map.insert('\u{1FE4}', "\u{03C1}\u{0313}");
-        map.insert('\u{1FE5}', "\u{03C1}\u{0314}");
-        map.insert('\u{1FE6}', "\u{03C5}\u{0342}");
-        map.insert('\u{1FE7}', "\u{03C5}\u{0308}\u{0342}");
-        map.insert('\u{1FE8}', "\u{03A5}\u{0306}");
-        map.insert('\u{1FE9}', "\u{03A5}\u{0304}");
-        map.insert('\u{1FEA}', "\u{03A5}\u{0300}");
-        map.insert('\u{1FEB}', "\u{03A5}\u{0301}");
-        map.insert('\u{1FEC}', "\u{03A1}\u{0314}");
-        map.insert('\u{1FED}', "\u{00A8}\u{0300}");
-        map.insert('\u{1FEE}', "\u{00A8}\u{0301}");
-        map.insert('\u{1FF2}', "\u{03C9}\u{0345}\u{0300}");
-        map.insert('\u{1FF3}', "\u{03C9}\u{0345}");
-        map.insert('\u{1FF4}', "\u{03BF}\u{0345}\u{0301}");
-        map.insert('\u{1FF6}', "\u{03C9}\u{0342}");
-        map.insert('\u{1FF7}', "\u{03C9}\u{0345}\u{0342}");
-        map.insert('\u{1FF8}', "\u{039F}\u{0300}");
-        map.insert('\u{1FF9}', "\u{039F}\u{0301}");
-        map.insert('\u{1FFA}', "\u{03A9}\u{0300}");
-        map.insert('\u{1FFB}', "\u{03A9}\u{0301}");
-        map.insert('\u{1FFC}', "\u{03A9}\u{0345}");
-        map.insert('\u{304C}', "\u{304B}\u{3099}");
-        map.insert('\u{304E}', "\u{304D}\u{3099}");
-        map.insert('\u{3050}', "\u{304F}\u{3099}");
-        map.insert('\u{3052}', "\u{3051}\u{3099}");
-        map.insert('\u{3054}', "\u{3053}\u{3099}");
-        map.insert('\u{3056}', "\u{3055}\u{3099}");
-        map.insert('\u{3058}', "\u{3057}\u{3099}");
-        map.insert('\u{305A}', "\u{3059}\u{3099}");
-        map.insert('\u{305C}', "\u{305B}\u{3099}");
-        map.insert('\u{305E}', "\u{305D}\u{3099}");
-        map.insert('\u{3060}', "\u{305F}\u{3099}");
-        map.insert('\u{3062}', "\u{3061}\u{3099}");
-        map.insert('\u{3065}', "\u{3064}\u{3099}");
-        map.insert('\u{3067}', "\u{3066}\u{3099}");
-        map.insert('\u{3069}', "\u{3068}\u{3099}");
-        map.insert('\u{3070}', "\u{306F}\u{3099}");
-        map.insert('\u{3071}', "\u{306F}\u{309A}");
-        map.insert('\u{3073}', "\u{3072}\u{3099}");
-        map.insert('\u{3074}', "\u{3072}\u{309A}");
-        map.insert('\u{3076}', "\u{3075}\u{3099}");
-        map.insert('\u{3077}', "\u{3075}\u{309A}");
-        map.insert('\u{3079}', "\u{3078}\u{3099}");
-        map.insert('\u{307A}', "\u{3078}\u{309A}");
-        map.insert('\u{307C}', "\u{307B}\u{3099}");
-        map.insert('\u{307D}', "\u{307B}\u{309A}");
-        map.insert('\u{3094}', "\u{3046}\u{3099}");
-        map.insert('\u{309E}', "\u{309D}\u{3099}");
-        map.insert('\u{30AC}', "\u{30AB}\u{3099}");
-        map.insert('\u{30AE}', "\u{30AD}\u{3099}");
-        map.insert('\u{30B0}', "\u{30AF}\u{3099}");
-        map.insert('\u{30B2}', "\u{30B1}\u{3099}");
-        map.insert('\u{30B4}', "\u{30B3}\u{3099}");
-        map.insert('\u{30B6}', "\u{30B5}\u{3099}");
-        map.insert('\u{30B8}', "\u{30B7}\u{3099}");
-        map.insert('\u{30BA}', "\u{30B9}\u{3099}");
-        map.insert('\u{30BC}', "\u{30BB}\u{3099}");
-        map.insert('\u{30BE}', "\u{30BD}\u{3099}");
-        map.insert('\u{30C0}', "\u{30BF}\u{3099}");
-        map.insert('\u{30C2}', "\u{30C1}\u{3099}");
-        map.insert('\u{30C5}', "\u{30C4}\u{3099}");
-        map.insert('\u{30C7}', "\u{30C6}\u{3099}");
-        map.insert('\u{30C9}', "\u{30C8}\u{3099}");
-        map.insert('\u{30D0}', "\u{30CF}\u{3099}");
-        map.insert('\u{30D1}', "\u{30CF}\u{309A}");
-        map.insert('\u{30D3}', "\u{30D2}\u{3099}");
-        map.insert('\u{30D4}', "\u{30D2}\u{309A}");
-        map.insert('\u{30D6}', "\u{30D5}\u{3099}");
-        map.insert('\u{30D7}', "\u{30D5}\u{309A}");
-        map.insert('\u{30D9}', "\u{30D8}\u{3099}");
-        map.insert('\u{30DA}', "\u{30D8}\u{309A}");
-        map.insert('\u{30DC}', "\u{30DB}\u{3099}");
-        map.insert('\u{30DD}', "\u{30DB}\u{309A}");
-        map.insert('\u{30F4}', "\u{30A6}\u{3099}");
-        map.insert('\u{30F7}', "\u{30EF}\u{3099}");
-        map.insert('\u{30F8}', "\u{30F0}\u{3099}");
-        map.insert('\u{30F9}', "\u{30F1}\u{3099}");
-        map.insert('\u{30FA}', "\u{30F2}\u{3099}");
-        map.insert('\u{30FE}', "\u{30FD}\u{3099}");
-        map.insert('\u{FB1F}', "\u{05F2}\u{05B7}");
-        map.insert('\u{FB2A}', "\u{05E9}\u{05C1}");
-        map.insert('\u{FB2B}', "\u{05E9}\u{05C2}");
-        map.insert('\u{FB2C}', "\u{05E9}\u{05BC}\u{05C1}");
-        map.insert('\u{FB2D}', "\u{05E9}\u{05BC}\u{05C2}");
-        map.insert('\u{FB2E}', "\u{05D0}\u{05B7}");
-        map.insert('\u{FB2F}', "\u{05D0}\u{05B8}");
-        map.insert('\u{FB30}', "\u{05D0}\u{05BC}");
-        map.insert('\u{FB31}', "\u{05D1}\u{05BC}");
-        map.insert('\u{FB32}', "\u{05D2}\u{05BC}");
-        map.insert('\u{FB33}', "\u{05D3}\u{05BC}");
-        map.insert('\u{FB34}', "\u{05D4}\u{05BC}");
-        map.insert('\u{FB35}', "\u{05D5}\u{05BC}");
-        map.insert('\u{FB36}', "\u{05D6}\u{05BC}");
-        map.insert('\u{FB38}', "\u{05D8}\u{05BC}");
-        map.insert('\u{FB39}', "\u{05D9}\u{05BC}");
-        map.insert('\u{FB3A}', "\u{05DA}\u{05BC}");
-        map.insert('\u{FB3B}', "\u{05DB}\u{05BC}");
-        map.insert('\u{FB3C}', "\u{05DC}\u{05BC}");
-        map.insert('\u{FB3E}', "\u{05DE}\u{05BC}");
-        map.insert('\u{FB40}', "\u{05E0}\u{05BC}");
-        map.insert('\u{FB41}', "\u{05E1}\u{05BC}");
-        map.insert('\u{FB43}', "\u{05E3}\u{05BC}");
-        map.insert('\u{FB44}', "\u{05E4}\u{05BC}");
-        map.insert('\u{FB46}', "\u{05E6}\u{05BC}");
-        map.insert('\u{FB47}', "\u{05E7}\u{05BC}");
-        map.insert('\u{FB48}', "\u{05E8}\u{05BC}");
-        map.insert('\u{FB49}', "\u{05E9}\u{05BC}");
-        map.insert('\u{FB4A}', "\u{05EA}\u{05BC}");
-        map.insert('\u{FB4B}', "\u{05D5}\u{05B9}");
-        map.insert('\u{FB4C}', "\u{05D1}\u{05BF}");
-        map.insert('\u{FB4D}', "\u{05DB}\u{05BF}");
-        map.insert('\u{FB4E}', "\u{05E4}\u{05BF}");
-        return map;
-    };
-    /// Dictionary (map) from decomposed components to sub dictionaries and composed characters
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// assert_eq!((*MAP_TO_NORMAL).get(&'e').unwrap().next.unwrap().get(&'\u{0301}').unwrap().current.unwrap(), '\u{00E9}');
-    /// ```
-    pub static ref MAP_TO_NORMAL: AHashMap<char, ReverseTreeNode> = {
-        let mut root = AHashMap::new();
-        let mut u0041 = AHashMap::new();
-        u0041.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00C0}'), None));
-        u0041.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00C1}'), None));
-        let mut u0041_0302 = AHashMap::new();
-        u0041_0302.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EA4}'), None));
-        u0041_0302.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EA6}'), None));
-        u0041_0302.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EA8}'), None));
-        u0041_0302.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EAA}'), None));
-        u0041.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00C2}'), Some(Box::new(u0041_0302))));
-        u0041.insert('\u{0303}', ReverseTreeNode::new(Some('\u{00C3}'), None));
-        let mut u0041_0308 = AHashMap::new();
-        u0041_0308.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01DE}'), None));
-        u0041.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00C4}'), Some(Box::new(u0041_0308))));
-        let mut u0041_030a = AHashMap::new();
-        u0041_030a.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01FA}'), None));
-        u0041.insert('\u{030A}', ReverseTreeNode::new(Some('\u{00C5}'), Some(Box::new(u0041_030a))));
-        u0041.insert('\u{0304}', ReverseTreeNode::new(Some('\u{0100}'), None));
-        let mut u0041_0306 = AHashMap::new();
-        u0041_0306.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EAE}'), None));
-        u0041_0306.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EB0}'), None));
-        u0041_0306.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EB2}'), None));
-        u0041_0306.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EB4}'), None));
-        u0041.insert('\u{0306}', ReverseTreeNode::new(Some('\u{0102}'), Some(Box::new(u0041_0306))));
-        u0041.insert('\u{0328}', ReverseTreeNode::new(Some('\u{0104}'), None));
-        u0041.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01CD}'), None));
-        let mut u0041_0307 = AHashMap::new();
-        u0041_0307.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01E0}'), None));
-        u0041.insert('\u{0307}', ReverseTreeNode::new(None, Some(Box::new(u0041_0307))));
-        u0041.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0200}'), None));
-        u0041.insert('\u{0311}', ReverseTreeNode::new(Some('\u{0202}'), None));
-        u0041.insert('\u{0325}', ReverseTreeNode::new(Some('\u{1E00}'), None));
-        let mut u0041_0323 = AHashMap::new();
-        u0041_0323.insert('\u{0302}', ReverseTreeNode::new(Some('\u{1EAC}'), None));
-        u0041_0323.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1EB6}'), None));
-        u0041.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EA0}'), Some(Box::new(u0041_0323))));
-        u0041.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EA2}'), None));
-        root.insert('\u{0041}', ReverseTreeNode::new(None, Some(Box::new(u0041))));
-        let mut u0043 = AHashMap::new();
-        let mut u0043_0327 = AHashMap::new();
-        u0043_0327.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E08}'), None));
-        u0043.insert('\u{0327}', ReverseTreeNode::new(Some('\u{00C7}'), Some(Box::new(u0043_0327))));
-        u0043.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0106}'), None));
-        u0043.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0108}'), None));
-        u0043.insert('\u{0307}', ReverseTreeNode::new(Some('\u{010A}'), None));
-        u0043.insert('\u{030C}', ReverseTreeNode::new(Some('\u{010C}'), None));
-        root.insert('\u{0043}', ReverseTreeNode::new(None, Some(Box::new(u0043))));
-        let mut u0045 = AHashMap::new();
-        u0045.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00C8}'), None));
-        u0045.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00C9}'), None));
-        let mut u0045_0302 = AHashMap::new();
-        u0045_0302.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EBE}'), None));
-        u0045_0302.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EC0}'), None));
-        u0045_0302.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EC2}'), None));
-        u0045_0302.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EC4}'), None));
-        u0045.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00CA}'), Some(Box::new(u0045_0302))));
-        u0045.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00CB}'), None));
-        let mut u0045_0304 = AHashMap::new();
-        u0045_0304.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1E14}'), None));
-        u0045_0304.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E16}'), None));
-        u0045.insert('\u{0304}', ReverseTreeNode::new(Some('\u{0112}'), Some(Box::new(u0045_0304))));
-        u0045.insert('\u{0306}', ReverseTreeNode::new(Some('\u{0114}'), None));
-        u0045.insert('\u{0307}', ReverseTreeNode::new(Some('\u{0116}'), None));
-        u0045.insert('\u{0328}', ReverseTreeNode::new(Some('\u{0118}'), None));
-        u0045.insert('\u{030C}', ReverseTreeNode::new(Some('\u{011A}'), None));
-        u0045.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0204}'), None));
-        u0045.insert('\u{0311}', ReverseTreeNode::new(Some('\u{0206}'), None));
-        u0045.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E18}'), None));
-        u0045.insert('\u{0330}', ReverseTreeNode::new(Some('\u{1E1A}'), None));
-        let mut u0045_0327 = AHashMap::new();
-        u0045_0327.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1E1C}'), None));
-        u0045.insert('\u{0327}', ReverseTreeNode::new(None, Some(Box::new(u0045_0327))));
-        let mut u0045_0323 = AHashMap::new();
-        u0045_0323.insert('\u{0302}', ReverseTreeNode::new(Some('\u{1EC6}'), None));
-        u0045.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EB8}'), Some(Box::new(u0045_0323))));
-        u0045.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EBA}'), None));
-        u0045.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EBC}'), None));
-        root.insert('\u{0045}', ReverseTreeNode::new(None, Some(Box::new(u0045))));
-        let mut u0049 = AHashMap::new();
-        u0049.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00CC}'), None));
-        u0049.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00CD}'), None));
-        u0049.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00CE}'), None));
-        let mut u0049_0308 = AHashMap::new();
-        u0049_0308.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E2E}'), None));
-        u0049.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00CF}'), Some(Box::new(u0049_0308))));
-        u0049.insert('\u{0303}', ReverseTreeNode::new(Some('\u{0128}'), None));
-        u0049.insert('\u{0304}', ReverseTreeNode::new(Some('\u{012A}'), None));
-        u0049.insert('\u{0306}', ReverseTreeNode::new(Some('\u{012C}'), None));
-        u0049.insert('\u{0328}', ReverseTreeNode::new(Some('\u{012E}'), None));
-        u0049.insert('\u{0307}', ReverseTreeNode::new(Some('\u{0130}'), None));
-        u0049.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01CF}'), None));
-        u0049.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0208}'), None));
-        u0049.insert('\u{0311}', ReverseTreeNode::new(Some('\u{020A}'), None));
-        u0049.insert('\u{0330}', ReverseTreeNode::new(Some('\u{1E2C}'), None));
-        u0049.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EC8}'), None));
-        u0049.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1ECA}'), None));
-        root.insert('\u{0049}', ReverseTreeNode::new(None, Some(Box::new(u0049))));
-        let mut u004e = AHashMap::new();
-        u004e.insert('\u{0303}', ReverseTreeNode::new(Some('\u{00D1}'), None));
-        u004e.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0143}'), None));
-        u004e.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0145}'), None));
-        u004e.insert('\u{030C}', ReverseTreeNode::new(Some('\u{0147}'), None));
-        u004e.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E44}'), None));
-        u004e.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E46}'), None));
-        u004e.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E48}'), None));
-        u004e.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E4A}'), None));
-        root.insert('\u{004E}', ReverseTreeNode::new(None, Some(Box::new(u004e))));
-        let mut u004f = AHashMap::new();
-        u004f.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00D2}'), None));
-        u004f.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00D3}'), None));
-        let mut u004f_0302 = AHashMap::new();
-        u004f_0302.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1ED0}'), None));
-        u004f_0302.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1ED2}'), None));
-        u004f_0302.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1ED4}'), None));
-        u004f_0302.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1ED6}'), None));
-        u004f.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00D4}'), Some(Box::new(u004f_0302))));
-        let mut u004f_0303 = AHashMap::new();
-        u004f_0303.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E4C}'), None));
-        u004f_0303.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E4E}'), None));
-        u004f.insert('\u{0303}', ReverseTreeNode::new(Some('\u{00D5}'), Some(Box::new(u004f_0303))));
-        u004f.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00D6}'), None));
-        let mut u004f_0304 = AHashMap::new();
-        u004f_0304.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1E50}'), None));
-        u004f_0304.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E52}'), None));
-        u004f.insert('\u{0304}', ReverseTreeNode::new(Some('\u{014C}'), Some(Box::new(u004f_0304))));
-        u004f.insert('\u{0306}', ReverseTreeNode::new(Some('\u{014E}'), None));
-        u004f.insert('\u{030B}', ReverseTreeNode::new(Some('\u{0150}'), None));
-        let mut u004f_031b = AHashMap::new();
-        u004f_031b.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EDA}'), None));
-        u004f_031b.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EDC}'), None));
-        u004f_031b.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EDE}'), None));
-        u004f_031b.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EE0}'), None));
-        u004f_031b.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EE2}'), None));
-        u004f.insert('\u{031B}', ReverseTreeNode::new(Some('\u{01A0}'), Some(Box::new(u004f_031b))));
-        u004f.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01D1}'), None));
-        let mut u004f_0328 = AHashMap::new();
-        u004f_0328.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01EC}'), None));
-        u004f.insert('\u{0328}', ReverseTreeNode::new(Some('\u{01EA}'), Some(Box::new(u004f_0328))));
-        u004f.insert('\u{030F}', ReverseTreeNode::new(Some('\u{020C}'), None));
-        u004f.insert('\u{0311}', ReverseTreeNode::new(Some('\u{020E}'), None));
-        let mut u004f_0323 = AHashMap::new();
-        u004f_0323.insert('\u{0302}', ReverseTreeNode::new(Some('\u{1ED8}'), None));
-        u004f.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1ECC}'), Some(Box::new(u004f_0323))));
-        u004f.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1ECE}'), None));
-        root.insert('\u{004F}', ReverseTreeNode::new(None, Some(Box::new(u004f))));
-        let mut u0055 = AHashMap::new();
-        u0055.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00D9}'), None));
-        u0055.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00DA}'), None));
-        u0055.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00DB}'), None));
-        let mut u0055_0308 = AHashMap::new();
-        u0055_0308.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01D5}'), None));
-        u0055_0308.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01D7}'), None));
-        u0055_0308.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01D9}'), None));
-        u0055_0308.insert('\u{0300}', ReverseTreeNode::new(Some('\u{01DB}'), None));
-        u0055.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00DC}'), Some(Box::new(u0055_0308))));
-        let mut u0055_0303 = AHashMap::new();
-        u0055_0303.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E78}'), None));
-        u0055.insert('\u{0303}', ReverseTreeNode::new(Some('\u{0168}'), Some(Box::new(u0055_0303))));
-        let mut u0055_0304 = AHashMap::new();
-        u0055_0304.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E7A}'), None));
-        u0055.insert('\u{0304}', ReverseTreeNode::new(Some('\u{016A}'), Some(Box::new(u0055_0304))));
-        u0055.insert('\u{0306}', ReverseTreeNode::new(Some('\u{016C}'), None));
-        u0055.insert('\u{030A}', ReverseTreeNode::new(Some('\u{016E}'), None));
-        u0055.insert('\u{030B}', ReverseTreeNode::new(Some('\u{0170}'), None));
-        u0055.insert('\u{0328}', ReverseTreeNode::new(Some('\u{0172}'), None));
-        let mut u0055_031b = AHashMap::new();
-        u0055_031b.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EE8}'), None));
-        u0055_031b.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EEA}'), None));
-        u0055_031b.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EEC}'), None));
-        u0055_031b.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EEE}'), None));
-        u0055_031b.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EF0}'), None));
-        u0055.insert('\u{031B}', ReverseTreeNode::new(Some('\u{01AF}'), Some(Box::new(u0055_031b))));
-        u0055.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01D3}'), None));
-        u0055.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0214}'), None));
-        u0055.insert('\u{0311}', ReverseTreeNode::new(Some('\u{0216}'), None));
-        u0055.insert('\u{0324}', ReverseTreeNode::new(Some('\u{1E72}'), None));
-        u0055.insert('\u{0330}', ReverseTreeNode::new(Some('\u{1E74}'), None));
-        u0055.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E76}'), None));
-        u0055.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EE4}'), None));
-        u0055.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EE6}'), None));
-        root.insert('\u{0055}', ReverseTreeNode::new(None, Some(Box::new(u0055))));
-        let mut u0059 = AHashMap::new();
-        u0059.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00DD}'), None));
-        u0059.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0176}'), None));
-        u0059.insert('\u{0308}', ReverseTreeNode::new(Some('\u{0178}'), None));
-        u0059.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E8E}'), None));
-        u0059.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EF2}'), None));
-        u0059.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EF4}'), None));
-        u0059.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EF6}'), None));
-        u0059.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EF8}'), None));
-        root.insert('\u{0059}', ReverseTreeNode::new(None, Some(Box::new(u0059))));
-        let mut u0061 = AHashMap::new();
-        u0061.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00E0}'), None));
-        u0061.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00E1}'), None));
-        let mut u0061_0302 = AHashMap::new();
-        u0061_0302.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EA5}'), None));
-        u0061_0302.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EA7}'), None));
-        u0061_0302.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EA9}'), None));
-        u0061_0302.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EAB}'), None));
-        u0061.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00E2}'), Some(Box::new(u0061_0302))));
-        u0061.insert('\u{0303}', ReverseTreeNode::new(Some('\u{00E3}'), None));
-        let mut u0061_0308 = AHashMap::new();
-        u0061_0308.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01DF}'), None));
-        u0061.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00E4}'), Some(Box::new(u0061_0308))));
-        let mut u0061_030a = AHashMap::new();
-        u0061_030a.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01FB}'), None));
-        u0061.insert('\u{030A}', ReverseTreeNode::new(Some('\u{00E5}'), Some(Box::new(u0061_030a))));
-        u0061.insert('\u{0304}', ReverseTreeNode::new(Some('\u{0101}'), None));
-        let mut u0061_0306 = AHashMap::new();
-        u0061_0306.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EAF}'), None));
-        u0061_0306.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EB1}'), None));
-        u0061_0306.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EB3}'), None));
-        u0061_0306.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EB5}'), None));
-        u0061.insert('\u{0306}', ReverseTreeNode::new(Some('\u{0103}'), Some(Box::new(u0061_0306))));
-        u0061.insert('\u{0328}', ReverseTreeNode::new(Some('\u{0105}'), None));
-        u0061.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01CE}'), None));
-        let mut u0061_0307 = AHashMap::new();
-        u0061_0307.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01E1}'), None));
-        u0061.insert('\u{0307}', ReverseTreeNode::new(None, Some(Box::new(u0061_0307))));
-        u0061.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0201}'), None));
-        u0061.insert('\u{0311}', ReverseTreeNode::new(Some('\u{0203}'), None));
-        u0061.insert('\u{0325}', ReverseTreeNode::new(Some('\u{1E01}'), None));
-        let mut u0061_0323 = AHashMap::new();
-        u0061_0323.insert('\u{0302}', ReverseTreeNode::new(Some('\u{1EAD}'), None));
-        u0061_0323.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1EB7}'), None));
-        u0061.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EA1}'), Some(Box::new(u0061_0323))));
-        u0061.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EA3}'), None));
-        root.insert('\u{0061}', ReverseTreeNode::new(None, Some(Box::new(u0061))));
-        let mut u0063 = AHashMap::new();
-        let mut u0063_0327 = AHashMap::new();
-        u0063_0327.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E09}'), None));
-        u0063.insert('\u{0327}', ReverseTreeNode::new(Some('\u{00E7}'), Some(Box::new(u0063_0327))));
-        u0063.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0107}'), None));
-        u0063.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0109}'), None));
-        u0063.insert('\u{0307}', ReverseTreeNode::new(Some('\u{010B}'), None));
-        u0063.insert('\u{030C}', ReverseTreeNode::new(Some('\u{010D}'), None));
-        root.insert('\u{0063}', ReverseTreeNode::new(None, Some(Box::new(u0063))));
-        let mut u0065 = AHashMap::new();
-        u0065.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00E8}'), None));
-        u0065.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00E9}'), None));
-        let mut u0065_0302 = AHashMap::new();
-        u0065_0302.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EBF}'), None));
-        u0065_0302.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EC1}'), None));
-        u0065_0302.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EC3}'), None));
-        u0065_0302.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EC5}'), None));
-        u0065.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00EA}'), Some(Box::new(u0065_0302))));
-        u0065.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00EB}'), None));
-        let mut u0065_0304 = AHashMap::new();
-        u0065_0304.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1E15}'), None));
-        u0065_0304.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E17}'), None));
-        u0065.insert('\u{0304}', ReverseTreeNode::new(Some('\u{0113}'), Some(Box::new(u0065_0304))));
-        u0065.insert('\u{0306}', ReverseTreeNode::new(Some('\u{0115}'), None));
-        u0065.insert('\u{0307}', ReverseTreeNode::new(Some('\u{0117}'), None));
-        u0065.insert('\u{0328}', ReverseTreeNode::new(Some('\u{0119}'), None));
-        u0065.insert('\u{030C}', ReverseTreeNode::new(Some('\u{011B}'), None));
-        u0065.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0205}'), None));
-        u0065.insert('\u{0311}', ReverseTreeNode::new(Some('\u{0207}'), None));
-        u0065.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E19}'), None));
-        u0065.insert('\u{0330}', ReverseTreeNode::new(Some('\u{1E1B}'), None));
-        let mut u0065_0327 = AHashMap::new();
-        u0065_0327.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1E1D}'), None));
-        u0065.insert('\u{0327}', ReverseTreeNode::new(None, Some(Box::new(u0065_0327))));
-        let mut u0065_0323 = AHashMap::new();
-        u0065_0323.insert('\u{0302}', ReverseTreeNode::new(Some('\u{1EC7}'), None));
-        u0065.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EB9}'), Some(Box::new(u0065_0323))));
-        u0065.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EBB}'), None));
-        u0065.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EBD}'), None));
-        root.insert('\u{0065}', ReverseTreeNode::new(None, Some(Box::new(u0065))));
-        let mut u0069 = AHashMap::new();
-        u0069.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00EC}'), None));
-        u0069.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00ED}'), None));
-        u0069.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00EE}'), None));
-        let mut u0069_0308 = AHashMap::new();
-        u0069_0308.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E2F}'), None));
-        u0069.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00EF}'), Some(Box::new(u0069_0308))));
-        u0069.insert('\u{0303}', ReverseTreeNode::new(Some('\u{0129}'), None));
-        u0069.insert('\u{0304}', ReverseTreeNode::new(Some('\u{012B}'), None));
-        u0069.insert('\u{0306}', ReverseTreeNode::new(Some('\u{012D}'), None));
-        u0069.insert('\u{0328}', ReverseTreeNode::new(Some('\u{012F}'), None));
-        u0069.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01D0}'), None));
-        u0069.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0209}'), None));
-        u0069.insert('\u{0311}', ReverseTreeNode::new(Some('\u{020B}'), None));
-        u0069.insert('\u{0330}', ReverseTreeNode::new(Some('\u{1E2D}'), None));
-        u0069.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EC9}'), None));
-        u0069.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1ECB}'), None));
-        root.insert('\u{0069}', ReverseTreeNode::new(None, Some(Box::new(u0069))));
-        let mut u006e = AHashMap::new();
-        u006e.insert('\u{0303}', ReverseTreeNode::new(Some('\u{00F1}'), None));
-        u006e.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0144}'), None));
-        u006e.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0146}'), None));
-        u006e.insert('\u{030C}', ReverseTreeNode::new(Some('\u{0148}'), None));
-        u006e.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E45}'), None));
-        u006e.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E47}'), None));
-        u006e.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E49}'), None));
-        u006e.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E4B}'), None));
-        root.insert('\u{006E}', ReverseTreeNode::new(None, Some(Box::new(u006e))));
-        let mut u006f = AHashMap::new();
-        u006f.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00F2}'), None));
-        u006f.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00F3}'), None));
-        let mut u006f_0302 = AHashMap::new();
-        u006f_0302.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1ED1}'), None));
-        u006f_0302.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1ED3}'), None));
-        u006f_0302.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1ED5}'), None));
-        u006f_0302.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1ED7}'), None));
-        u006f.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00F4}'), Some(Box::new(u006f_0302))));
-        let mut u006f_0303 = AHashMap::new();
-        u006f_0303.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E4D}'), None));
-        u006f_0303.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E4F}'), None));
-        u006f.insert('\u{0303}', ReverseTreeNode::new(Some('\u{00F5}'), Some(Box::new(u006f_0303))));
-        u006f.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00F6}'), None));
-        let mut u006f_0304 = AHashMap::new();
-        u006f_0304.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1E51}'), None));
-        u006f_0304.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E53}'), None));
-        u006f.insert('\u{0304}', ReverseTreeNode::new(Some('\u{014D}'), Some(Box::new(u006f_0304))));
-        u006f.insert('\u{0306}', ReverseTreeNode::new(Some('\u{014F}'), None));
-        u006f.insert('\u{030B}', ReverseTreeNode::new(Some('\u{0151}'), None));
-        let mut u006f_031b = AHashMap::new();
-        u006f_031b.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EDB}'), None));
-        u006f_031b.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EDD}'), None));
-        u006f_031b.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EDF}'), None));
-        u006f_031b.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EE1}'), None));
-        u006f_031b.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EE3}'), None));
-        u006f.insert('\u{031B}', ReverseTreeNode::new(Some('\u{01A1}'), Some(Box::new(u006f_031b))));
-        u006f.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01D2}'), None));
-        let mut u006f_0328 = AHashMap::new();
-        u006f_0328.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01ED}'), None));
-        u006f.insert('\u{0328}', ReverseTreeNode::new(Some('\u{01EB}'), Some(Box::new(u006f_0328))));
-        u006f.insert('\u{030F}', ReverseTreeNode::new(Some('\u{020D}'), None));
-        u006f.insert('\u{0311}', ReverseTreeNode::new(Some('\u{020F}'), None));
-        let mut u006f_0323 = AHashMap::new();
-        u006f_0323.insert('\u{0302}', ReverseTreeNode::new(Some('\u{1ED9}'), None));
-        u006f.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1ECD}'), Some(Box::new(u006f_0323))));
-        u006f.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1ECF}'), None));
-        root.insert('\u{006F}', ReverseTreeNode::new(None, Some(Box::new(u006f))));
-        let mut u0075 = AHashMap::new();
-        u0075.insert('\u{0300}', ReverseTreeNode::new(Some('\u{00F9}'), None));
-        u0075.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00FA}'), None));
-        u0075.insert('\u{0302}', ReverseTreeNode::new(Some('\u{00FB}'), None));
-        let mut u0075_0308 = AHashMap::new();
-        u0075_0308.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01D6}'), None));
-        u0075_0308.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01D8}'), None));
-        u0075_0308.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01DA}'), None));
-        u0075_0308.insert('\u{0300}', ReverseTreeNode::new(Some('\u{01DC}'), None));
-        u0075.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00FC}'), Some(Box::new(u0075_0308))));
-        let mut u0075_0303 = AHashMap::new();
-        u0075_0303.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E79}'), None));
-        u0075.insert('\u{0303}', ReverseTreeNode::new(Some('\u{0169}'), Some(Box::new(u0075_0303))));
-        let mut u0075_0304 = AHashMap::new();
-        u0075_0304.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E7B}'), None));
-        u0075.insert('\u{0304}', ReverseTreeNode::new(Some('\u{016B}'), Some(Box::new(u0075_0304))));
-        u0075.insert('\u{0306}', ReverseTreeNode::new(Some('\u{016D}'), None));
-        u0075.insert('\u{030A}', ReverseTreeNode::new(Some('\u{016F}'), None));
-        u0075.insert('\u{030B}', ReverseTreeNode::new(Some('\u{0171}'), None));
-        u0075.insert('\u{0328}', ReverseTreeNode::new(Some('\u{0173}'), None));
-        let mut u0075_031b = AHashMap::new();
-        u0075_031b.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1EE9}'), None));
-        u0075_031b.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EEB}'), None));
-        u0075_031b.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EED}'), None));
-        u0075_031b.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EEF}'), None));
-        u0075_031b.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EF1}'), None));
-        u0075.insert('\u{031B}', ReverseTreeNode::new(Some('\u{01B0}'), Some(Box::new(u0075_031b))));
-        u0075.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01D4}'), None));
-        u0075.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0215}'), None));
-        u0075.insert('\u{0311}', ReverseTreeNode::new(Some('\u{0217}'), None));
-        u0075.insert('\u{0324}', ReverseTreeNode::new(Some('\u{1E73}'), None));
-        u0075.insert('\u{0330}', ReverseTreeNode::new(Some('\u{1E75}'), None));
-        u0075.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E77}'), None));
-        u0075.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EE5}'), None));
-        u0075.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EE7}'), None));
-        root.insert('\u{0075}', ReverseTreeNode::new(None, Some(Box::new(u0075))));
-        let mut u0079 = AHashMap::new();
-        u0079.insert('\u{0301}', ReverseTreeNode::new(Some('\u{00FD}'), None));
-        u0079.insert('\u{0308}', ReverseTreeNode::new(Some('\u{00FF}'), None));
-        u0079.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0177}'), None));
-        u0079.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E8F}'), None));
-        u0079.insert('\u{030A}', ReverseTreeNode::new(Some('\u{1E99}'), None));
-        u0079.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1EF3}'), None));
-        u0079.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1EF5}'), None));
-        u0079.insert('\u{0309}', ReverseTreeNode::new(Some('\u{1EF7}'), None));
-        u0079.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1EF9}'), None));
-        root.insert('\u{0079}', ReverseTreeNode::new(None, Some(Box::new(u0079))));
-        let mut u0044 = AHashMap::new();
-        u0044.insert('\u{030C}', ReverseTreeNode::new(Some('\u{010E}'), None));
-        u0044.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E0A}'), None));
-        u0044.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E0C}'), None));
-        u0044.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E0E}'), None));
-        u0044.insert('\u{0327}', ReverseTreeNode::new(Some('\u{1E10}'), None));
-        u0044.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E12}'), None));
-        root.insert('\u{0044}', ReverseTreeNode::new(None, Some(Box::new(u0044))));
-        let mut u0064 = AHashMap::new();
-        u0064.insert('\u{030C}', ReverseTreeNode::new(Some('\u{010F}'), None));
-        u0064.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E0B}'), None));
-        u0064.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E0D}'), None));
-        u0064.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E0F}'), None));
-        u0064.insert('\u{0327}', ReverseTreeNode::new(Some('\u{1E11}'), None));
-        u0064.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E13}'), None));
-        root.insert('\u{0064}', ReverseTreeNode::new(None, Some(Box::new(u0064))));
-        let mut u0047 = AHashMap::new();
-        u0047.insert('\u{0302}', ReverseTreeNode::new(Some('\u{011C}'), None));
-        u0047.insert('\u{0306}', ReverseTreeNode::new(Some('\u{011E}'), None));
-        u0047.insert('\u{0307}', ReverseTreeNode::new(Some('\u{0120}'), None));
-        u0047.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0122}'), None));
-        u0047.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01E6}'), None));
-        u0047.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01F4}'), None));
-        u0047.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1E20}'), None));
-        root.insert('\u{0047}', ReverseTreeNode::new(None, Some(Box::new(u0047))));
-        let mut u0067 = AHashMap::new();
-        u0067.insert('\u{0302}', ReverseTreeNode::new(Some('\u{011D}'), None));
-        u0067.insert('\u{0306}', ReverseTreeNode::new(Some('\u{011F}'), None));
-        u0067.insert('\u{0307}', ReverseTreeNode::new(Some('\u{0121}'), None));
-        u0067.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0123}'), None));
-        u0067.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01E7}'), None));
-        u0067.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01F5}'), None));
-        u0067.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1E21}'), None));
-        root.insert('\u{0067}', ReverseTreeNode::new(None, Some(Box::new(u0067))));
-        let mut u0048 = AHashMap::new();
-        u0048.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0124}'), None));
-        u0048.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E22}'), None));
-        u0048.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E24}'), None));
-        u0048.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E26}'), None));
-        u0048.insert('\u{0327}', ReverseTreeNode::new(Some('\u{1E28}'), None));
-        u0048.insert('\u{032E}', ReverseTreeNode::new(Some('\u{1E2A}'), None));
-        root.insert('\u{0048}', ReverseTreeNode::new(None, Some(Box::new(u0048))));
-        let mut u0068 = AHashMap::new();
-        u0068.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0125}'), None));
-        u0068.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E23}'), None));
-        u0068.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E25}'), None));
-        u0068.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E27}'), None));
-        u0068.insert('\u{0327}', ReverseTreeNode::new(Some('\u{1E29}'), None));
-        u0068.insert('\u{032E}', ReverseTreeNode::new(Some('\u{1E2B}'), None));
-        u0068.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E96}'), None));
-        root.insert('\u{0068}', ReverseTreeNode::new(None, Some(Box::new(u0068))));
-        let mut u004a = AHashMap::new();
-        u004a.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0134}'), None));
-        root.insert('\u{004A}', ReverseTreeNode::new(None, Some(Box::new(u004a))));
-        let mut u006a = AHashMap::new();
-        u006a.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0135}'), None));
-        u006a.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01F0}'), None));
-        root.insert('\u{006A}', ReverseTreeNode::new(None, Some(Box::new(u006a))));
-        let mut u004b = AHashMap::new();
-        u004b.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0136}'), None));
-        u004b.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01E8}'), None));
-        u004b.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E30}'), None));
-        u004b.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E32}'), None));
-        u004b.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E34}'), None));
-        root.insert('\u{004B}', ReverseTreeNode::new(None, Some(Box::new(u004b))));
-        let mut u006b = AHashMap::new();
-        u006b.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0137}'), None));
-        u006b.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01E9}'), None));
-        u006b.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E31}'), None));
-        u006b.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E33}'), None));
-        u006b.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E35}'), None));
-        root.insert('\u{006B}', ReverseTreeNode::new(None, Some(Box::new(u006b))));
-        let mut u004c = AHashMap::new();
-        u004c.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0139}'), None));
-        u004c.insert('\u{0327}', ReverseTreeNode::new(Some('\u{013B}'), None));
-        u004c.insert('\u{030C}', ReverseTreeNode::new(Some('\u{013D}'), None));
-        let mut u004c_0323 = AHashMap::new();
-        u004c_0323.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1E38}'), None));
-        u004c.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E36}'), Some(Box::new(u004c_0323))));
-        u004c.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E3A}'), None));
-        u004c.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E3C}'), None));
-        root.insert('\u{004C}', ReverseTreeNode::new(None, Some(Box::new(u004c))));
-        let mut u006c = AHashMap::new();
-        u006c.insert('\u{0301}', ReverseTreeNode::new(Some('\u{013A}'), None));
-        u006c.insert('\u{0327}', ReverseTreeNode::new(Some('\u{013C}'), None));
-        u006c.insert('\u{030C}', ReverseTreeNode::new(Some('\u{013E}'), None));
-        let mut u006c_0323 = AHashMap::new();
-        u006c_0323.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1E39}'), None));
-        u006c.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E37}'), Some(Box::new(u006c_0323))));
-        u006c.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E3B}'), None));
-        u006c.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E3D}'), None));
-        root.insert('\u{006C}', ReverseTreeNode::new(None, Some(Box::new(u006c))));
-        let mut u0052 = AHashMap::new();
-        u0052.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0154}'), None));
-        u0052.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0156}'), None));
-        u0052.insert('\u{030C}', ReverseTreeNode::new(Some('\u{0158}'), None));
-        u0052.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0210}'), None));
-        u0052.insert('\u{0311}', ReverseTreeNode::new(Some('\u{0212}'), None));
-        u0052.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E58}'), None));
-        let mut u0052_0323 = AHashMap::new();
-        u0052_0323.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1E5C}'), None));
-        u0052.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E5A}'), Some(Box::new(u0052_0323))));
-        u0052.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E5E}'), None));
-        root.insert('\u{0052}', ReverseTreeNode::new(None, Some(Box::new(u0052))));
-        let mut u0072 = AHashMap::new();
-        u0072.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0155}'), None));
-        u0072.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0157}'), None));
-        u0072.insert('\u{030C}', ReverseTreeNode::new(Some('\u{0159}'), None));
-        u0072.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0211}'), None));
-        u0072.insert('\u{0311}', ReverseTreeNode::new(Some('\u{0213}'), None));
-        u0072.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E59}'), None));
-        let mut u0072_0323 = AHashMap::new();
-        u0072_0323.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1E5D}'), None));
-        u0072.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E5B}'), Some(Box::new(u0072_0323))));
-        u0072.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E5F}'), None));
-        root.insert('\u{0072}', ReverseTreeNode::new(None, Some(Box::new(u0072))));
-        let mut u0053 = AHashMap::new();
-        let mut u0053_0301 = AHashMap::new();
-        u0053_0301.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E64}'), None));
-        u0053.insert('\u{0301}', ReverseTreeNode::new(Some('\u{015A}'), Some(Box::new(u0053_0301))));
-        u0053.insert('\u{0302}', ReverseTreeNode::new(Some('\u{015C}'), None));
-        u0053.insert('\u{0327}', ReverseTreeNode::new(Some('\u{015E}'), None));
-        let mut u0053_030c = AHashMap::new();
-        u0053_030c.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E66}'), None));
-        u0053.insert('\u{030C}', ReverseTreeNode::new(Some('\u{0160}'), Some(Box::new(u0053_030c))));
-        u0053.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E60}'), None));
-        let mut u0053_0323 = AHashMap::new();
-        u0053_0323.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E68}'), None));
-        u0053.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E62}'), Some(Box::new(u0053_0323))));
-        root.insert('\u{0053}', ReverseTreeNode::new(None, Some(Box::new(u0053))));
-        let mut u0073 = AHashMap::new();
-        let mut u0073_0301 = AHashMap::new();
-        u0073_0301.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E65}'), None));
-        u0073.insert('\u{0301}', ReverseTreeNode::new(Some('\u{015B}'), Some(Box::new(u0073_0301))));
-        u0073.insert('\u{0302}', ReverseTreeNode::new(Some('\u{015D}'), None));
-        u0073.insert('\u{0327}', ReverseTreeNode::new(Some('\u{015F}'), None));
-        let mut u0073_030c = AHashMap::new();
-        u0073_030c.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E67}'), None));
-        u0073.insert('\u{030C}', ReverseTreeNode::new(Some('\u{0161}'), Some(Box::new(u0073_030c))));
-        u0073.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E61}'), None));
-        let mut u0073_0323 = AHashMap::new();
-        u0073_0323.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E69}'), None));
-        u0073.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E63}'), Some(Box::new(u0073_0323))));
-        root.insert('\u{0073}', ReverseTreeNode::new(None, Some(Box::new(u0073))));
-        let mut u0054 = AHashMap::new();
-        u0054.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0162}'), None));
-        u0054.insert('\u{030C}', ReverseTreeNode::new(Some('\u{0164}'), None));
-        u0054.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E6A}'), None));
-        u0054.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E6C}'), None));
-        u0054.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E6E}'), None));
-        u0054.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E70}'), None));
-        root.insert('\u{0054}', ReverseTreeNode::new(None, Some(Box::new(u0054))));
-        let mut u0074 = AHashMap::new();
-        u0074.insert('\u{0327}', ReverseTreeNode::new(Some('\u{0163}'), None));
-        u0074.insert('\u{030C}', ReverseTreeNode::new(Some('\u{0165}'), None));
-        u0074.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E6B}'), None));
-        u0074.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E6D}'), None));
-        u0074.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E6F}'), None));
-        u0074.insert('\u{032D}', ReverseTreeNode::new(Some('\u{1E71}'), None));
-        u0074.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E97}'), None));
-        root.insert('\u{0074}', ReverseTreeNode::new(None, Some(Box::new(u0074))));
-        let mut u0057 = AHashMap::new();
-        u0057.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0174}'), None));
-        u0057.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1E80}'), None));
-        u0057.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E82}'), None));
-        u0057.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E84}'), None));
-        u0057.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E86}'), None));
-        u0057.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E88}'), None));
-        root.insert('\u{0057}', ReverseTreeNode::new(None, Some(Box::new(u0057))));
-        let mut u0077 = AHashMap::new();
-        u0077.insert('\u{0302}', ReverseTreeNode::new(Some('\u{0175}'), None));
-        u0077.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1E81}'), None));
-        u0077.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E83}'), None));
-        u0077.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E85}'), None));
-        u0077.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E87}'), None));
-        u0077.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E89}'), None));
-        u0077.insert('\u{030A}', ReverseTreeNode::new(Some('\u{1E98}'), None));
-        root.insert('\u{0077}', ReverseTreeNode::new(None, Some(Box::new(u0077))));
-        let mut u005a = AHashMap::new();
-        u005a.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0179}'), None));
-        u005a.insert('\u{0307}', ReverseTreeNode::new(Some('\u{017B}'), None));
-        u005a.insert('\u{030C}', ReverseTreeNode::new(Some('\u{017D}'), None));
-        u005a.insert('\u{0302}', ReverseTreeNode::new(Some('\u{1E90}'), None));
-        u005a.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E92}'), None));
-        u005a.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E94}'), None));
-        root.insert('\u{005A}', ReverseTreeNode::new(None, Some(Box::new(u005a))));
-        let mut u007a = AHashMap::new();
-        u007a.insert('\u{0301}', ReverseTreeNode::new(Some('\u{017A}'), None));
-        u007a.insert('\u{0307}', ReverseTreeNode::new(Some('\u{017C}'), None));
-        u007a.insert('\u{030C}', ReverseTreeNode::new(Some('\u{017E}'), None));
-        u007a.insert('\u{0302}', ReverseTreeNode::new(Some('\u{1E91}'), None));
-        u007a.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E93}'), None));
-        u007a.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E95}'), None));
-        root.insert('\u{007A}', ReverseTreeNode::new(None, Some(Box::new(u007a))));
-        let mut u00c6 = AHashMap::new();
-        u00c6.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01E2}'), None));
-        u00c6.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01FC}'), None));
-        root.insert('\u{00C6}', ReverseTreeNode::new(None, Some(Box::new(u00c6))));
-        let mut u00e6 = AHashMap::new();
-        u00e6.insert('\u{0304}', ReverseTreeNode::new(Some('\u{01E3}'), None));
-        u00e6.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01FD}'), None));
-        root.insert('\u{00E6}', ReverseTreeNode::new(None, Some(Box::new(u00e6))));
-        let mut u01b7 = AHashMap::new();
-        u01b7.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01EE}'), None));
-        root.insert('\u{01B7}', ReverseTreeNode::new(None, Some(Box::new(u01b7))));
-        let mut u0292 = AHashMap::new();
-        u0292.insert('\u{030C}', ReverseTreeNode::new(Some('\u{01EF}'), None));
-        root.insert('\u{0292}', ReverseTreeNode::new(None, Some(Box::new(u0292))));
-        let mut u00d8 = AHashMap::new();
-        u00d8.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01FE}'), None));
-        root.insert('\u{00D8}', ReverseTreeNode::new(None, Some(Box::new(u00d8))));
-        let mut u00f8 = AHashMap::new();
-        u00f8.insert('\u{0301}', ReverseTreeNode::new(Some('\u{01FF}'), None));
-        root.insert('\u{00F8}', ReverseTreeNode::new(None, Some(Box::new(u00f8))));
-        let mut u0306 = AHashMap::new();
-        u0306.insert('\u{0307}', ReverseTreeNode::new(Some('\u{0310}'), None));
-        root.insert('\u{0306}', ReverseTreeNode::new(None, Some(Box::new(u0306))));
-        let mut u0308 = AHashMap::new();
-        u0308.insert('\u{030D}', ReverseTreeNode::new(Some('\u{0344}'), None));
-        root.insert('\u{0308}', ReverseTreeNode::new(None, Some(Box::new(u0308))));
-        let mut u00a8 = AHashMap::new();
-        u00a8.insert('\u{030D}', ReverseTreeNode::new(Some('\u{0385}'), None));
-        u00a8.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FC1}'), None));
-        u00a8.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FED}'), None));
-        u00a8.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FEE}'), None));
-        root.insert('\u{00A8}', ReverseTreeNode::new(None, Some(Box::new(u00a8))));
-        let mut u0391 = AHashMap::new();
-        u0391.insert('\u{030D}', ReverseTreeNode::new(Some('\u{0386}'), None));
-        let mut u0391_0313 = AHashMap::new();
-        u0391_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F0A}'), None));
-        u0391_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F0C}'), None));
-        u0391_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F0E}'), None));
-        u0391.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F08}'), Some(Box::new(u0391_0313))));
-        let mut u0391_0314 = AHashMap::new();
-        u0391_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F0B}'), None));
-        u0391_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F0D}'), None));
-        u0391_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F0F}'), None));
-        u0391.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F09}'), Some(Box::new(u0391_0314))));
-        let mut u0391_0345 = AHashMap::new();
-        let mut u0391_0345_0313 = AHashMap::new();
-        u0391_0345_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F8A}'), None));
-        u0391_0345_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F8C}'), None));
-        u0391_0345_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F8E}'), None));
-        u0391_0345.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F88}'), Some(Box::new(u0391_0345_0313))));
-        let mut u0391_0345_0314 = AHashMap::new();
-        u0391_0345_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F8B}'), None));
-        u0391_0345_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F8D}'), None));
-        u0391_0345_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F8F}'), None));
-        u0391_0345.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F89}'), Some(Box::new(u0391_0345_0314))));
-        u0391.insert('\u{0345}', ReverseTreeNode::new(Some('\u{1FBC}'), Some(Box::new(u0391_0345))));
-        u0391.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1FB8}'), None));
-        u0391.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1FB9}'), None));
-        u0391.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FBA}'), None));
-        u0391.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FBB}'), None));
-        root.insert('\u{0391}', ReverseTreeNode::new(None, Some(Box::new(u0391))));
-        let mut u0395 = AHashMap::new();
-        u0395.insert('\u{030D}', ReverseTreeNode::new(Some('\u{0388}'), None));
-        let mut u0395_0313 = AHashMap::new();
-        u0395_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F1A}'), None));
-        u0395_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F1C}'), None));
-        u0395.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F18}'), Some(Box::new(u0395_0313))));
-        let mut u0395_0314 = AHashMap::new();
-        u0395_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F1B}'), None));
-        u0395_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F1D}'), None));
-        u0395.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F19}'), Some(Box::new(u0395_0314))));
-        u0395.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FC8}'), None));
-        u0395.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FC9}'), None));
-        root.insert('\u{0395}', ReverseTreeNode::new(None, Some(Box::new(u0395))));
-        let mut u0397 = AHashMap::new();
-        u0397.insert('\u{030D}', ReverseTreeNode::new(Some('\u{0389}'), None));
-        let mut u0397_0313 = AHashMap::new();
-        u0397_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F2A}'), None));
-        u0397_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F2C}'), None));
-        u0397_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F2E}'), None));
-        u0397.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F28}'), Some(Box::new(u0397_0313))));
-        let mut u0397_0314 = AHashMap::new();
-        u0397_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F2B}'), None));
-        u0397_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F2D}'), None));
-        u0397_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F2F}'), None));
-        u0397.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F29}'), Some(Box::new(u0397_0314))));
-        let mut u0397_0345 = AHashMap::new();
-        let mut u0397_0345_0313 = AHashMap::new();
-        u0397_0345_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F9A}'), None));
-        u0397_0345_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F9C}'), None));
-        u0397_0345_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F9E}'), None));
-        u0397_0345.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F98}'), Some(Box::new(u0397_0345_0313))));
-        let mut u0397_0345_0314 = AHashMap::new();
-        u0397_0345_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F9B}'), None));
-        u0397_0345_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F9D}'), None));
-        u0397_0345_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F9F}'), None));
-        u0397_0345.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F99}'), Some(Box::new(u0397_0345_0314))));
-        u0397.insert('\u{0345}', ReverseTreeNode::new(Some('\u{1FCC}'), Some(Box::new(u0397_0345))));
-        u0397.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FCA}'), None));
-        u0397.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FCB}'), None));
-        root.insert('\u{0397}', ReverseTreeNode::new(None, Some(Box::new(u0397))));
-        let mut u0399 = AHashMap::new();
-        u0399.insert('\u{030D}', ReverseTreeNode::new(Some('\u{038A}'), None));
-        u0399.insert('\u{0308}', ReverseTreeNode::new(Some('\u{03AA}'), None));
-        let mut u0399_0313 = AHashMap::new();
-        u0399_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F3A}'), None));
-        u0399_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F3C}'), None));
-        u0399_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F3E}'), None));
-        u0399.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F38}'), Some(Box::new(u0399_0313))));
-        let mut u0399_0314 = AHashMap::new();
-        u0399_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F3B}'), None));
-        u0399_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F3D}'), None));
-        u0399_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F3F}'), None));
-        u0399.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F39}'), Some(Box::new(u0399_0314))));
-        u0399.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1FD8}'), None));
-        u0399.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1FD9}'), None));
-        u0399.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FDA}'), None));
-        u0399.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FDB}'), None));
-        root.insert('\u{0399}', ReverseTreeNode::new(None, Some(Box::new(u0399))));
-        let mut u039f = AHashMap::new();
-        u039f.insert('\u{030D}', ReverseTreeNode::new(Some('\u{038C}'), None));
-        let mut u039f_0313 = AHashMap::new();
-        u039f_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F4A}'), None));
-        u039f_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F4C}'), None));
-        u039f.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F48}'), Some(Box::new(u039f_0313))));
-        let mut u039f_0314 = AHashMap::new();
-        u039f_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F4B}'), None));
-        u039f_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F4D}'), None));
-        u039f.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F49}'), Some(Box::new(u039f_0314))));
-        u039f.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FF8}'), None));
-        u039f.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FF9}'), None));
-        root.insert('\u{039F}', ReverseTreeNode::new(None, Some(Box::new(u039f))));
-        let mut u03a5 = AHashMap::new();
-        u03a5.insert('\u{030D}', ReverseTreeNode::new(Some('\u{038E}'), None));
-        u03a5.insert('\u{0308}', ReverseTreeNode::new(Some('\u{03AB}'), None));
-        let mut u03a5_0314 = AHashMap::new();
-        u03a5_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F5B}'), None));
-        u03a5_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F5D}'), None));
-        u03a5_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F5F}'), None));
-        u03a5.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F59}'), Some(Box::new(u03a5_0314))));
-        u03a5.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1FE8}'), None));
-        u03a5.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1FE9}'), None));
-        u03a5.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FEA}'), None));
-        u03a5.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FEB}'), None));
-        root.insert('\u{03A5}', ReverseTreeNode::new(None, Some(Box::new(u03a5))));
-        let mut u03a9 = AHashMap::new();
-        u03a9.insert('\u{030D}', ReverseTreeNode::new(Some('\u{038F}'), None));
-        let mut u03a9_0313 = AHashMap::new();
-        u03a9_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F6A}'), None));
-        u03a9_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F6C}'), None));
-        u03a9_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F6E}'), None));
-        u03a9.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F68}'), Some(Box::new(u03a9_0313))));
-        let mut u03a9_0314 = AHashMap::new();
-        u03a9_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F6B}'), None));
-        u03a9_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F6D}'), None));
-        u03a9_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F6F}'), None));
-        u03a9.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F69}'), Some(Box::new(u03a9_0314))));
-        let mut u03a9_0345 = AHashMap::new();
-        let mut u03a9_0345_0313 = AHashMap::new();
-        u03a9_0345_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FAA}'), None));
-        u03a9_0345_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FAC}'), None));
-        u03a9_0345_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FAE}'), None));
-        u03a9_0345.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1FA8}'), Some(Box::new(u03a9_0345_0313))));
-        let mut u03a9_0345_0314 = AHashMap::new();
-        u03a9_0345_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FAB}'), None));
-        u03a9_0345_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FAD}'), None));
-        u03a9_0345_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FAF}'), None));
-        u03a9_0345.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1FA9}'), Some(Box::new(u03a9_0345_0314))));
-        u03a9.insert('\u{0345}', ReverseTreeNode::new(Some('\u{1FFC}'), Some(Box::new(u03a9_0345))));
-        u03a9.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FFA}'), None));
-        u03a9.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FFB}'), None));
-        root.insert('\u{03A9}', ReverseTreeNode::new(None, Some(Box::new(u03a9))));
-        let mut u03b9 = AHashMap::new();
-        let mut u03b9_0308 = AHashMap::new();
-        u03b9_0308.insert('\u{030D}', ReverseTreeNode::new(Some('\u{0390}'), None));
-        u03b9_0308.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FD2}'), None));
-        u03b9_0308.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FD3}'), None));
-        u03b9_0308.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FD7}'), None));
-        u03b9.insert('\u{0308}', ReverseTreeNode::new(Some('\u{03CA}'), Some(Box::new(u03b9_0308))));
-        u03b9.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03AF}'), None));
-        let mut u03b9_0313 = AHashMap::new();
-        u03b9_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F32}'), None));
-        u03b9_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F34}'), None));
-        u03b9_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F36}'), None));
-        u03b9.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F30}'), Some(Box::new(u03b9_0313))));
-        let mut u03b9_0314 = AHashMap::new();
-        u03b9_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F33}'), None));
-        u03b9_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F35}'), None));
-        u03b9_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F37}'), None));
-        u03b9.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F31}'), Some(Box::new(u03b9_0314))));
-        u03b9.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F76}'), None));
-        u03b9.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F77}'), None));
-        u03b9.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1FD0}'), None));
-        u03b9.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1FD1}'), None));
-        u03b9.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FD6}'), None));
-        root.insert('\u{03B9}', ReverseTreeNode::new(None, Some(Box::new(u03b9))));
-        let mut u03b1 = AHashMap::new();
-        u03b1.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03AC}'), None));
-        let mut u03b1_0313 = AHashMap::new();
-        u03b1_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F02}'), None));
-        u03b1_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F04}'), None));
-        u03b1_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F06}'), None));
-        u03b1.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F00}'), Some(Box::new(u03b1_0313))));
-        let mut u03b1_0314 = AHashMap::new();
-        u03b1_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F03}'), None));
-        u03b1_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F05}'), None));
-        u03b1_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F07}'), None));
-        u03b1.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F01}'), Some(Box::new(u03b1_0314))));
-        u03b1.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F70}'), None));
-        u03b1.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F71}'), None));
-        let mut u03b1_0345 = AHashMap::new();
-        let mut u03b1_0345_0313 = AHashMap::new();
-        u03b1_0345_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F82}'), None));
-        u03b1_0345_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F84}'), None));
-        u03b1_0345_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F86}'), None));
-        u03b1_0345.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F80}'), Some(Box::new(u03b1_0345_0313))));
-        let mut u03b1_0345_0314 = AHashMap::new();
-        u03b1_0345_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F83}'), None));
-        u03b1_0345_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F85}'), None));
-        u03b1_0345_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F87}'), None));
-        u03b1_0345.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F81}'), Some(Box::new(u03b1_0345_0314))));
-        u03b1_0345.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FB2}'), None));
-        u03b1_0345.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FB4}'), None));
-        u03b1_0345.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FB7}'), None));
-        u03b1.insert('\u{0345}', ReverseTreeNode::new(Some('\u{1FB3}'), Some(Box::new(u03b1_0345))));
-        u03b1.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1FB0}'), None));
-        u03b1.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1FB1}'), None));
-        u03b1.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FB6}'), None));
-        root.insert('\u{03B1}', ReverseTreeNode::new(None, Some(Box::new(u03b1))));
-        let mut u03b5 = AHashMap::new();
-        u03b5.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03AD}'), None));
-        let mut u03b5_0313 = AHashMap::new();
-        u03b5_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F12}'), None));
-        u03b5_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F14}'), None));
-        u03b5.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F10}'), Some(Box::new(u03b5_0313))));
-        let mut u03b5_0314 = AHashMap::new();
-        u03b5_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F13}'), None));
-        u03b5_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F15}'), None));
-        u03b5.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F11}'), Some(Box::new(u03b5_0314))));
-        u03b5.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F72}'), None));
-        u03b5.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F73}'), None));
-        root.insert('\u{03B5}', ReverseTreeNode::new(None, Some(Box::new(u03b5))));
-        let mut u03b7 = AHashMap::new();
-        u03b7.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03AE}'), None));
-        let mut u03b7_0313 = AHashMap::new();
-        u03b7_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F22}'), None));
-        u03b7_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F24}'), None));
-        u03b7_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F26}'), None));
-        u03b7.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F20}'), Some(Box::new(u03b7_0313))));
-        let mut u03b7_0314 = AHashMap::new();
-        u03b7_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F23}'), None));
-        u03b7_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F25}'), None));
-        u03b7_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F27}'), None));
-        u03b7.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F21}'), Some(Box::new(u03b7_0314))));
-        u03b7.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F74}'), None));
-        u03b7.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F75}'), None));
-        let mut u03b7_0345 = AHashMap::new();
-        let mut u03b7_0345_0313 = AHashMap::new();
-        u03b7_0345_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F92}'), None));
-        u03b7_0345_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F94}'), None));
-        u03b7_0345_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F96}'), None));
-        u03b7_0345.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F90}'), Some(Box::new(u03b7_0345_0313))));
-        let mut u03b7_0345_0314 = AHashMap::new();
-        u03b7_0345_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F93}'), None));
-        u03b7_0345_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F95}'), None));
-        u03b7_0345_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F97}'), None));
-        u03b7_0345.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F91}'), Some(Box::new(u03b7_0345_0314))));
-        u03b7_0345.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FC2}'), None));
-        u03b7_0345.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FC4}'), None));
-        u03b7_0345.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FC7}'), None));
-        u03b7.insert('\u{0345}', ReverseTreeNode::new(Some('\u{1FC3}'), Some(Box::new(u03b7_0345))));
-        u03b7.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FC6}'), None));
-        root.insert('\u{03B7}', ReverseTreeNode::new(None, Some(Box::new(u03b7))));
-        let mut u03c5 = AHashMap::new();
-        let mut u03c5_0308 = AHashMap::new();
-        u03c5_0308.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03B0}'), None));
-        u03c5_0308.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FE2}'), None));
-        u03c5_0308.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FE3}'), None));
-        u03c5_0308.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FE7}'), None));
-        u03c5.insert('\u{0308}', ReverseTreeNode::new(Some('\u{03CB}'), Some(Box::new(u03c5_0308))));
-        u03c5.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03CD}'), None));
-        let mut u03c5_0313 = AHashMap::new();
-        u03c5_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F52}'), None));
-        u03c5_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F54}'), None));
-        u03c5_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F56}'), None));
-        u03c5.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F50}'), Some(Box::new(u03c5_0313))));
-        let mut u03c5_0314 = AHashMap::new();
-        u03c5_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F53}'), None));
-        u03c5_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F55}'), None));
-        u03c5_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F57}'), None));
-        u03c5.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F51}'), Some(Box::new(u03c5_0314))));
-        u03c5.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F7A}'), None));
-        u03c5.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F7B}'), None));
-        u03c5.insert('\u{0306}', ReverseTreeNode::new(Some('\u{1FE0}'), None));
-        u03c5.insert('\u{0304}', ReverseTreeNode::new(Some('\u{1FE1}'), None));
-        u03c5.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FE6}'), None));
-        root.insert('\u{03C5}', ReverseTreeNode::new(None, Some(Box::new(u03c5))));
-        let mut u03bf = AHashMap::new();
-        u03bf.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03CC}'), None));
-        let mut u03bf_0313 = AHashMap::new();
-        u03bf_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F42}'), None));
-        u03bf_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F44}'), None));
-        u03bf.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F40}'), Some(Box::new(u03bf_0313))));
-        let mut u03bf_0314 = AHashMap::new();
-        u03bf_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F43}'), None));
-        u03bf_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F45}'), None));
-        u03bf.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F41}'), Some(Box::new(u03bf_0314))));
-        u03bf.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F78}'), None));
-        u03bf.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F79}'), None));
-        let mut u03bf_0345 = AHashMap::new();
-        u03bf_0345.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FF4}'), None));
-        u03bf.insert('\u{0345}', ReverseTreeNode::new(None, Some(Box::new(u03bf_0345))));
-        root.insert('\u{03BF}', ReverseTreeNode::new(None, Some(Box::new(u03bf))));
-        let mut u03c9 = AHashMap::new();
-        u03c9.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03CE}'), None));
-        let mut u03c9_0313 = AHashMap::new();
-        u03c9_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F62}'), None));
-        u03c9_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F64}'), None));
-        u03c9_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F66}'), None));
-        u03c9.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1F60}'), Some(Box::new(u03c9_0313))));
-        let mut u03c9_0314 = AHashMap::new();
-        u03c9_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F63}'), None));
-        u03c9_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F65}'), None));
-        u03c9_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1F67}'), None));
-        u03c9.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1F61}'), Some(Box::new(u03c9_0314))));
-        u03c9.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1F7C}'), None));
-        u03c9.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1F7D}'), None));
-        let mut u03c9_0345 = AHashMap::new();
-        let mut u03c9_0345_0313 = AHashMap::new();
-        u03c9_0345_0313.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FA2}'), None));
-        u03c9_0345_0313.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FA4}'), None));
-        u03c9_0345_0313.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FA6}'), None));
-        u03c9_0345.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1FA0}'), Some(Box::new(u03c9_0345_0313))));
-        let mut u03c9_0345_0314 = AHashMap::new();
-        u03c9_0345_0314.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FA3}'), None));
-        u03c9_0345_0314.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FA5}'), None));
-        u03c9_0345_0314.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FA7}'), None));
-        u03c9_0345.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1FA1}'), Some(Box::new(u03c9_0345_0314))));
-        u03c9_0345.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FF2}'), None));
-        u03c9_0345.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FF7}'), None));
-        u03c9.insert('\u{0345}', ReverseTreeNode::new(Some('\u{1FF3}'), Some(Box::new(u03c9_0345))));
-        u03c9.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FF6}'), None));
-        root.insert('\u{03C9}', ReverseTreeNode::new(None, Some(Box::new(u03c9))));
-        let mut u03d2 = AHashMap::new();
-        u03d2.insert('\u{030D}', ReverseTreeNode::new(Some('\u{03D3}'), None));
-        u03d2.insert('\u{0308}', ReverseTreeNode::new(Some('\u{03D4}'), None));
-        root.insert('\u{03D2}', ReverseTreeNode::new(None, Some(Box::new(u03d2))));
-        let mut u0415 = AHashMap::new();
-        u0415.insert('\u{0308}', ReverseTreeNode::new(Some('\u{0401}'), None));
-        u0415.insert('\u{0306}', ReverseTreeNode::new(Some('\u{04D6}'), None));
-        root.insert('\u{0415}', ReverseTreeNode::new(None, Some(Box::new(u0415))));
-        let mut u0413 = AHashMap::new();
-        u0413.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0403}'), None));
-        root.insert('\u{0413}', ReverseTreeNode::new(None, Some(Box::new(u0413))));
-        let mut u0406 = AHashMap::new();
-        u0406.insert('\u{0308}', ReverseTreeNode::new(Some('\u{0407}'), None));
-        root.insert('\u{0406}', ReverseTreeNode::new(None, Some(Box::new(u0406))));
-        let mut u041a = AHashMap::new();
-        u041a.insert('\u{0301}', ReverseTreeNode::new(Some('\u{040C}'), None));
-        root.insert('\u{041A}', ReverseTreeNode::new(None, Some(Box::new(u041a))));
-        let mut u0423 = AHashMap::new();
-        u0423.insert('\u{0306}', ReverseTreeNode::new(Some('\u{040E}'), None));
-        u0423.insert('\u{0304}', ReverseTreeNode::new(Some('\u{04EE}'), None));
-        u0423.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04F0}'), None));
-        u0423.insert('\u{030B}', ReverseTreeNode::new(Some('\u{04F2}'), None));
-        root.insert('\u{0423}', ReverseTreeNode::new(None, Some(Box::new(u0423))));
-        let mut u0418 = AHashMap::new();
-        u0418.insert('\u{0306}', ReverseTreeNode::new(Some('\u{0419}'), None));
-        u0418.insert('\u{0304}', ReverseTreeNode::new(Some('\u{04E2}'), None));
-        u0418.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04E4}'), None));
-        root.insert('\u{0418}', ReverseTreeNode::new(None, Some(Box::new(u0418))));
-        let mut u0438 = AHashMap::new();
-        u0438.insert('\u{0306}', ReverseTreeNode::new(Some('\u{0439}'), None));
-        u0438.insert('\u{0304}', ReverseTreeNode::new(Some('\u{04E3}'), None));
-        u0438.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04E5}'), None));
-        root.insert('\u{0438}', ReverseTreeNode::new(None, Some(Box::new(u0438))));
-        let mut u0435 = AHashMap::new();
-        u0435.insert('\u{0308}', ReverseTreeNode::new(Some('\u{0451}'), None));
-        u0435.insert('\u{0306}', ReverseTreeNode::new(Some('\u{04D7}'), None));
-        root.insert('\u{0435}', ReverseTreeNode::new(None, Some(Box::new(u0435))));
-        let mut u0433 = AHashMap::new();
-        u0433.insert('\u{0301}', ReverseTreeNode::new(Some('\u{0453}'), None));
-        root.insert('\u{0433}', ReverseTreeNode::new(None, Some(Box::new(u0433))));
-        let mut u0456 = AHashMap::new();
-        u0456.insert('\u{0308}', ReverseTreeNode::new(Some('\u{0457}'), None));
-        root.insert('\u{0456}', ReverseTreeNode::new(None, Some(Box::new(u0456))));
-        let mut u043a = AHashMap::new();
-        u043a.insert('\u{0301}', ReverseTreeNode::new(Some('\u{045C}'), None));
-        root.insert('\u{043A}', ReverseTreeNode::new(None, Some(Box::new(u043a))));
-        let mut u0443 = AHashMap::new();
-        u0443.insert('\u{0306}', ReverseTreeNode::new(Some('\u{045E}'), None));
-        u0443.insert('\u{0304}', ReverseTreeNode::new(Some('\u{04EF}'), None));
-        u0443.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04F1}'), None));
-        u0443.insert('\u{030B}', ReverseTreeNode::new(Some('\u{04F3}'), None));
-        root.insert('\u{0443}', ReverseTreeNode::new(None, Some(Box::new(u0443))));
-        let mut u0474 = AHashMap::new();
-        u0474.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0476}'), None));
-        root.insert('\u{0474}', ReverseTreeNode::new(None, Some(Box::new(u0474))));
-        let mut u0475 = AHashMap::new();
-        u0475.insert('\u{030F}', ReverseTreeNode::new(Some('\u{0477}'), None));
-        root.insert('\u{0475}', ReverseTreeNode::new(None, Some(Box::new(u0475))));
-        let mut u0416 = AHashMap::new();
-        u0416.insert('\u{0306}', ReverseTreeNode::new(Some('\u{04C1}'), None));
-        u0416.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04DC}'), None));
-        root.insert('\u{0416}', ReverseTreeNode::new(None, Some(Box::new(u0416))));
-        let mut u0436 = AHashMap::new();
-        u0436.insert('\u{0306}', ReverseTreeNode::new(Some('\u{04C2}'), None));
-        u0436.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04DD}'), None));
-        root.insert('\u{0436}', ReverseTreeNode::new(None, Some(Box::new(u0436))));
-        let mut u0410 = AHashMap::new();
-        u0410.insert('\u{0306}', ReverseTreeNode::new(Some('\u{04D0}'), None));
-        u0410.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04D2}'), None));
-        root.insert('\u{0410}', ReverseTreeNode::new(None, Some(Box::new(u0410))));
-        let mut u0430 = AHashMap::new();
-        u0430.insert('\u{0306}', ReverseTreeNode::new(Some('\u{04D1}'), None));
-        u0430.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04D3}'), None));
-        root.insert('\u{0430}', ReverseTreeNode::new(None, Some(Box::new(u0430))));
-        let mut u018f = AHashMap::new();
-        u018f.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04DA}'), None));
-        root.insert('\u{018F}', ReverseTreeNode::new(None, Some(Box::new(u018f))));
-        let mut u0259 = AHashMap::new();
-        u0259.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04DB}'), None));
-        root.insert('\u{0259}', ReverseTreeNode::new(None, Some(Box::new(u0259))));
-        let mut u0417 = AHashMap::new();
-        u0417.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04DE}'), None));
-        root.insert('\u{0417}', ReverseTreeNode::new(None, Some(Box::new(u0417))));
-        let mut u0437 = AHashMap::new();
-        u0437.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04DF}'), None));
-        root.insert('\u{0437}', ReverseTreeNode::new(None, Some(Box::new(u0437))));
-        let mut u041e = AHashMap::new();
-        u041e.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04E6}'), None));
-        root.insert('\u{041E}', ReverseTreeNode::new(None, Some(Box::new(u041e))));
-        let mut u043e = AHashMap::new();
-        u043e.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04E7}'), None));
-        root.insert('\u{043E}', ReverseTreeNode::new(None, Some(Box::new(u043e))));
-        let mut u019f = AHashMap::new();
-        u019f.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04EA}'), None));
-        root.insert('\u{019F}', ReverseTreeNode::new(None, Some(Box::new(u019f))));
-        let mut u0275 = AHashMap::new();
-        u0275.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04EB}'), None));
-        root.insert('\u{0275}', ReverseTreeNode::new(None, Some(Box::new(u0275))));
-        let mut u0427 = AHashMap::new();
-        u0427.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04F4}'), None));
-        root.insert('\u{0427}', ReverseTreeNode::new(None, Some(Box::new(u0427))));
-        let mut u0447 = AHashMap::new();
-        u0447.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04F5}'), None));
-        root.insert('\u{0447}', ReverseTreeNode::new(None, Some(Box::new(u0447))));
-        let mut u042b = AHashMap::new();
-        u042b.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04F8}'), None));
-        root.insert('\u{042B}', ReverseTreeNode::new(None, Some(Box::new(u042b))));
-        let mut u044b = AHashMap::new();
-        u044b.insert('\u{0308}', ReverseTreeNode::new(Some('\u{04F9}'), None));
-        root.insert('\u{044B}', ReverseTreeNode::new(None, Some(Box::new(u044b))));
-        let mut u0928 = AHashMap::new();
-        u0928.insert('\u{093C}', ReverseTreeNode::new(Some('\u{0929}'), None));
-        root.insert('\u{0928}', ReverseTreeNode::new(None, Some(Box::new(u0928))));
-        let mut u0930 = AHashMap::new();
-        u0930.insert('\u{093C}', ReverseTreeNode::new(Some('\u{0931}'), None));
-        root.insert('\u{0930}', ReverseTreeNode::new(None, Some(Box::new(u0930))));
-        let mut u0933 = AHashMap::new();
-        u0933.insert('\u{093C}', ReverseTreeNode::new(Some('\u{0934}'), None));
-        root.insert('\u{0933}', ReverseTreeNode::new(None, Some(Box::new(u0933))));
-        let mut u0915 = AHashMap::new();
-        u0915.insert('\u{093C}', ReverseTreeNode::new(Some('\u{0958}'), None));
-        root.insert('\u{0915}', ReverseTreeNode::new(None, Some(Box::new(u0915))));
-        let mut u0916 = AHashMap::new();
-        u0916.insert('\u{093C}', ReverseTreeNode::new(Some('\u{0959}'), None));
-        root.insert('\u{0916}', ReverseTreeNode::new(None, Some(Box::new(u0916))));
-        let mut u0917 = AHashMap::new();
-        u0917.insert('\u{093C}', ReverseTreeNode::new(Some('\u{095A}'), None));
-        root.insert('\u{0917}', ReverseTreeNode::new(None, Some(Box::new(u0917))));
-        let mut u091c = AHashMap::new();
-        u091c.insert('\u{093C}', ReverseTreeNode::new(Some('\u{095B}'), None));
-        root.insert('\u{091C}', ReverseTreeNode::new(None, Some(Box::new(u091c))));
-        let mut u0921 = AHashMap::new();
-        u0921.insert('\u{093C}', ReverseTreeNode::new(Some('\u{095C}'), None));
-        root.insert('\u{0921}', ReverseTreeNode::new(None, Some(Box::new(u0921))));
-        let mut u0922 = AHashMap::new();
-        u0922.insert('\u{093C}', ReverseTreeNode::new(Some('\u{095D}'), None));
-        root.insert('\u{0922}', ReverseTreeNode::new(None, Some(Box::new(u0922))));
-        let mut u092b = AHashMap::new();
-        u092b.insert('\u{093C}', ReverseTreeNode::new(Some('\u{095E}'), None));
-        root.insert('\u{092B}', ReverseTreeNode::new(None, Some(Box::new(u092b))));
-        let mut u092f = AHashMap::new();
-        u092f.insert('\u{093C}', ReverseTreeNode::new(Some('\u{095F}'), None));
-        root.insert('\u{092F}', ReverseTreeNode::new(None, Some(Box::new(u092f))));
-        let mut u09ac = AHashMap::new();
-        u09ac.insert('\u{09BC}', ReverseTreeNode::new(Some('\u{09B0}'), None));
-        root.insert('\u{09AC}', ReverseTreeNode::new(None, Some(Box::new(u09ac))));
-        let mut u09c7 = AHashMap::new();
-        u09c7.insert('\u{09BE}', ReverseTreeNode::new(Some('\u{09CB}'), None));
-        u09c7.insert('\u{09D7}', ReverseTreeNode::new(Some('\u{09CC}'), None));
-        root.insert('\u{09C7}', ReverseTreeNode::new(None, Some(Box::new(u09c7))));
-        let mut u09a1 = AHashMap::new();
-        u09a1.insert('\u{09BC}', ReverseTreeNode::new(Some('\u{09DC}'), None));
-        root.insert('\u{09A1}', ReverseTreeNode::new(None, Some(Box::new(u09a1))));
-        let mut u09a2 = AHashMap::new();
-        u09a2.insert('\u{09BC}', ReverseTreeNode::new(Some('\u{09DD}'), None));
-        root.insert('\u{09A2}', ReverseTreeNode::new(None, Some(Box::new(u09a2))));
-        let mut u09af = AHashMap::new();
-        u09af.insert('\u{09BC}', ReverseTreeNode::new(Some('\u{09DF}'), None));
-        root.insert('\u{09AF}', ReverseTreeNode::new(None, Some(Box::new(u09af))));
-        let mut u0a16 = AHashMap::new();
-        u0a16.insert('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A59}'), None));
-        root.insert('\u{0A16}', ReverseTreeNode::new(None, Some(Box::new(u0a16))));
-        let mut u0a17 = AHashMap::new();
-        u0a17.insert('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A5A}'), None));
-        root.insert('\u{0A17}', ReverseTreeNode::new(None, Some(Box::new(u0a17))));
-        let mut u0a1c = AHashMap::new();
-        u0a1c.insert('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A5B}'), None));
-        root.insert('\u{0A1C}', ReverseTreeNode::new(None, Some(Box::new(u0a1c))));
-        let mut u0a21 = AHashMap::new();
-        u0a21.insert('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A5C}'), None));
-        root.insert('\u{0A21}', ReverseTreeNode::new(None, Some(Box::new(u0a21))));
-        let mut u0a2b = AHashMap::new();
-        u0a2b.insert('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A5E}'), None));
-        root.insert('\u{0A2B}', ReverseTreeNode::new(None, Some(Box::new(u0a2b))));
-        let mut u0b47 = AHashMap::new();
-        u0b47.insert('\u{0B56}', ReverseTreeNode::new(Some('\u{0B48}'), None));
-        u0b47.insert('\u{0B3E}', ReverseTreeNode::new(Some('\u{0B4B}'), None));
-        u0b47.insert('\u{0B57}', ReverseTreeNode::new(Some('\u{0B4C}'), None));
-        root.insert('\u{0B47}', ReverseTreeNode::new(None, Some(Box::new(u0b47))));
-        let mut u0b21 = AHashMap::new();
-        u0b21.insert('\u{0B3C}', ReverseTreeNode::new(Some('\u{0B5C}'), None));
-        root.insert('\u{0B21}', ReverseTreeNode::new(None, Some(Box::new(u0b21))));
-        let mut u0b22 = AHashMap::new();
-        u0b22.insert('\u{0B3C}', ReverseTreeNode::new(Some('\u{0B5D}'), None));
-        root.insert('\u{0B22}', ReverseTreeNode::new(None, Some(Box::new(u0b22))));
-        let mut u0b2f = AHashMap::new();
-        u0b2f.insert('\u{0B3C}', ReverseTreeNode::new(Some('\u{0B5F}'), None));
-        root.insert('\u{0B2F}', ReverseTreeNode::new(None, Some(Box::new(u0b2f))));
-        let mut u0b92 = AHashMap::new();
-        u0b92.insert('\u{0BD7}', ReverseTreeNode::new(Some('\u{0B94}'), None));
-        root.insert('\u{0B92}', ReverseTreeNode::new(None, Some(Box::new(u0b92))));
-        let mut u0bc6 = AHashMap::new();
-        u0bc6.insert('\u{0BBE}', ReverseTreeNode::new(Some('\u{0BCA}'), None));
-        u0bc6.insert('\u{0BD7}', ReverseTreeNode::new(Some('\u{0BCC}'), None));
-        root.insert('\u{0BC6}', ReverseTreeNode::new(None, Some(Box::new(u0bc6))));
-        let mut u0bc7 = AHashMap::new();
-        u0bc7.insert('\u{0BBE}', ReverseTreeNode::new(Some('\u{0BCB}'), None));
-        root.insert('\u{0BC7}', ReverseTreeNode::new(None, Some(Box::new(u0bc7))));
-        let mut u0c46 = AHashMap::new();
-        u0c46.insert('\u{0C56}', ReverseTreeNode::new(Some('\u{0C48}'), None));
-        root.insert('\u{0C46}', ReverseTreeNode::new(None, Some(Box::new(u0c46))));
-        let mut u0cbf = AHashMap::new();
-        u0cbf.insert('\u{0CD5}', ReverseTreeNode::new(Some('\u{0CC0}'), None));
-        root.insert('\u{0CBF}', ReverseTreeNode::new(None, Some(Box::new(u0cbf))));
-        let mut u0cc6 = AHashMap::new();
-        u0cc6.insert('\u{0CD5}', ReverseTreeNode::new(Some('\u{0CC7}'), None));
-        u0cc6.insert('\u{0CD6}', ReverseTreeNode::new(Some('\u{0CC8}'), None));
-        let mut u0cc6_0cc2 = AHashMap::new();
-        u0cc6_0cc2.insert('\u{0CD5}', ReverseTreeNode::new(Some('\u{0CCB}'), None));
-        u0cc6.insert('\u{0CC2}', ReverseTreeNode::new(Some('\u{0CCA}'), Some(Box::new(u0cc6_0cc2))));
-        root.insert('\u{0CC6}', ReverseTreeNode::new(None, Some(Box::new(u0cc6))));
-        let mut u0d46 = AHashMap::new();
-        u0d46.insert('\u{0D3E}', ReverseTreeNode::new(Some('\u{0D4A}'), None));
-        u0d46.insert('\u{0D57}', ReverseTreeNode::new(Some('\u{0D4C}'), None));
-        root.insert('\u{0D46}', ReverseTreeNode::new(None, Some(Box::new(u0d46))));
-        let mut u0d47 = AHashMap::new();
-        u0d47.insert('\u{0D3E}', ReverseTreeNode::new(Some('\u{0D4B}'), None));
-        root.insert('\u{0D47}', ReverseTreeNode::new(None, Some(Box::new(u0d47))));
-        let mut u0e4d = AHashMap::new();
-        u0e4d.insert('\u{0E32}', ReverseTreeNode::new(Some('\u{0E33}'), None));
-        root.insert('\u{0E4D}', ReverseTreeNode::new(None, Some(Box::new(u0e4d))));
-        let mut u0ecd = AHashMap::new();
-        u0ecd.insert('\u{0EB2}', ReverseTreeNode::new(Some('\u{0EB3}'), None));
-        root.insert('\u{0ECD}', ReverseTreeNode::new(None, Some(Box::new(u0ecd))));
-        let mut u0f42 = AHashMap::new();
-        u0f42.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F43}'), None));
-        root.insert('\u{0F42}', ReverseTreeNode::new(None, Some(Box::new(u0f42))));
-        let mut u0f4c = AHashMap::new();
-        u0f4c.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F4D}'), None));
-        root.insert('\u{0F4C}', ReverseTreeNode::new(None, Some(Box::new(u0f4c))));
-        let mut u0f51 = AHashMap::new();
-        u0f51.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F52}'), None));
-        root.insert('\u{0F51}', ReverseTreeNode::new(None, Some(Box::new(u0f51))));
-        let mut u0f56 = AHashMap::new();
-        u0f56.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F57}'), None));
-        root.insert('\u{0F56}', ReverseTreeNode::new(None, Some(Box::new(u0f56))));
-        let mut u0f5b = AHashMap::new();
-        u0f5b.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F5C}'), None));
-        root.insert('\u{0F5B}', ReverseTreeNode::new(None, Some(Box::new(u0f5b))));
-        let mut u0f40 = AHashMap::new();
-        u0f40.insert('\u{0FB5}', ReverseTreeNode::new(Some('\u{0F69}'), None));
-        root.insert('\u{0F40}', ReverseTreeNode::new(None, Some(Box::new(u0f40))));
-        let mut u0f72 = AHashMap::new();
-        u0f72.insert('\u{0F71}', ReverseTreeNode::new(Some('\u{0F73}'), None));
-        root.insert('\u{0F72}', ReverseTreeNode::new(None, Some(Box::new(u0f72))));
-        let mut u0f74 = AHashMap::new();
-        u0f74.insert('\u{0F71}', ReverseTreeNode::new(Some('\u{0F75}'), None));
-        root.insert('\u{0F74}', ReverseTreeNode::new(None, Some(Box::new(u0f74))));
-        let mut u0fb2 = AHashMap::new();
-        let mut u0fb2_0f80 = AHashMap::new();
-        u0fb2_0f80.insert('\u{0F71}', ReverseTreeNode::new(Some('\u{0F77}'), None));
-        u0fb2.insert('\u{0F80}', ReverseTreeNode::new(Some('\u{0F76}'), Some(Box::new(u0fb2_0f80))));
-        root.insert('\u{0FB2}', ReverseTreeNode::new(None, Some(Box::new(u0fb2))));
-        let mut u0fb3 = AHashMap::new();
-        let mut u0fb3_0f80 = AHashMap::new();
-        u0fb3_0f80.insert('\u{0F71}', ReverseTreeNode::new(Some('\u{0F79}'), None));
-        u0fb3.insert('\u{0F80}', ReverseTreeNode::new(Some('\u{0F78}'), Some(Box::new(u0fb3_0f80))));
-        root.insert('\u{0FB3}', ReverseTreeNode::new(None, Some(Box::new(u0fb3))));
-        let mut u0f80 = AHashMap::new();
-        u0f80.insert('\u{0F71}', ReverseTreeNode::new(Some('\u{0F81}'), None));
-        root.insert('\u{0F80}', ReverseTreeNode::new(None, Some(Box::new(u0f80))));
-        let mut u0f92 = AHashMap::new();
-        u0f92.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F93}'), None));
-        root.insert('\u{0F92}', ReverseTreeNode::new(None, Some(Box::new(u0f92))));
-        let mut u0f9c = AHashMap::new();
-        u0f9c.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F9D}'), None));
-        root.insert('\u{0F9C}', ReverseTreeNode::new(None, Some(Box::new(u0f9c))));
-        let mut u0fa1 = AHashMap::new();
-        u0fa1.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0FA2}'), None));
-        root.insert('\u{0FA1}', ReverseTreeNode::new(None, Some(Box::new(u0fa1))));
-        let mut u0fa6 = AHashMap::new();
-        u0fa6.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0FA7}'), None));
-        root.insert('\u{0FA6}', ReverseTreeNode::new(None, Some(Box::new(u0fa6))));
-        let mut u0fab = AHashMap::new();
-        u0fab.insert('\u{0FB7}', ReverseTreeNode::new(Some('\u{0FAC}'), None));
-        root.insert('\u{0FAB}', ReverseTreeNode::new(None, Some(Box::new(u0fab))));
-        let mut u0f90 = AHashMap::new();
-        u0f90.insert('\u{0FB5}', ReverseTreeNode::new(Some('\u{0FB9}'), None));
-        root.insert('\u{0F90}', ReverseTreeNode::new(None, Some(Box::new(u0f90))));
-        let mut u0042 = AHashMap::new();
-        u0042.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E02}'), None));
-        u0042.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E04}'), None));
-        u0042.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E06}'), None));
-        root.insert('\u{0042}', ReverseTreeNode::new(None, Some(Box::new(u0042))));
-        let mut u0062 = AHashMap::new();
-        u0062.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E03}'), None));
-        u0062.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E05}'), None));
-        u0062.insert('\u{0331}', ReverseTreeNode::new(Some('\u{1E07}'), None));
-        root.insert('\u{0062}', ReverseTreeNode::new(None, Some(Box::new(u0062))));
-        let mut u0046 = AHashMap::new();
-        u0046.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E1E}'), None));
-        root.insert('\u{0046}', ReverseTreeNode::new(None, Some(Box::new(u0046))));
-        let mut u0066 = AHashMap::new();
-        u0066.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E1F}'), None));
-        root.insert('\u{0066}', ReverseTreeNode::new(None, Some(Box::new(u0066))));
-        let mut u004d = AHashMap::new();
-        u004d.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E3E}'), None));
-        u004d.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E40}'), None));
-        u004d.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E42}'), None));
-        root.insert('\u{004D}', ReverseTreeNode::new(None, Some(Box::new(u004d))));
-        let mut u006d = AHashMap::new();
-        u006d.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E3F}'), None));
-        u006d.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E41}'), None));
-        u006d.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E43}'), None));
-        root.insert('\u{006D}', ReverseTreeNode::new(None, Some(Box::new(u006d))));
-        let mut u0050 = AHashMap::new();
-        u0050.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E54}'), None));
-        u0050.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E56}'), None));
-        root.insert('\u{0050}', ReverseTreeNode::new(None, Some(Box::new(u0050))));
-        let mut u0070 = AHashMap::new();
-        u0070.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1E55}'), None));
-        u0070.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E57}'), None));
-        root.insert('\u{0070}', ReverseTreeNode::new(None, Some(Box::new(u0070))));
-        let mut u0056 = AHashMap::new();
-        u0056.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1E7C}'), None));
-        u0056.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E7E}'), None));
-        root.insert('\u{0056}', ReverseTreeNode::new(None, Some(Box::new(u0056))));
-        let mut u0076 = AHashMap::new();
-        u0076.insert('\u{0303}', ReverseTreeNode::new(Some('\u{1E7D}'), None));
-        u0076.insert('\u{0323}', ReverseTreeNode::new(Some('\u{1E7F}'), None));
-        root.insert('\u{0076}', ReverseTreeNode::new(None, Some(Box::new(u0076))));
-        let mut u0058 = AHashMap::new();
-        u0058.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E8A}'), None));
-        u0058.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E8C}'), None));
-        root.insert('\u{0058}', ReverseTreeNode::new(None, Some(Box::new(u0058))));
-        let mut u0078 = AHashMap::new();
-        u0078.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E8B}'), None));
-        u0078.insert('\u{0308}', ReverseTreeNode::new(Some('\u{1E8D}'), None));
-        root.insert('\u{0078}', ReverseTreeNode::new(None, Some(Box::new(u0078))));
-        let mut u017f = AHashMap::new();
-        u017f.insert('\u{0307}', ReverseTreeNode::new(Some('\u{1E9B}'), None));
-        root.insert('\u{017F}', ReverseTreeNode::new(None, Some(Box::new(u017f))));
-        let mut u1fbf = AHashMap::new();
-        u1fbf.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FCD}'), None));
-        u1fbf.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FCE}'), None));
-        u1fbf.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FCF}'), None));
-        root.insert('\u{1FBF}', ReverseTreeNode::new(None, Some(Box::new(u1fbf))));
-        let mut u1ffe = AHashMap::new();
-        u1ffe.insert('\u{0300}', ReverseTreeNode::new(Some('\u{1FDD}'), None));
-        u1ffe.insert('\u{0301}', ReverseTreeNode::new(Some('\u{1FDE}'), None));
-        u1ffe.insert('\u{0342}', ReverseTreeNode::new(Some('\u{1FDF}'), None));
-        root.insert('\u{1FFE}', ReverseTreeNode::new(None, Some(Box::new(u1ffe))));
-        let mut u03c1 = AHashMap::new();
-        u03c1.insert('\u{0313}', ReverseTreeNode::new(Some('\u{1FE4}'), None));
-        u03c1.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1FE5}'), None));
-        root.insert('\u{03C1}', ReverseTreeNode::new(None, Some(Box::new(u03c1))));
-        let mut u03a1 = AHashMap::new();
-        u03a1.insert('\u{0314}', ReverseTreeNode::new(Some('\u{1FEC}'), None));
-        root.insert('\u{03A1}', ReverseTreeNode::new(None, Some(Box::new(u03a1))));
-        let mut u304b = AHashMap::new();
-        u304b.insert('\u{3099}', ReverseTreeNode::new(Some('\u{304C}'), None));
-        root.insert('\u{304B}', ReverseTreeNode::new(None, Some(Box::new(u304b))));
-        let mut u304d = AHashMap::new();
-        u304d.insert('\u{3099}', ReverseTreeNode::new(Some('\u{304E}'), None));
-        root.insert('\u{304D}', ReverseTreeNode::new(None, Some(Box::new(u304d))));
-        let mut u304f = AHashMap::new();
-        u304f.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3050}'), None));
-        root.insert('\u{304F}', ReverseTreeNode::new(None, Some(Box::new(u304f))));
-        let mut u3051 = AHashMap::new();
-        u3051.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3052}'), None));
-        root.insert('\u{3051}', ReverseTreeNode::new(None, Some(Box::new(u3051))));
-        let mut u3053 = AHashMap::new();
-        u3053.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3054}'), None));
-        root.insert('\u{3053}', ReverseTreeNode::new(None, Some(Box::new(u3053))));
-        let mut u3055 = AHashMap::new();
-        u3055.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3056}'), None));
-        root.insert('\u{3055}', ReverseTreeNode::new(None, Some(Box::new(u3055))));
-        let mut u3057 = AHashMap::new();
-        u3057.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3058}'), None));
-        root.insert('\u{3057}', ReverseTreeNode::new(None, Some(Box::new(u3057))));
-        let mut u3059 = AHashMap::new();
-        u3059.insert('\u{3099}', ReverseTreeNode::new(Some('\u{305A}'), None));
-        root.insert('\u{3059}', ReverseTreeNode::new(None, Some(Box::new(u3059))));
-        let mut u305b = AHashMap::new();
-        u305b.insert('\u{3099}', ReverseTreeNode::new(Some('\u{305C}'), None));
-        root.insert('\u{305B}', ReverseTreeNode::new(None, Some(Box::new(u305b))));
-        let mut u305d = AHashMap::new();
-        u305d.insert('\u{3099}', ReverseTreeNode::new(Some('\u{305E}'), None));
-        root.insert('\u{305D}', ReverseTreeNode::new(None, Some(Box::new(u305d))));
-        let mut u305f = AHashMap::new();
-        u305f.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3060}'), None));
-        root.insert('\u{305F}', ReverseTreeNode::new(None, Some(Box::new(u305f))));
-        let mut u3061 = AHashMap::new();
-        u3061.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3062}'), None));
-        root.insert('\u{3061}', ReverseTreeNode::new(None, Some(Box::new(u3061))));
-        let mut u3064 = AHashMap::new();
-        u3064.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3065}'), None));
-        root.insert('\u{3064}', ReverseTreeNode::new(None, Some(Box::new(u3064))));
-        let mut u3066 = AHashMap::new();
-        u3066.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3067}'), None));
-        root.insert('\u{3066}', ReverseTreeNode::new(None, Some(Box::new(u3066))));
-        let mut u3068 = AHashMap::new();
-        u3068.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3069}'), None));
-        root.insert('\u{3068}', ReverseTreeNode::new(None, Some(Box::new(u3068))));
-        let mut u306f = AHashMap::new();
-        u306f.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3070}'), None));
-        u306f.insert('\u{309A}', ReverseTreeNode::new(Some('\u{3071}'), None));
-        root.insert('\u{306F}', ReverseTreeNode::new(None, Some(Box::new(u306f))));
-        let mut u3072 = AHashMap::new();
-        u3072.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3073}'), None));
-        u3072.insert('\u{309A}', ReverseTreeNode::new(Some('\u{3074}'), None));
-        root.insert('\u{3072}', ReverseTreeNode::new(None, Some(Box::new(u3072))));
-        let mut u3075 = AHashMap::new();
-        u3075.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3076}'), None));
-        u3075.insert('\u{309A}', ReverseTreeNode::new(Some('\u{3077}'), None));
-        root.insert('\u{3075}', ReverseTreeNode::new(None, Some(Box::new(u3075))));
-        let mut u3078 = AHashMap::new();
-        u3078.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3079}'), None));
-        u3078.insert('\u{309A}', ReverseTreeNode::new(Some('\u{307A}'), None));
-        root.insert('\u{3078}', ReverseTreeNode::new(None, Some(Box::new(u3078))));
-        let mut u307b = AHashMap::new();
-        u307b.insert('\u{3099}', ReverseTreeNode::new(Some('\u{307C}'), None));
-        u307b.insert('\u{309A}', ReverseTreeNode::new(Some('\u{307D}'), None));
-        root.insert('\u{307B}', ReverseTreeNode::new(None, Some(Box::new(u307b))));
-        let mut u3046 = AHashMap::new();
-        u3046.insert('\u{3099}', ReverseTreeNode::new(Some('\u{3094}'), None));
-        root.insert('\u{3046}', ReverseTreeNode::new(None, Some(Box::new(u3046))));
-        let mut u309d = AHashMap::new();
-        u309d.insert('\u{3099}', ReverseTreeNode::new(Some('\u{309E}'), None));
-        root.insert('\u{309D}', ReverseTreeNode::new(None, Some(Box::new(u309d))));
-        let mut u30ab = AHashMap::new();
-        u30ab.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30AC}'), None));
-        root.insert('\u{30AB}', ReverseTreeNode::new(None, Some(Box::new(u30ab))));
-        let mut u30ad = AHashMap::new();
-        u30ad.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30AE}'), None));
-        root.insert('\u{30AD}', ReverseTreeNode::new(None, Some(Box::new(u30ad))));
-        let mut u30af = AHashMap::new();
-        u30af.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30B0}'), None));
-        root.insert('\u{30AF}', ReverseTreeNode::new(None, Some(Box::new(u30af))));
-        let mut u30b1 = AHashMap::new();
-        u30b1.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30B2}'), None));
-        root.insert('\u{30B1}', ReverseTreeNode::new(None, Some(Box::new(u30b1))));
-        let mut u30b3 = AHashMap::new();
-        u30b3.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30B4}'), None));
-        root.insert('\u{30B3}', ReverseTreeNode::new(None, Some(Box::new(u30b3))));
-        let mut u30b5 = AHashMap::new();
-        u30b5.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30B6}'), None));
-        root.insert('\u{30B5}', ReverseTreeNode::new(None, Some(Box::new(u30b5))));
-        let mut u30b7 = AHashMap::new();
-        u30b7.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30B8}'), None));
-        root.insert('\u{30B7}', ReverseTreeNode::new(None, Some(Box::new(u30b7))));
-        let mut u30b9 = AHashMap::new();
-        u30b9.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30BA}'), None));
-        root.insert('\u{30B9}', ReverseTreeNode::new(None, Some(Box::new(u30b9))));
-        let mut u30bb = AHashMap::new();
-        u30bb.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30BC}'), None));
-        root.insert('\u{30BB}', ReverseTreeNode::new(None, Some(Box::new(u30bb))));
-        let mut u30bd = AHashMap::new();
-        u30bd.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30BE}'), None));
-        root.insert('\u{30BD}', ReverseTreeNode::new(None, Some(Box::new(u30bd))));
-        let mut u30bf = AHashMap::new();
-        u30bf.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30C0}'), None));
-        root.insert('\u{30BF}', ReverseTreeNode::new(None, Some(Box::new(u30bf))));
-        let mut u30c1 = AHashMap::new();
-        u30c1.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30C2}'), None));
-        root.insert('\u{30C1}', ReverseTreeNode::new(None, Some(Box::new(u30c1))));
-        let mut u30c4 = AHashMap::new();
-        u30c4.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30C5}'), None));
-        root.insert('\u{30C4}', ReverseTreeNode::new(None, Some(Box::new(u30c4))));
-        let mut u30c6 = AHashMap::new();
-        u30c6.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30C7}'), None));
-        root.insert('\u{30C6}', ReverseTreeNode::new(None, Some(Box::new(u30c6))));
-        let mut u30c8 = AHashMap::new();
-        u30c8.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30C9}'), None));
-        root.insert('\u{30C8}', ReverseTreeNode::new(None, Some(Box::new(u30c8))));
-        let mut u30cf = AHashMap::new();
-        u30cf.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30D0}'), None));
-        u30cf.insert('\u{309A}', ReverseTreeNode::new(Some('\u{30D1}'), None));
-        root.insert('\u{30CF}', ReverseTreeNode::new(None, Some(Box::new(u30cf))));
-        let mut u30d2 = AHashMap::new();
-        u30d2.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30D3}'), None));
-        u30d2.insert('\u{309A}', ReverseTreeNode::new(Some('\u{30D4}'), None));
-        root.insert('\u{30D2}', ReverseTreeNode::new(None, Some(Box::new(u30d2))));
-        let mut u30d5 = AHashMap::new();
-        u30d5.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30D6}'), None));
-        u30d5.insert('\u{309A}', ReverseTreeNode::new(Some('\u{30D7}'), None));
-        root.insert('\u{30D5}', ReverseTreeNode::new(None, Some(Box::new(u30d5))));
-        let mut u30d8 = AHashMap::new();
-        u30d8.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30D9}'), None));
-        u30d8.insert('\u{309A}', ReverseTreeNode::new(Some('\u{30DA}'), None));
-        root.insert('\u{30D8}', ReverseTreeNode::new(None, Some(Box::new(u30d8))));
-        let mut u30db = AHashMap::new();
-        u30db.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30DC}'), None));
-        u30db.insert('\u{309A}', ReverseTreeNode::new(Some('\u{30DD}'), None));
-        root.insert('\u{30DB}', ReverseTreeNode::new(None, Some(Box::new(u30db))));
-        let mut u30a6 = AHashMap::new();
-        u30a6.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30F4}'), None));
-        root.insert('\u{30A6}', ReverseTreeNode::new(None, Some(Box::new(u30a6))));
-        let mut u30ef = AHashMap::new();
-        u30ef.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30F7}'), None));
-        root.insert('\u{30EF}', ReverseTreeNode::new(None, Some(Box::new(u30ef))));
-        let mut u30f0 = AHashMap::new();
-        u30f0.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30F8}'), None));
-        root.insert('\u{30F0}', ReverseTreeNode::new(None, Some(Box::new(u30f0))));
-        let mut u30f1 = AHashMap::new();
-        u30f1.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30F9}'), None));
-        root.insert('\u{30F1}', ReverseTreeNode::new(None, Some(Box::new(u30f1))));
-        let mut u30f2 = AHashMap::new();
-        u30f2.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30FA}'), None));
-        root.insert('\u{30F2}', ReverseTreeNode::new(None, Some(Box::new(u30f2))));
-        let mut u30fd = AHashMap::new();
-        u30fd.insert('\u{3099}', ReverseTreeNode::new(Some('\u{30FE}'), None));
-        root.insert('\u{30FD}', ReverseTreeNode::new(None, Some(Box::new(u30fd))));
-        let mut u05f2 = AHashMap::new();
-        u05f2.insert('\u{05B7}', ReverseTreeNode::new(Some('\u{FB1F}'), None));
-        root.insert('\u{05F2}', ReverseTreeNode::new(None, Some(Box::new(u05f2))));
-        let mut u05e9 = AHashMap::new();
-        u05e9.insert('\u{05C1}', ReverseTreeNode::new(Some('\u{FB2A}'), None));
-        u05e9.insert('\u{05C2}', ReverseTreeNode::new(Some('\u{FB2B}'), None));
-        let mut u05e9_05bc = AHashMap::new();
-        u05e9_05bc.insert('\u{05C1}', ReverseTreeNode::new(Some('\u{FB2C}'), None));
-        u05e9_05bc.insert('\u{05C2}', ReverseTreeNode::new(Some('\u{FB2D}'), None));
-        u05e9.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB49}'), Some(Box::new(u05e9_05bc))));
-        root.insert('\u{05E9}', ReverseTreeNode::new(None, Some(Box::new(u05e9))));
-        let mut u05d0 = AHashMap::new();
-        u05d0.insert('\u{05B7}', ReverseTreeNode::new(Some('\u{FB2E}'), None));
-        u05d0.insert('\u{05B8}', ReverseTreeNode::new(Some('\u{FB2F}'), None));
-        u05d0.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB30}'), None));
-        root.insert('\u{05D0}', ReverseTreeNode::new(None, Some(Box::new(u05d0))));
-        let mut u05d1 = AHashMap::new();
-        u05d1.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB31}'), None));
-        u05d1.insert('\u{05BF}', ReverseTreeNode::new(Some('\u{FB4C}'), None));
-        root.insert('\u{05D1}', ReverseTreeNode::new(None, Some(Box::new(u05d1))));
-        let mut u05d2 = AHashMap::new();
-        u05d2.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB32}'), None));
-        root.insert('\u{05D2}', ReverseTreeNode::new(None, Some(Box::new(u05d2))));
-        let mut u05d3 = AHashMap::new();
-        u05d3.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB33}'), None));
-        root.insert('\u{05D3}', ReverseTreeNode::new(None, Some(Box::new(u05d3))));
-        let mut u05d4 = AHashMap::new();
-        u05d4.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB34}'), None));
-        root.insert('\u{05D4}', ReverseTreeNode::new(None, Some(Box::new(u05d4))));
-        let mut u05d5 = AHashMap::new();
-        u05d5.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB35}'), None));
-        u05d5.insert('\u{05B9}', ReverseTreeNode::new(Some('\u{FB4B}'), None));
-        root.insert('\u{05D5}', ReverseTreeNode::new(None, Some(Box::new(u05d5))));
-        let mut u05d6 = AHashMap::new();
-        u05d6.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB36}'), None));
-        root.insert('\u{05D6}', ReverseTreeNode::new(None, Some(Box::new(u05d6))));
-        let mut u05d8 = AHashMap::new();
-        u05d8.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB38}'), None));
-        root.insert('\u{05D8}', ReverseTreeNode::new(None, Some(Box::new(u05d8))));
-        let mut u05d9 = AHashMap::new();
-        u05d9.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB39}'), None));
-        root.insert('\u{05D9}', ReverseTreeNode::new(None, Some(Box::new(u05d9))));
-        let mut u05da = AHashMap::new();
-        u05da.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB3A}'), None));
-        root.insert('\u{05DA}', ReverseTreeNode::new(None, Some(Box::new(u05da))));
-        let mut u05db = AHashMap::new();
-        u05db.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB3B}'), None));
-        u05db.insert('\u{05BF}', ReverseTreeNode::new(Some('\u{FB4D}'), None));
-        root.insert('\u{05DB}', ReverseTreeNode::new(None, Some(Box::new(u05db))));
-        let mut u05dc = AHashMap::new();
-        u05dc.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB3C}'), None));
-        root.insert('\u{05DC}', ReverseTreeNode::new(None, Some(Box::new(u05dc))));
-        let mut u05de = AHashMap::new();
-        u05de.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB3E}'), None));
-        root.insert('\u{05DE}', ReverseTreeNode::new(None, Some(Box::new(u05de))));
-        let mut u05e0 = AHashMap::new();
-        u05e0.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB40}'), None));
-        root.insert('\u{05E0}', ReverseTreeNode::new(None, Some(Box::new(u05e0))));
-        let mut u05e1 = AHashMap::new();
-        u05e1.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB41}'), None));
-        root.insert('\u{05E1}', ReverseTreeNode::new(None, Some(Box::new(u05e1))));
-        let mut u05e3 = AHashMap::new();
-        u05e3.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB43}'), None));
-        root.insert('\u{05E3}', ReverseTreeNode::new(None, Some(Box::new(u05e3))));
-        let mut u05e4 = AHashMap::new();
-        u05e4.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB44}'), None));
-        u05e4.insert('\u{05BF}', ReverseTreeNode::new(Some('\u{FB4E}'), None));
-        root.insert('\u{05E4}', ReverseTreeNode::new(None, Some(Box::new(u05e4))));
-        let mut u05e6 = AHashMap::new();
-        u05e6.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB46}'), None));
-        root.insert('\u{05E6}', ReverseTreeNode::new(None, Some(Box::new(u05e6))));
-        let mut u05e7 = AHashMap::new();
-        u05e7.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB47}'), None));
-        root.insert('\u{05E7}', ReverseTreeNode::new(None, Some(Box::new(u05e7))));
-        let mut u05e8 = AHashMap::new();
-        u05e8.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB48}'), None));
-        root.insert('\u{05E8}', ReverseTreeNode::new(None, Some(Box::new(u05e8))));
-        let mut u05ea = AHashMap::new();
-        u05ea.insert('\u{05BC}', ReverseTreeNode::new(Some('\u{FB4A}'), None));
-        root.insert('\u{05EA}', ReverseTreeNode::new(None, Some(Box::new(u05ea))));
-        return root;
-    };
-}
+use super::reverse_tree::{CharMap, ReverseTreeNode};
+
+/// map from composed character (normal) to decomposed components (HFS+)
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(MAP_TO_HFS.get(&'\u{00E9}').unwrap(), &"e\u{0301}");
+/// ```
+pub static MAP_TO_HFS: CharMap<&str> = CharMap {
+    entries: &[
+        ('\u{00C0}', "\u{0041}\u{0300}"),
+        ('\u{00C1}', "\u{0041}\u{0301}"),
+        ('\u{00C2}', "\u{0041}\u{0302}"),
+        ('\u{00C3}', "\u{0041}\u{0303}"),
+        ('\u{00C4}', "\u{0041}\u{0308}"),
+        ('\u{00C5}', "\u{0041}\u{030A}"),
+        ('\u{00C7}', "\u{0043}\u{0327}"),
+        ('\u{00C8}', "\u{0045}\u{0300}"),
+        ('\u{00C9}', "\u{0045}\u{0301}"),
+        ('\u{00CA}', "\u{0045}\u{0302}"),
+        ('\u{00CB}', "\u{0045}\u{0308}"),
+        ('\u{00CC}', "\u{0049}\u{0300}"),
+        ('\u{00CD}', "\u{0049}\u{0301}"),
+        ('\u{00CE}', "\u{0049}\u{0302}"),
+        ('\u{00CF}', "\u{0049}\u{0308}"),
+        ('\u{00D1}', "\u{004E}\u{0303}"),
+        ('\u{00D2}', "\u{004F}\u{0300}"),
+        ('\u{00D3}', "\u{004F}\u{0301}"),
+        ('\u{00D4}', "\u{004F}\u{0302}"),
+        ('\u{00D5}', "\u{004F}\u{0303}"),
+        ('\u{00D6}', "\u{004F}\u{0308}"),
+        ('\u{00D9}', "\u{0055}\u{0300}"),
+        ('\u{00DA}', "\u{0055}\u{0301}"),
+        ('\u{00DB}', "\u{0055}\u{0302}"),
+        ('\u{00DC}', "\u{0055}\u{0308}"),
+        ('\u{00DD}', "\u{0059}\u{0301}"),
+        ('\u{00E0}', "\u{0061}\u{0300}"),
+        ('\u{00E1}', "\u{0061}\u{0301}"),
+        ('\u{00E2}', "\u{0061}\u{0302}"),
+        ('\u{00E3}', "\u{0061}\u{0303}"),
+        ('\u{00E4}', "\u{0061}\u{0308}"),
+        ('\u{00E5}', "\u{0061}\u{030A}"),
+        ('\u{00E7}', "\u{0063}\u{0327}"),
+        ('\u{00E8}', "\u{0065}\u{0300}"),
+        ('\u{00E9}', "\u{0065}\u{0301}"),
+        ('\u{00EA}', "\u{0065}\u{0302}"),
+        ('\u{00EB}', "\u{0065}\u{0308}"),
+        ('\u{00EC}', "\u{0069}\u{0300}"),
+        ('\u{00ED}', "\u{0069}\u{0301}"),
+        ('\u{00EE}', "\u{0069}\u{0302}"),
+        ('\u{00EF}', "\u{0069}\u{0308}"),
+        ('\u{00F1}', "\u{006E}\u{0303}"),
+        ('\u{00F2}', "\u{006F}\u{0300}"),
+        ('\u{00F3}', "\u{006F}\u{0301}"),
+        ('\u{00F4}', "\u{006F}\u{0302}"),
+        ('\u{00F5}', "\u{006F}\u{0303}"),
+        ('\u{00F6}', "\u{006F}\u{0308}"),
+        ('\u{00F9}', "\u{0075}\u{0300}"),
+        ('\u{00FA}', "\u{0075}\u{0301}"),
+        ('\u{00FB}', "\u{0075}\u{0302}"),
+        ('\u{00FC}', "\u{0075}\u{0308}"),
+        ('\u{00FD}', "\u{0079}\u{0301}"),
+        ('\u{00FF}', "\u{0079}\u{0308}"),
+        ('\u{0100}', "\u{0041}\u{0304}"),
+        ('\u{0101}', "\u{0061}\u{0304}"),
+        ('\u{0102}', "\u{0041}\u{0306}"),
+        ('\u{0103}', "\u{0061}\u{0306}"),
+        ('\u{0104}', "\u{0041}\u{0328}"),
+        ('\u{0105}', "\u{0061}\u{0328}"),
+        ('\u{0106}', "\u{0043}\u{0301}"),
+        ('\u{0107}', "\u{0063}\u{0301}"),
+        ('\u{0108}', "\u{0043}\u{0302}"),
+        ('\u{0109}', "\u{0063}\u{0302}"),
+        ('\u{010A}', "\u{0043}\u{0307}"),
+        ('\u{010B}', "\u{0063}\u{0307}"),
+        ('\u{010C}', "\u{0043}\u{030C}"),
+        ('\u{010D}', "\u{0063}\u{030C}"),
+        ('\u{010E}', "\u{0044}\u{030C}"),
+        ('\u{010F}', "\u{0064}\u{030C}"),
+        ('\u{0112}', "\u{0045}\u{0304}"),
+        ('\u{0113}', "\u{0065}\u{0304}"),
+        ('\u{0114}', "\u{0045}\u{0306}"),
+        ('\u{0115}', "\u{0065}\u{0306}"),
+        ('\u{0116}', "\u{0045}\u{0307}"),
+        ('\u{0117}', "\u{0065}\u{0307}"),
+        ('\u{0118}', "\u{0045}\u{0328}"),
+        ('\u{0119}', "\u{0065}\u{0328}"),
+        ('\u{011A}', "\u{0045}\u{030C}"),
+        ('\u{011B}', "\u{0065}\u{030C}"),
+        ('\u{011C}', "\u{0047}\u{0302}"),
+        ('\u{011D}', "\u{0067}\u{0302}"),
+        ('\u{011E}', "\u{0047}\u{0306}"),
+        ('\u{011F}', "\u{0067}\u{0306}"),
+        ('\u{0120}', "\u{0047}\u{0307}"),
+        ('\u{0121}', "\u{0067}\u{0307}"),
+        ('\u{0122}', "\u{0047}\u{0327}"),
+        ('\u{0123}', "\u{0067}\u{0327}"),
+        ('\u{0124}', "\u{0048}\u{0302}"),
+        ('\u{0125}', "\u{0068}\u{0302}"),
+        ('\u{0128}', "\u{0049}\u{0303}"),
+        ('\u{0129}', "\u{0069}\u{0303}"),
+        ('\u{012A}', "\u{0049}\u{0304}"),
+        ('\u{012B}', "\u{0069}\u{0304}"),
+        ('\u{012C}', "\u{0049}\u{0306}"),
+        ('\u{012D}', "\u{0069}\u{0306}"),
+        ('\u{012E}', "\u{0049}\u{0328}"),
+        ('\u{012F}', "\u{0069}\u{0328}"),
+        ('\u{0130}', "\u{0049}\u{0307}"),
+        ('\u{0134}', "\u{004A}\u{0302}"),
+        ('\u{0135}', "\u{006A}\u{0302}"),
+        ('\u{0136}', "\u{004B}\u{0327}"),
+        ('\u{0137}', "\u{006B}\u{0327}"),
+        ('\u{0139}', "\u{004C}\u{0301}"),
+        ('\u{013A}', "\u{006C}\u{0301}"),
+        ('\u{013B}', "\u{004C}\u{0327}"),
+        ('\u{013C}', "\u{006C}\u{0327}"),
+        ('\u{013D}', "\u{004C}\u{030C}"),
+        ('\u{013E}', "\u{006C}\u{030C}"),
+        ('\u{0143}', "\u{004E}\u{0301}"),
+        ('\u{0144}', "\u{006E}\u{0301}"),
+        ('\u{0145}', "\u{004E}\u{0327}"),
+        ('\u{0146}', "\u{006E}\u{0327}"),
+        ('\u{0147}', "\u{004E}\u{030C}"),
+        ('\u{0148}', "\u{006E}\u{030C}"),
+        ('\u{014C}', "\u{004F}\u{0304}"),
+        ('\u{014D}', "\u{006F}\u{0304}"),
+        ('\u{014E}', "\u{004F}\u{0306}"),
+        ('\u{014F}', "\u{006F}\u{0306}"),
+        ('\u{0150}', "\u{004F}\u{030B}"),
+        ('\u{0151}', "\u{006F}\u{030B}"),
+        ('\u{0154}', "\u{0052}\u{0301}"),
+        ('\u{0155}', "\u{0072}\u{0301}"),
+        ('\u{0156}', "\u{0052}\u{0327}"),
+        ('\u{0157}', "\u{0072}\u{0327}"),
+        ('\u{0158}', "\u{0052}\u{030C}"),
+        ('\u{0159}', "\u{0072}\u{030C}"),
+        ('\u{015A}', "\u{0053}\u{0301}"),
+        ('\u{015B}', "\u{0073}\u{0301}"),
+        ('\u{015C}', "\u{0053}\u{0302}"),
+        ('\u{015D}', "\u{0073}\u{0302}"),
+        ('\u{015E}', "\u{0053}\u{0327}"),
+        ('\u{015F}', "\u{0073}\u{0327}"),
+        ('\u{0160}', "\u{0053}\u{030C}"),
+        ('\u{0161}', "\u{0073}\u{030C}"),
+        ('\u{0162}', "\u{0054}\u{0327}"),
+        ('\u{0163}', "\u{0074}\u{0327}"),
+        ('\u{0164}', "\u{0054}\u{030C}"),
+        ('\u{0165}', "\u{0074}\u{030C}"),
+        ('\u{0168}', "\u{0055}\u{0303}"),
+        ('\u{0169}', "\u{0075}\u{0303}"),
+        ('\u{016A}', "\u{0055}\u{0304}"),
+        ('\u{016B}', "\u{0075}\u{0304}"),
+        ('\u{016C}', "\u{0055}\u{0306}"),
+        ('\u{016D}', "\u{0075}\u{0306}"),
+        ('\u{016E}', "\u{0055}\u{030A}"),
+        ('\u{016F}', "\u{0075}\u{030A}"),
+        ('\u{0170}', "\u{0055}\u{030B}"),
+        ('\u{0171}', "\u{0075}\u{030B}"),
+        ('\u{0172}', "\u{0055}\u{0328}"),
+        ('\u{0173}', "\u{0075}\u{0328}"),
+        ('\u{0174}', "\u{0057}\u{0302}"),
+        ('\u{0175}', "\u{0077}\u{0302}"),
+        ('\u{0176}', "\u{0059}\u{0302}"),
+        ('\u{0177}', "\u{0079}\u{0302}"),
+        ('\u{0178}', "\u{0059}\u{0308}"),
+        ('\u{0179}', "\u{005A}\u{0301}"),
+        ('\u{017A}', "\u{007A}\u{0301}"),
+        ('\u{017B}', "\u{005A}\u{0307}"),
+        ('\u{017C}', "\u{007A}\u{0307}"),
+        ('\u{017D}', "\u{005A}\u{030C}"),
+        ('\u{017E}', "\u{007A}\u{030C}"),
+        ('\u{01A0}', "\u{004F}\u{031B}"),
+        ('\u{01A1}', "\u{006F}\u{031B}"),
+        ('\u{01AF}', "\u{0055}\u{031B}"),
+        ('\u{01B0}', "\u{0075}\u{031B}"),
+        ('\u{01CD}', "\u{0041}\u{030C}"),
+        ('\u{01CE}', "\u{0061}\u{030C}"),
+        ('\u{01CF}', "\u{0049}\u{030C}"),
+        ('\u{01D0}', "\u{0069}\u{030C}"),
+        ('\u{01D1}', "\u{004F}\u{030C}"),
+        ('\u{01D2}', "\u{006F}\u{030C}"),
+        ('\u{01D3}', "\u{0055}\u{030C}"),
+        ('\u{01D4}', "\u{0075}\u{030C}"),
+        ('\u{01D5}', "\u{0055}\u{0308}\u{0304}"),
+        ('\u{01D6}', "\u{0075}\u{0308}\u{0304}"),
+        ('\u{01D7}', "\u{0055}\u{0308}\u{0301}"),
+        ('\u{01D8}', "\u{0075}\u{0308}\u{0301}"),
+        ('\u{01D9}', "\u{0055}\u{0308}\u{030C}"),
+        ('\u{01DA}', "\u{0075}\u{0308}\u{030C}"),
+        ('\u{01DB}', "\u{0055}\u{0308}\u{0300}"),
+        ('\u{01DC}', "\u{0075}\u{0308}\u{0300}"),
+        ('\u{01DE}', "\u{0041}\u{0308}\u{0304}"),
+        ('\u{01DF}', "\u{0061}\u{0308}\u{0304}"),
+        ('\u{01E0}', "\u{0041}\u{0307}\u{0304}"),
+        ('\u{01E1}', "\u{0061}\u{0307}\u{0304}"),
+        ('\u{01E2}', "\u{00C6}\u{0304}"),
+        ('\u{01E3}', "\u{00E6}\u{0304}"),
+        ('\u{01E6}', "\u{0047}\u{030C}"),
+        ('\u{01E7}', "\u{0067}\u{030C}"),
+        ('\u{01E8}', "\u{004B}\u{030C}"),
+        ('\u{01E9}', "\u{006B}\u{030C}"),
+        ('\u{01EA}', "\u{004F}\u{0328}"),
+        ('\u{01EB}', "\u{006F}\u{0328}"),
+        ('\u{01EC}', "\u{004F}\u{0328}\u{0304}"),
+        ('\u{01ED}', "\u{006F}\u{0328}\u{0304}"),
+        ('\u{01EE}', "\u{01B7}\u{030C}"),
+        ('\u{01EF}', "\u{0292}\u{030C}"),
+        ('\u{01F0}', "\u{006A}\u{030C}"),
+        ('\u{01F4}', "\u{0047}\u{0301}"),
+        ('\u{01F5}', "\u{0067}\u{0301}"),
+        ('\u{01FA}', "\u{0041}\u{030A}\u{0301}"),
+        ('\u{01FB}', "\u{0061}\u{030A}\u{0301}"),
+        ('\u{01FC}', "\u{00C6}\u{0301}"),
+        ('\u{01FD}', "\u{00E6}\u{0301}"),
+        ('\u{01FE}', "\u{00D8}\u{0301}"),
+        ('\u{01FF}', "\u{00F8}\u{0301}"),
+        ('\u{0200}', "\u{0041}\u{030F}"),
+        ('\u{0201}', "\u{0061}\u{030F}"),
+        ('\u{0202}', "\u{0041}\u{0311}"),
+        ('\u{0203}', "\u{0061}\u{0311}"),
+        ('\u{0204}', "\u{0045}\u{030F}"),
+        ('\u{0205}', "\u{0065}\u{030F}"),
+        ('\u{0206}', "\u{0045}\u{0311}"),
+        ('\u{0207}', "\u{0065}\u{0311}"),
+        ('\u{0208}', "\u{0049}\u{030F}"),
+        ('\u{0209}', "\u{0069}\u{030F}"),
+        ('\u{020A}', "\u{0049}\u{0311}"),
+        ('\u{020B}', "\u{0069}\u{0311}"),
+        ('\u{020C}', "\u{004F}\u{030F}"),
+        ('\u{020D}', "\u{006F}\u{030F}"),
+        ('\u{020E}', "\u{004F}\u{0311}"),
+        ('\u{020F}', "\u{006F}\u{0311}"),
+        ('\u{0210}', "\u{0052}\u{030F}"),
+        ('\u{0211}', "\u{0072}\u{030F}"),
+        ('\u{0212}', "\u{0052}\u{0311}"),
+        ('\u{0213}', "\u{0072}\u{0311}"),
+        ('\u{0214}', "\u{0055}\u{030F}"),
+        ('\u{0215}', "\u{0075}\u{030F}"),
+        ('\u{0216}', "\u{0055}\u{0311}"),
+        ('\u{0217}', "\u{0075}\u{0311}"),
+        ('\u{0310}', "\u{0306}\u{0307}"),
+        ('\u{0344}', "\u{0308}\u{030D}"),
+        ('\u{0385}', "\u{00A8}\u{030D}"),
+        ('\u{0386}', "\u{0391}\u{030D}"),
+        ('\u{0388}', "\u{0395}\u{030D}"),
+        ('\u{0389}', "\u{0397}\u{030D}"),
+        ('\u{038A}', "\u{0399}\u{030D}"),
+        ('\u{038C}', "\u{039F}\u{030D}"),
+        ('\u{038E}', "\u{03A5}\u{030D}"),
+        ('\u{038F}', "\u{03A9}\u{030D}"),
+        ('\u{0390}', "\u{03B9}\u{0308}\u{030D}"),
+        ('\u{03AA}', "\u{0399}\u{0308}"),
+        ('\u{03AB}', "\u{03A5}\u{0308}"),
+        ('\u{03AC}', "\u{03B1}\u{030D}"),
+        ('\u{03AD}', "\u{03B5}\u{030D}"),
+        ('\u{03AE}', "\u{03B7}\u{030D}"),
+        ('\u{03AF}', "\u{03B9}\u{030D}"),
+        ('\u{03B0}', "\u{03C5}\u{0308}\u{030D}"),
+        ('\u{03CA}', "\u{03B9}\u{0308}"),
+        ('\u{03CB}', "\u{03C5}\u{0308}"),
+        ('\u{03CC}', "\u{03BF}\u{030D}"),
+        ('\u{03CD}', "\u{03C5}\u{030D}"),
+        ('\u{03CE}', "\u{03C9}\u{030D}"),
+        ('\u{03D3}', "\u{03D2}\u{030D}"),
+        ('\u{03D4}', "\u{03D2}\u{0308}"),
+        ('\u{0401}', "\u{0415}\u{0308}"),
+        ('\u{0403}', "\u{0413}\u{0301}"),
+        ('\u{0407}', "\u{0406}\u{0308}"),
+        ('\u{040C}', "\u{041A}\u{0301}"),
+        ('\u{040E}', "\u{0423}\u{0306}"),
+        ('\u{0419}', "\u{0418}\u{0306}"),
+        ('\u{0439}', "\u{0438}\u{0306}"),
+        ('\u{0451}', "\u{0435}\u{0308}"),
+        ('\u{0453}', "\u{0433}\u{0301}"),
+        ('\u{0457}', "\u{0456}\u{0308}"),
+        ('\u{045C}', "\u{043A}\u{0301}"),
+        ('\u{045E}', "\u{0443}\u{0306}"),
+        ('\u{0476}', "\u{0474}\u{030F}"),
+        ('\u{0477}', "\u{0475}\u{030F}"),
+        ('\u{04C1}', "\u{0416}\u{0306}"),
+        ('\u{04C2}', "\u{0436}\u{0306}"),
+        ('\u{04D0}', "\u{0410}\u{0306}"),
+        ('\u{04D1}', "\u{0430}\u{0306}"),
+        ('\u{04D2}', "\u{0410}\u{0308}"),
+        ('\u{04D3}', "\u{0430}\u{0308}"),
+        ('\u{04D6}', "\u{0415}\u{0306}"),
+        ('\u{04D7}', "\u{0435}\u{0306}"),
+        ('\u{04DA}', "\u{018F}\u{0308}"),
+        ('\u{04DB}', "\u{0259}\u{0308}"),
+        ('\u{04DC}', "\u{0416}\u{0308}"),
+        ('\u{04DD}', "\u{0436}\u{0308}"),
+        ('\u{04DE}', "\u{0417}\u{0308}"),
+        ('\u{04DF}', "\u{0437}\u{0308}"),
+        ('\u{04E2}', "\u{0418}\u{0304}"),
+        ('\u{04E3}', "\u{0438}\u{0304}"),
+        ('\u{04E4}', "\u{0418}\u{0308}"),
+        ('\u{04E5}', "\u{0438}\u{0308}"),
+        ('\u{04E6}', "\u{041E}\u{0308}"),
+        ('\u{04E7}', "\u{043E}\u{0308}"),
+        ('\u{04EA}', "\u{019F}\u{0308}"),
+        ('\u{04EB}', "\u{0275}\u{0308}"),
+        ('\u{04EE}', "\u{0423}\u{0304}"),
+        ('\u{04EF}', "\u{0443}\u{0304}"),
+        ('\u{04F0}', "\u{0423}\u{0308}"),
+        ('\u{04F1}', "\u{0443}\u{0308}"),
+        ('\u{04F2}', "\u{0423}\u{030B}"),
+        ('\u{04F3}', "\u{0443}\u{030B}"),
+        ('\u{04F4}', "\u{0427}\u{0308}"),
+        ('\u{04F5}', "\u{0447}\u{0308}"),
+        ('\u{04F8}', "\u{042B}\u{0308}"),
+        ('\u{04F9}', "\u{044B}\u{0308}"),
+        ('\u{0929}', "\u{0928}\u{093C}"),
+        ('\u{0931}', "\u{0930}\u{093C}"),
+        ('\u{0934}', "\u{0933}\u{093C}"),
+        ('\u{0958}', "\u{0915}\u{093C}"),
+        ('\u{0959}', "\u{0916}\u{093C}"),
+        ('\u{095A}', "\u{0917}\u{093C}"),
+        ('\u{095B}', "\u{091C}\u{093C}"),
+        ('\u{095C}', "\u{0921}\u{093C}"),
+        ('\u{095D}', "\u{0922}\u{093C}"),
+        ('\u{095E}', "\u{092B}\u{093C}"),
+        ('\u{095F}', "\u{092F}\u{093C}"),
+        ('\u{09B0}', "\u{09AC}\u{09BC}"),
+        ('\u{09CB}', "\u{09C7}\u{09BE}"),
+        ('\u{09CC}', "\u{09C7}\u{09D7}"),
+        ('\u{09DC}', "\u{09A1}\u{09BC}"),
+        ('\u{09DD}', "\u{09A2}\u{09BC}"),
+        ('\u{09DF}', "\u{09AF}\u{09BC}"),
+        ('\u{0A59}', "\u{0A16}\u{0A3C}"),
+        ('\u{0A5A}', "\u{0A17}\u{0A3C}"),
+        ('\u{0A5B}', "\u{0A1C}\u{0A3C}"),
+        ('\u{0A5C}', "\u{0A21}\u{0A3C}"),
+        ('\u{0A5E}', "\u{0A2B}\u{0A3C}"),
+        ('\u{0B48}', "\u{0B47}\u{0B56}"),
+        ('\u{0B4B}', "\u{0B47}\u{0B3E}"),
+        ('\u{0B4C}', "\u{0B47}\u{0B57}"),
+        ('\u{0B5C}', "\u{0B21}\u{0B3C}"),
+        ('\u{0B5D}', "\u{0B22}\u{0B3C}"),
+        ('\u{0B5F}', "\u{0B2F}\u{0B3C}"),
+        ('\u{0B94}', "\u{0B92}\u{0BD7}"),
+        ('\u{0BCA}', "\u{0BC6}\u{0BBE}"),
+        ('\u{0BCB}', "\u{0BC7}\u{0BBE}"),
+        ('\u{0BCC}', "\u{0BC6}\u{0BD7}"),
+        ('\u{0C48}', "\u{0C46}\u{0C56}"),
+        ('\u{0CC0}', "\u{0CBF}\u{0CD5}"),
+        ('\u{0CC7}', "\u{0CC6}\u{0CD5}"),
+        ('\u{0CC8}', "\u{0CC6}\u{0CD6}"),
+        ('\u{0CCA}', "\u{0CC6}\u{0CC2}"),
+        ('\u{0CCB}', "\u{0CC6}\u{0CC2}\u{0CD5}"),
+        ('\u{0D4A}', "\u{0D46}\u{0D3E}"),
+        ('\u{0D4B}', "\u{0D47}\u{0D3E}"),
+        ('\u{0D4C}', "\u{0D46}\u{0D57}"),
+        ('\u{0E33}', "\u{0E4D}\u{0E32}"),
+        ('\u{0EB3}', "\u{0ECD}\u{0EB2}"),
+        ('\u{0F43}', "\u{0F42}\u{0FB7}"),
+        ('\u{0F4D}', "\u{0F4C}\u{0FB7}"),
+        ('\u{0F52}', "\u{0F51}\u{0FB7}"),
+        ('\u{0F57}', "\u{0F56}\u{0FB7}"),
+        ('\u{0F5C}', "\u{0F5B}\u{0FB7}"),
+        ('\u{0F69}', "\u{0F40}\u{0FB5}"),
+        ('\u{0F73}', "\u{0F72}\u{0F71}"),
+        ('\u{0F75}', "\u{0F74}\u{0F71}"),
+        ('\u{0F76}', "\u{0FB2}\u{0F80}"),
+        ('\u{0F77}', "\u{0FB2}\u{0F80}\u{0F71}"),
+        ('\u{0F78}', "\u{0FB3}\u{0F80}"),
+        ('\u{0F79}', "\u{0FB3}\u{0F80}\u{0F71}"),
+        ('\u{0F81}', "\u{0F80}\u{0F71}"),
+        ('\u{0F93}', "\u{0F92}\u{0FB7}"),
+        ('\u{0F9D}', "\u{0F9C}\u{0FB7}"),
+        ('\u{0FA2}', "\u{0FA1}\u{0FB7}"),
+        ('\u{0FA7}', "\u{0FA6}\u{0FB7}"),
+        ('\u{0FAC}', "\u{0FAB}\u{0FB7}"),
+        ('\u{0FB9}', "\u{0F90}\u{0FB5}"),
+        ('\u{1E00}', "\u{0041}\u{0325}"),
+        ('\u{1E01}', "\u{0061}\u{0325}"),
+        ('\u{1E02}', "\u{0042}\u{0307}"),
+        ('\u{1E03}', "\u{0062}\u{0307}"),
+        ('\u{1E04}', "\u{0042}\u{0323}"),
+        ('\u{1E05}', "\u{0062}\u{0323}"),
+        ('\u{1E06}', "\u{0042}\u{0331}"),
+        ('\u{1E07}', "\u{0062}\u{0331}"),
+        ('\u{1E08}', "\u{0043}\u{0327}\u{0301}"),
+        ('\u{1E09}', "\u{0063}\u{0327}\u{0301}"),
+        ('\u{1E0A}', "\u{0044}\u{0307}"),
+        ('\u{1E0B}', "\u{0064}\u{0307}"),
+        ('\u{1E0C}', "\u{0044}\u{0323}"),
+        ('\u{1E0D}', "\u{0064}\u{0323}"),
+        ('\u{1E0E}', "\u{0044}\u{0331}"),
+        ('\u{1E0F}', "\u{0064}\u{0331}"),
+        ('\u{1E10}', "\u{0044}\u{0327}"),
+        ('\u{1E11}', "\u{0064}\u{0327}"),
+        ('\u{1E12}', "\u{0044}\u{032D}"),
+        ('\u{1E13}', "\u{0064}\u{032D}"),
+        ('\u{1E14}', "\u{0045}\u{0304}\u{0300}"),
+        ('\u{1E15}', "\u{0065}\u{0304}\u{0300}"),
+        ('\u{1E16}', "\u{0045}\u{0304}\u{0301}"),
+        ('\u{1E17}', "\u{0065}\u{0304}\u{0301}"),
+        ('\u{1E18}', "\u{0045}\u{032D}"),
+        ('\u{1E19}', "\u{0065}\u{032D}"),
+        ('\u{1E1A}', "\u{0045}\u{0330}"),
+        ('\u{1E1B}', "\u{0065}\u{0330}"),
+        ('\u{1E1C}', "\u{0045}\u{0327}\u{0306}"),
+        ('\u{1E1D}', "\u{0065}\u{0327}\u{0306}"),
+        ('\u{1E1E}', "\u{0046}\u{0307}"),
+        ('\u{1E1F}', "\u{0066}\u{0307}"),
+        ('\u{1E20}', "\u{0047}\u{0304}"),
+        ('\u{1E21}', "\u{0067}\u{0304}"),
+        ('\u{1E22}', "\u{0048}\u{0307}"),
+        ('\u{1E23}', "\u{0068}\u{0307}"),
+        ('\u{1E24}', "\u{0048}\u{0323}"),
+        ('\u{1E25}', "\u{0068}\u{0323}"),
+        ('\u{1E26}', "\u{0048}\u{0308}"),
+        ('\u{1E27}', "\u{0068}\u{0308}"),
+        ('\u{1E28}', "\u{0048}\u{0327}"),
+        ('\u{1E29}', "\u{0068}\u{0327}"),
+        ('\u{1E2A}', "\u{0048}\u{032E}"),
+        ('\u{1E2B}', "\u{0068}\u{032E}"),
+        ('\u{1E2C}', "\u{0049}\u{0330}"),
+        ('\u{1E2D}', "\u{0069}\u{0330}"),
+        ('\u{1E2E}', "\u{0049}\u{0308}\u{0301}"),
+        ('\u{1E2F}', "\u{0069}\u{0308}\u{0301}"),
+        ('\u{1E30}', "\u{004B}\u{0301}"),
+        ('\u{1E31}', "\u{006B}\u{0301}"),
+        ('\u{1E32}', "\u{004B}\u{0323}"),
+        ('\u{1E33}', "\u{006B}\u{0323}"),
+        ('\u{1E34}', "\u{004B}\u{0331}"),
+        ('\u{1E35}', "\u{006B}\u{0331}"),
+        ('\u{1E36}', "\u{004C}\u{0323}"),
+        ('\u{1E37}', "\u{006C}\u{0323}"),
+        ('\u{1E38}', "\u{004C}\u{0323}\u{0304}"),
+        ('\u{1E39}', "\u{006C}\u{0323}\u{0304}"),
+        ('\u{1E3A}', "\u{004C}\u{0331}"),
+        ('\u{1E3B}', "\u{006C}\u{0331}"),
+        ('\u{1E3C}', "\u{004C}\u{032D}"),
+        ('\u{1E3D}', "\u{006C}\u{032D}"),
+        ('\u{1E3E}', "\u{004D}\u{0301}"),
+        ('\u{1E3F}', "\u{006D}\u{0301}"),
+        ('\u{1E40}', "\u{004D}\u{0307}"),
+        ('\u{1E41}', "\u{006D}\u{0307}"),
+        ('\u{1E42}', "\u{004D}\u{0323}"),
+        ('\u{1E43}', "\u{006D}\u{0323}"),
+        ('\u{1E44}', "\u{004E}\u{0307}"),
+        ('\u{1E45}', "\u{006E}\u{0307}"),
+        ('\u{1E46}', "\u{004E}\u{0323}"),
+        ('\u{1E47}', "\u{006E}\u{0323}"),
+        ('\u{1E48}', "\u{004E}\u{0331}"),
+        ('\u{1E49}', "\u{006E}\u{0331}"),
+        ('\u{1E4A}', "\u{004E}\u{032D}"),
+        ('\u{1E4B}', "\u{006E}\u{032D}"),
+        ('\u{1E4C}', "\u{004F}\u{0303}\u{0301}"),
+        ('\u{1E4D}', "\u{006F}\u{0303}\u{0301}"),
+        ('\u{1E4E}', "\u{004F}\u{0303}\u{0308}"),
+        ('\u{1E4F}', "\u{006F}\u{0303}\u{0308}"),
+        ('\u{1E50}', "\u{004F}\u{0304}\u{0300}"),
+        ('\u{1E51}', "\u{006F}\u{0304}\u{0300}"),
+        ('\u{1E52}', "\u{004F}\u{0304}\u{0301}"),
+        ('\u{1E53}', "\u{006F}\u{0304}\u{0301}"),
+        ('\u{1E54}', "\u{0050}\u{0301}"),
+        ('\u{1E55}', "\u{0070}\u{0301}"),
+        ('\u{1E56}', "\u{0050}\u{0307}"),
+        ('\u{1E57}', "\u{0070}\u{0307}"),
+        ('\u{1E58}', "\u{0052}\u{0307}"),
+        ('\u{1E59}', "\u{0072}\u{0307}"),
+        ('\u{1E5A}', "\u{0052}\u{0323}"),
+        ('\u{1E5B}', "\u{0072}\u{0323}"),
+        ('\u{1E5C}', "\u{0052}\u{0323}\u{0304}"),
+        ('\u{1E5D}', "\u{0072}\u{0323}\u{0304}"),
+        ('\u{1E5E}', "\u{0052}\u{0331}"),
+        ('\u{1E5F}', "\u{0072}\u{0331}"),
+        ('\u{1E60}', "\u{0053}\u{0307}"),
+        ('\u{1E61}', "\u{0073}\u{0307}"),
+        ('\u{1E62}', "\u{0053}\u{0323}"),
+        ('\u{1E63}', "\u{0073}\u{0323}"),
+        ('\u{1E64}', "\u{0053}\u{0301}\u{0307}"),
+        ('\u{1E65}', "\u{0073}\u{0301}\u{0307}"),
+        ('\u{1E66}', "\u{0053}\u{030C}\u{0307}"),
+        ('\u{1E67}', "\u{0073}\u{030C}\u{0307}"),
+        ('\u{1E68}', "\u{0053}\u{0323}\u{0307}"),
+        ('\u{1E69}', "\u{0073}\u{0323}\u{0307}"),
+        ('\u{1E6A}', "\u{0054}\u{0307}"),
+        ('\u{1E6B}', "\u{0074}\u{0307}"),
+        ('\u{1E6C}', "\u{0054}\u{0323}"),
+        ('\u{1E6D}', "\u{0074}\u{0323}"),
+        ('\u{1E6E}', "\u{0054}\u{0331}"),
+        ('\u{1E6F}', "\u{0074}\u{0331}"),
+        ('\u{1E70}', "\u{0054}\u{032D}"),
+        ('\u{1E71}', "\u{0074}\u{032D}"),
+        ('\u{1E72}', "\u{0055}\u{0324}"),
+        ('\u{1E73}', "\u{0075}\u{0324}"),
+        ('\u{1E74}', "\u{0055}\u{0330}"),
+        ('\u{1E75}', "\u{0075}\u{0330}"),
+        ('\u{1E76}', "\u{0055}\u{032D}"),
+        ('\u{1E77}', "\u{0075}\u{032D}"),
+        ('\u{1E78}', "\u{0055}\u{0303}\u{0301}"),
+        ('\u{1E79}', "\u{0075}\u{0303}\u{0301}"),
+        ('\u{1E7A}', "\u{0055}\u{0304}\u{0308}"),
+        ('\u{1E7B}', "\u{0075}\u{0304}\u{0308}"),
+        ('\u{1E7C}', "\u{0056}\u{0303}"),
+        ('\u{1E7D}', "\u{0076}\u{0303}"),
+        ('\u{1E7E}', "\u{0056}\u{0323}"),
+        ('\u{1E7F}', "\u{0076}\u{0323}"),
+        ('\u{1E80}', "\u{0057}\u{0300}"),
+        ('\u{1E81}', "\u{0077}\u{0300}"),
+        ('\u{1E82}', "\u{0057}\u{0301}"),
+        ('\u{1E83}', "\u{0077}\u{0301}"),
+        ('\u{1E84}', "\u{0057}\u{0308}"),
+        ('\u{1E85}', "\u{0077}\u{0308}"),
+        ('\u{1E86}', "\u{0057}\u{0307}"),
+        ('\u{1E87}', "\u{0077}\u{0307}"),
+        ('\u{1E88}', "\u{0057}\u{0323}"),
+        ('\u{1E89}', "\u{0077}\u{0323}"),
+        ('\u{1E8A}', "\u{0058}\u{0307}"),
+        ('\u{1E8B}', "\u{0078}\u{0307}"),
+        ('\u{1E8C}', "\u{0058}\u{0308}"),
+        ('\u{1E8D}', "\u{0078}\u{0308}"),
+        ('\u{1E8E}', "\u{0059}\u{0307}"),
+        ('\u{1E8F}', "\u{0079}\u{0307}"),
+        ('\u{1E90}', "\u{005A}\u{0302}"),
+        ('\u{1E91}', "\u{007A}\u{0302}"),
+        ('\u{1E92}', "\u{005A}\u{0323}"),
+        ('\u{1E93}', "\u{007A}\u{0323}"),
+        ('\u{1E94}', "\u{005A}\u{0331}"),
+        ('\u{1E95}', "\u{007A}\u{0331}"),
+        ('\u{1E96}', "\u{0068}\u{0331}"),
+        ('\u{1E97}', "\u{0074}\u{0308}"),
+        ('\u{1E98}', "\u{0077}\u{030A}"),
+        ('\u{1E99}', "\u{0079}\u{030A}"),
+        ('\u{1E9B}', "\u{017F}\u{0307}"),
+        ('\u{1EA0}', "\u{0041}\u{0323}"),
+        ('\u{1EA1}', "\u{0061}\u{0323}"),
+        ('\u{1EA2}', "\u{0041}\u{0309}"),
+        ('\u{1EA3}', "\u{0061}\u{0309}"),
+        ('\u{1EA4}', "\u{0041}\u{0302}\u{0301}"),
+        ('\u{1EA5}', "\u{0061}\u{0302}\u{0301}"),
+        ('\u{1EA6}', "\u{0041}\u{0302}\u{0300}"),
+        ('\u{1EA7}', "\u{0061}\u{0302}\u{0300}"),
+        ('\u{1EA8}', "\u{0041}\u{0302}\u{0309}"),
+        ('\u{1EA9}', "\u{0061}\u{0302}\u{0309}"),
+        ('\u{1EAA}', "\u{0041}\u{0302}\u{0303}"),
+        ('\u{1EAB}', "\u{0061}\u{0302}\u{0303}"),
+        ('\u{1EAC}', "\u{0041}\u{0323}\u{0302}"),
+        ('\u{1EAD}', "\u{0061}\u{0323}\u{0302}"),
+        ('\u{1EAE}', "\u{0041}\u{0306}\u{0301}"),
+        ('\u{1EAF}', "\u{0061}\u{0306}\u{0301}"),
+        ('\u{1EB0}', "\u{0041}\u{0306}\u{0300}"),
+        ('\u{1EB1}', "\u{0061}\u{0306}\u{0300}"),
+        ('\u{1EB2}', "\u{0041}\u{0306}\u{0309}"),
+        ('\u{1EB3}', "\u{0061}\u{0306}\u{0309}"),
+        ('\u{1EB4}', "\u{0041}\u{0306}\u{0303}"),
+        ('\u{1EB5}', "\u{0061}\u{0306}\u{0303}"),
+        ('\u{1EB6}', "\u{0041}\u{0323}\u{0306}"),
+        ('\u{1EB7}', "\u{0061}\u{0323}\u{0306}"),
+        ('\u{1EB8}', "\u{0045}\u{0323}"),
+        ('\u{1EB9}', "\u{0065}\u{0323}"),
+        ('\u{1EBA}', "\u{0045}\u{0309}"),
+        ('\u{1EBB}', "\u{0065}\u{0309}"),
+        ('\u{1EBC}', "\u{0045}\u{0303}"),
+        ('\u{1EBD}', "\u{0065}\u{0303}"),
+        ('\u{1EBE}', "\u{0045}\u{0302}\u{0301}"),
+        ('\u{1EBF}', "\u{0065}\u{0302}\u{0301}"),
+        ('\u{1EC0}', "\u{0045}\u{0302}\u{0300}"),
+        ('\u{1EC1}', "\u{0065}\u{0302}\u{0300}"),
+        ('\u{1EC2}', "\u{0045}\u{0302}\u{0309}"),
+        ('\u{1EC3}', "\u{0065}\u{0302}\u{0309}"),
+        ('\u{1EC4}', "\u{0045}\u{0302}\u{0303}"),
+        ('\u{1EC5}', "\u{0065}\u{0302}\u{0303}"),
+        ('\u{1EC6}', "\u{0045}\u{0323}\u{0302}"),
+        ('\u{1EC7}', "\u{0065}\u{0323}\u{0302}"),
+        ('\u{1EC8}', "\u{0049}\u{0309}"),
+        ('\u{1EC9}', "\u{0069}\u{0309}"),
+        ('\u{1ECA}', "\u{0049}\u{0323}"),
+        ('\u{1ECB}', "\u{0069}\u{0323}"),
+        ('\u{1ECC}', "\u{004F}\u{0323}"),
+        ('\u{1ECD}', "\u{006F}\u{0323}"),
+        ('\u{1ECE}', "\u{004F}\u{0309}"),
+        ('\u{1ECF}', "\u{006F}\u{0309}"),
+        ('\u{1ED0}', "\u{004F}\u{0302}\u{0301}"),
+        ('\u{1ED1}', "\u{006F}\u{0302}\u{0301}"),
+        ('\u{1ED2}', "\u{004F}\u{0302}\u{0300}"),
+        ('\u{1ED3}', "\u{006F}\u{0302}\u{0300}"),
+        ('\u{1ED4}', "\u{004F}\u{0302}\u{0309}"),
+        ('\u{1ED5}', "\u{006F}\u{0302}\u{0309}"),
+        ('\u{1ED6}', "\u{004F}\u{0302}\u{0303}"),
+        ('\u{1ED7}', "\u{006F}\u{0302}\u{0303}"),
+        ('\u{1ED8}', "\u{004F}\u{0323}\u{0302}"),
+        ('\u{1ED9}', "\u{006F}\u{0323}\u{0302}"),
+        ('\u{1EDA}', "\u{004F}\u{031B}\u{0301}"),
+        ('\u{1EDB}', "\u{006F}\u{031B}\u{0301}"),
+        ('\u{1EDC}', "\u{004F}\u{031B}\u{0300}"),
+        ('\u{1EDD}', "\u{006F}\u{031B}\u{0300}"),
+        ('\u{1EDE}', "\u{004F}\u{031B}\u{0309}"),
+        ('\u{1EDF}', "\u{006F}\u{031B}\u{0309}"),
+        ('\u{1EE0}', "\u{004F}\u{031B}\u{0303}"),
+        ('\u{1EE1}', "\u{006F}\u{031B}\u{0303}"),
+        ('\u{1EE2}', "\u{004F}\u{031B}\u{0323}"),
+        ('\u{1EE3}', "\u{006F}\u{031B}\u{0323}"),
+        ('\u{1EE4}', "\u{0055}\u{0323}"),
+        ('\u{1EE5}', "\u{0075}\u{0323}"),
+        ('\u{1EE6}', "\u{0055}\u{0309}"),
+        ('\u{1EE7}', "\u{0075}\u{0309}"),
+        ('\u{1EE8}', "\u{0055}\u{031B}\u{0301}"),
+        ('\u{1EE9}', "\u{0075}\u{031B}\u{0301}"),
+        ('\u{1EEA}', "\u{0055}\u{031B}\u{0300}"),
+        ('\u{1EEB}', "\u{0075}\u{031B}\u{0300}"),
+        ('\u{1EEC}', "\u{0055}\u{031B}\u{0309}"),
+        ('\u{1EED}', "\u{0075}\u{031B}\u{0309}"),
+        ('\u{1EEE}', "\u{0055}\u{031B}\u{0303}"),
+        ('\u{1EEF}', "\u{0075}\u{031B}\u{0303}"),
+        ('\u{1EF0}', "\u{0055}\u{031B}\u{0323}"),
+        ('\u{1EF1}', "\u{0075}\u{031B}\u{0323}"),
+        ('\u{1EF2}', "\u{0059}\u{0300}"),
+        ('\u{1EF3}', "\u{0079}\u{0300}"),
+        ('\u{1EF4}', "\u{0059}\u{0323}"),
+        ('\u{1EF5}', "\u{0079}\u{0323}"),
+        ('\u{1EF6}', "\u{0059}\u{0309}"),
+        ('\u{1EF7}', "\u{0079}\u{0309}"),
+        ('\u{1EF8}', "\u{0059}\u{0303}"),
+        ('\u{1EF9}', "\u{0079}\u{0303}"),
+        ('\u{1F00}', "\u{03B1}\u{0313}"),
+        ('\u{1F01}', "\u{03B1}\u{0314}"),
+        ('\u{1F02}', "\u{03B1}\u{0313}\u{0300}"),
+        ('\u{1F03}', "\u{03B1}\u{0314}\u{0300}"),
+        ('\u{1F04}', "\u{03B1}\u{0313}\u{0301}"),
+        ('\u{1F05}', "\u{03B1}\u{0314}\u{0301}"),
+        ('\u{1F06}', "\u{03B1}\u{0313}\u{0342}"),
+        ('\u{1F07}', "\u{03B1}\u{0314}\u{0342}"),
+        ('\u{1F08}', "\u{0391}\u{0313}"),
+        ('\u{1F09}', "\u{0391}\u{0314}"),
+        ('\u{1F0A}', "\u{0391}\u{0313}\u{0300}"),
+        ('\u{1F0B}', "\u{0391}\u{0314}\u{0300}"),
+        ('\u{1F0C}', "\u{0391}\u{0313}\u{0301}"),
+        ('\u{1F0D}', "\u{0391}\u{0314}\u{0301}"),
+        ('\u{1F0E}', "\u{0391}\u{0313}\u{0342}"),
+        ('\u{1F0F}', "\u{0391}\u{0314}\u{0342}"),
+        ('\u{1F10}', "\u{03B5}\u{0313}"),
+        ('\u{1F11}', "\u{03B5}\u{0314}"),
+        ('\u{1F12}', "\u{03B5}\u{0313}\u{0300}"),
+        ('\u{1F13}', "\u{03B5}\u{0314}\u{0300}"),
+        ('\u{1F14}', "\u{03B5}\u{0313}\u{0301}"),
+        ('\u{1F15}', "\u{03B5}\u{0314}\u{0301}"),
+        ('\u{1F18}', "\u{0395}\u{0313}"),
+        ('\u{1F19}', "\u{0395}\u{0314}"),
+        ('\u{1F1A}', "\u{0395}\u{0313}\u{0300}"),
+        ('\u{1F1B}', "\u{0395}\u{0314}\u{0300}"),
+        ('\u{1F1C}', "\u{0395}\u{0313}\u{0301}"),
+        ('\u{1F1D}', "\u{0395}\u{0314}\u{0301}"),
+        ('\u{1F20}', "\u{03B7}\u{0313}"),
+        ('\u{1F21}', "\u{03B7}\u{0314}"),
+        ('\u{1F22}', "\u{03B7}\u{0313}\u{0300}"),
+        ('\u{1F23}', "\u{03B7}\u{0314}\u{0300}"),
+        ('\u{1F24}', "\u{03B7}\u{0313}\u{0301}"),
+        ('\u{1F25}', "\u{03B7}\u{0314}\u{0301}"),
+        ('\u{1F26}', "\u{03B7}\u{0313}\u{0342}"),
+        ('\u{1F27}', "\u{03B7}\u{0314}\u{0342}"),
+        ('\u{1F28}', "\u{0397}\u{0313}"),
+        ('\u{1F29}', "\u{0397}\u{0314}"),
+        ('\u{1F2A}', "\u{0397}\u{0313}\u{0300}"),
+        ('\u{1F2B}', "\u{0397}\u{0314}\u{0300}"),
+        ('\u{1F2C}', "\u{0397}\u{0313}\u{0301}"),
+        ('\u{1F2D}', "\u{0397}\u{0314}\u{0301}"),
+        ('\u{1F2E}', "\u{0397}\u{0313}\u{0342}"),
+        ('\u{1F2F}', "\u{0397}\u{0314}\u{0342}"),
+        ('\u{1F30}', "\u{03B9}\u{0313}"),
+        ('\u{1F31}', "\u{03B9}\u{0314}"),
+        ('\u{1F32}', "\u{03B9}\u{0313}\u{0300}"),
+        ('\u{1F33}', "\u{03B9}\u{0314}\u{0300}"),
+        ('\u{1F34}', "\u{03B9}\u{0313}\u{0301}"),
+        ('\u{1F35}', "\u{03B9}\u{0314}\u{0301}"),
+        ('\u{1F36}', "\u{03B9}\u{0313}\u{0342}"),
+        ('\u{1F37}', "\u{03B9}\u{0314}\u{0342}"),
+        ('\u{1F38}', "\u{0399}\u{0313}"),
+        ('\u{1F39}', "\u{0399}\u{0314}"),
+        ('\u{1F3A}', "\u{0399}\u{0313}\u{0300}"),
+        ('\u{1F3B}', "\u{0399}\u{0314}\u{0300}"),
+        ('\u{1F3C}', "\u{0399}\u{0313}\u{0301}"),
+        ('\u{1F3D}', "\u{0399}\u{0314}\u{0301}"),
+        ('\u{1F3E}', "\u{0399}\u{0313}\u{0342}"),
+        ('\u{1F3F}', "\u{0399}\u{0314}\u{0342}"),
+        ('\u{1F40}', "\u{03BF}\u{0313}"),
+        ('\u{1F41}', "\u{03BF}\u{0314}"),
+        ('\u{1F42}', "\u{03BF}\u{0313}\u{0300}"),
+        ('\u{1F43}', "\u{03BF}\u{0314}\u{0300}"),
+        ('\u{1F44}', "\u{03BF}\u{0313}\u{0301}"),
+        ('\u{1F45}', "\u{03BF}\u{0314}\u{0301}"),
+        ('\u{1F48}', "\u{039F}\u{0313}"),
+        ('\u{1F49}', "\u{039F}\u{0314}"),
+        ('\u{1F4A}', "\u{039F}\u{0313}\u{0300}"),
+        ('\u{1F4B}', "\u{039F}\u{0314}\u{0300}"),
+        ('\u{1F4C}', "\u{039F}\u{0313}\u{0301}"),
+        ('\u{1F4D}', "\u{039F}\u{0314}\u{0301}"),
+        ('\u{1F50}', "\u{03C5}\u{0313}"),
+        ('\u{1F51}', "\u{03C5}\u{0314}"),
+        ('\u{1F52}', "\u{03C5}\u{0313}\u{0300}"),
+        ('\u{1F53}', "\u{03C5}\u{0314}\u{0300}"),
+        ('\u{1F54}', "\u{03C5}\u{0313}\u{0301}"),
+        ('\u{1F55}', "\u{03C5}\u{0314}\u{0301}"),
+        ('\u{1F56}', "\u{03C5}\u{0313}\u{0342}"),
+        ('\u{1F57}', "\u{03C5}\u{0314}\u{0342}"),
+        ('\u{1F59}', "\u{03A5}\u{0314}"),
+        ('\u{1F5B}', "\u{03A5}\u{0314}\u{0300}"),
+        ('\u{1F5D}', "\u{03A5}\u{0314}\u{0301}"),
+        ('\u{1F5F}', "\u{03A5}\u{0314}\u{0342}"),
+        ('\u{1F60}', "\u{03C9}\u{0313}"),
+        ('\u{1F61}', "\u{03C9}\u{0314}"),
+        ('\u{1F62}', "\u{03C9}\u{0313}\u{0300}"),
+        ('\u{1F63}', "\u{03C9}\u{0314}\u{0300}"),
+        ('\u{1F64}', "\u{03C9}\u{0313}\u{0301}"),
+        ('\u{1F65}', "\u{03C9}\u{0314}\u{0301}"),
+        ('\u{1F66}', "\u{03C9}\u{0313}\u{0342}"),
+        ('\u{1F67}', "\u{03C9}\u{0314}\u{0342}"),
+        ('\u{1F68}', "\u{03A9}\u{0313}"),
+        ('\u{1F69}', "\u{03A9}\u{0314}"),
+        ('\u{1F6A}', "\u{03A9}\u{0313}\u{0300}"),
+        ('\u{1F6B}', "\u{03A9}\u{0314}\u{0300}"),
+        ('\u{1F6C}', "\u{03A9}\u{0313}\u{0301}"),
+        ('\u{1F6D}', "\u{03A9}\u{0314}\u{0301}"),
+        ('\u{1F6E}', "\u{03A9}\u{0313}\u{0342}"),
+        ('\u{1F6F}', "\u{03A9}\u{0314}\u{0342}"),
+        ('\u{1F70}', "\u{03B1}\u{0300}"),
+        ('\u{1F71}', "\u{03B1}\u{0301}"),
+        ('\u{1F72}', "\u{03B5}\u{0300}"),
+        ('\u{1F73}', "\u{03B5}\u{0301}"),
+        ('\u{1F74}', "\u{03B7}\u{0300}"),
+        ('\u{1F75}', "\u{03B7}\u{0301}"),
+        ('\u{1F76}', "\u{03B9}\u{0300}"),
+        ('\u{1F77}', "\u{03B9}\u{0301}"),
+        ('\u{1F78}', "\u{03BF}\u{0300}"),
+        ('\u{1F79}', "\u{03BF}\u{0301}"),
+        ('\u{1F7A}', "\u{03C5}\u{0300}"),
+        ('\u{1F7B}', "\u{03C5}\u{0301}"),
+        ('\u{1F7C}', "\u{03C9}\u{0300}"),
+        ('\u{1F7D}', "\u{03C9}\u{0301}"),
+        ('\u{1F80}', "\u{03B1}\u{0345}\u{0313}"),
+        ('\u{1F81}', "\u{03B1}\u{0345}\u{0314}"),
+        ('\u{1F82}', "\u{03B1}\u{0345}\u{0313}\u{0300}"),
+        ('\u{1F83}', "\u{03B1}\u{0345}\u{0314}\u{0300}"),
+        ('\u{1F84}', "\u{03B1}\u{0345}\u{0313}\u{0301}"),
+        ('\u{1F85}', "\u{03B1}\u{0345}\u{0314}\u{0301}"),
+        ('\u{1F86}', "\u{03B1}\u{0345}\u{0313}\u{0342}"),
+        ('\u{1F87}', "\u{03B1}\u{0345}\u{0314}\u{0342}"),
+        ('\u{1F88}', "\u{0391}\u{0345}\u{0313}"),
+        ('\u{1F89}', "\u{0391}\u{0345}\u{0314}"),
+        ('\u{1F8A}', "\u{0391}\u{0345}\u{0313}\u{0300}"),
+        ('\u{1F8B}', "\u{0391}\u{0345}\u{0314}\u{0300}"),
+        ('\u{1F8C}', "\u{0391}\u{0345}\u{0313}\u{0301}"),
+        ('\u{1F8D}', "\u{0391}\u{0345}\u{0314}\u{0301}"),
+        ('\u{1F8E}', "\u{0391}\u{0345}\u{0313}\u{0342}"),
+        ('\u{1F8F}', "\u{0391}\u{0345}\u{0314}\u{0342}"),
+        ('\u{1F90}', "\u{03B7}\u{0345}\u{0313}"),
+        ('\u{1F91}', "\u{03B7}\u{0345}\u{0314}"),
+        ('\u{1F92}', "\u{03B7}\u{0345}\u{0313}\u{0300}"),
+        ('\u{1F93}', "\u{03B7}\u{0345}\u{0314}\u{0300}"),
+        ('\u{1F94}', "\u{03B7}\u{0345}\u{0313}\u{0301}"),
+        ('\u{1F95}', "\u{03B7}\u{0345}\u{0314}\u{0301}"),
+        ('\u{1F96}', "\u{03B7}\u{0345}\u{0313}\u{0342}"),
+        ('\u{1F97}', "\u{03B7}\u{0345}\u{0314}\u{0342}"),
+        ('\u{1F98}', "\u{0397}\u{0345}\u{0313}"),
+        ('\u{1F99}', "\u{0397}\u{0345}\u{0314}"),
+        ('\u{1F9A}', "\u{0397}\u{0345}\u{0313}\u{0300}"),
+        ('\u{1F9B}', "\u{0397}\u{0345}\u{0314}\u{0300}"),
+        ('\u{1F9C}', "\u{0397}\u{0345}\u{0313}\u{0301}"),
+        ('\u{1F9D}', "\u{0397}\u{0345}\u{0314}\u{0301}"),
+        ('\u{1F9E}', "\u{0397}\u{0345}\u{0313}\u{0342}"),
+        ('\u{1F9F}', "\u{0397}\u{0345}\u{0314}\u{0342}"),
+        ('\u{1FA0}', "\u{03C9}\u{0345}\u{0313}"),
+        ('\u{1FA1}', "\u{03C9}\u{0345}\u{0314}"),
+        ('\u{1FA2}', "\u{03C9}\u{0345}\u{0313}\u{0300}"),
+        ('\u{1FA3}', "\u{03C9}\u{0345}\u{0314}\u{0300}"),
+        ('\u{1FA4}', "\u{03C9}\u{0345}\u{0313}\u{0301}"),
+        ('\u{1FA5}', "\u{03C9}\u{0345}\u{0314}\u{0301}"),
+        ('\u{1FA6}', "\u{03C9}\u{0345}\u{0313}\u{0342}"),
+        ('\u{1FA7}', "\u{03C9}\u{0345}\u{0314}\u{0342}"),
+        ('\u{1FA8}', "\u{03A9}\u{0345}\u{0313}"),
+        ('\u{1FA9}', "\u{03A9}\u{0345}\u{0314}"),
+        ('\u{1FAA}', "\u{03A9}\u{0345}\u{0313}\u{0300}"),
+        ('\u{1FAB}', "\u{03A9}\u{0345}\u{0314}\u{0300}"),
+        ('\u{1FAC}', "\u{03A9}\u{0345}\u{0313}\u{0301}"),
+        ('\u{1FAD}', "\u{03A9}\u{0345}\u{0314}\u{0301}"),
+        ('\u{1FAE}', "\u{03A9}\u{0345}\u{0313}\u{0342}"),
+        ('\u{1FAF}', "\u{03A9}\u{0345}\u{0314}\u{0342}"),
+        ('\u{1FB0}', "\u{03B1}\u{0306}"),
+        ('\u{1FB1}', "\u{03B1}\u{0304}"),
+        ('\u{1FB2}', "\u{03B1}\u{0345}\u{0300}"),
+        ('\u{1FB3}', "\u{03B1}\u{0345}"),
+        ('\u{1FB4}', "\u{03B1}\u{0345}\u{0301}"),
+        ('\u{1FB6}', "\u{03B1}\u{0342}"),
+        ('\u{1FB7}', "\u{03B1}\u{0345}\u{0342}"),
+        ('\u{1FB8}', "\u{0391}\u{0306}"),
+        ('\u{1FB9}', "\u{0391}\u{0304}"),
+        ('\u{1FBA}', "\u{0391}\u{0300}"),
+        ('\u{1FBB}', "\u{0391}\u{0301}"),
+        ('\u{1FBC}', "\u{0391}\u{0345}"),
+        ('\u{1FC1}', "\u{00A8}\u{0342}"),
+        ('\u{1FC2}', "\u{03B7}\u{0345}\u{0300}"),
+        ('\u{1FC3}', "\u{03B7}\u{0345}"),
+        ('\u{1FC4}', "\u{03B7}\u{0345}\u{0301}"),
+        ('\u{1FC6}', "\u{03B7}\u{0342}"),
+        ('\u{1FC7}', "\u{03B7}\u{0345}\u{0342}"),
+        ('\u{1FC8}', "\u{0395}\u{0300}"),
+        ('\u{1FC9}', "\u{0395}\u{0301}"),
+        ('\u{1FCA}', "\u{0397}\u{0300}"),
+        ('\u{1FCB}', "\u{0397}\u{0301}"),
+        ('\u{1FCC}', "\u{0397}\u{0345}"),
+        ('\u{1FCD}', "\u{1FBF}\u{0300}"),
+        ('\u{1FCE}', "\u{1FBF}\u{0301}"),
+        ('\u{1FCF}', "\u{1FBF}\u{0342}"),
+        ('\u{1FD0}', "\u{03B9}\u{0306}"),
+        ('\u{1FD1}', "\u{03B9}\u{0304}"),
+        ('\u{1FD2}', "\u{03B9}\u{0308}\u{0300}"),
+        ('\u{1FD3}', "\u{03B9}\u{0308}\u{0301}"),
+        ('\u{1FD6}', "\u{03B9}\u{0342}"),
+        ('\u{1FD7}', "\u{03B9}\u{0308}\u{0342}"),
+        ('\u{1FD8}', "\u{0399}\u{0306}"),
+        ('\u{1FD9}', "\u{0399}\u{0304}"),
+        ('\u{1FDA}', "\u{0399}\u{0300}"),
+        ('\u{1FDB}', "\u{0399}\u{0301}"),
+        ('\u{1FDD}', "\u{1FFE}\u{0300}"),
+        ('\u{1FDE}', "\u{1FFE}\u{0301}"),
+        ('\u{1FDF}', "\u{1FFE}\u{0342}"),
+        ('\u{1FE0}', "\u{03C5}\u{0306}"),
+        ('\u{1FE1}', "\u{03C5}\u{0304}"),
+        ('\u{1FE2}', "\u{03C5}\u{0308}\u{0300}"),
+        ('\u{1FE3}', "\u{03C5}\u{0308}\u{0301}"),
+        ('\u{1FE4}', "\u{03C1}\u{0313}"),
+        ('\u{1FE5}', "\u{03C1}\u{0314}"),
+        ('\u{1FE6}', "\u{03C5}\u{0342}"),
+        ('\u{1FE7}', "\u{03C5}\u{0308}\u{0342}"),
+        ('\u{1FE8}', "\u{03A5}\u{0306}"),
+        ('\u{1FE9}', "\u{03A5}\u{0304}"),
+        ('\u{1FEA}', "\u{03A5}\u{0300}"),
+        ('\u{1FEB}', "\u{03A5}\u{0301}"),
+        ('\u{1FEC}', "\u{03A1}\u{0314}"),
+        ('\u{1FED}', "\u{00A8}\u{0300}"),
+        ('\u{1FEE}', "\u{00A8}\u{0301}"),
+        ('\u{1FF2}', "\u{03C9}\u{0345}\u{0300}"),
+        ('\u{1FF3}', "\u{03C9}\u{0345}"),
+        ('\u{1FF4}', "\u{03BF}\u{0345}\u{0301}"),
+        ('\u{1FF6}', "\u{03C9}\u{0342}"),
+        ('\u{1FF7}', "\u{03C9}\u{0345}\u{0342}"),
+        ('\u{1FF8}', "\u{039F}\u{0300}"),
+        ('\u{1FF9}', "\u{039F}\u{0301}"),
+        ('\u{1FFA}', "\u{03A9}\u{0300}"),
+        ('\u{1FFB}', "\u{03A9}\u{0301}"),
+        ('\u{1FFC}', "\u{03A9}\u{0345}"),
+        ('\u{304C}', "\u{304B}\u{3099}"),
+        ('\u{304E}', "\u{304D}\u{3099}"),
+        ('\u{3050}', "\u{304F}\u{3099}"),
+        ('\u{3052}', "\u{3051}\u{3099}"),
+        ('\u{3054}', "\u{3053}\u{3099}"),
+        ('\u{3056}', "\u{3055}\u{3099}"),
+        ('\u{3058}', "\u{3057}\u{3099}"),
+        ('\u{305A}', "\u{3059}\u{3099}"),
+        ('\u{305C}', "\u{305B}\u{3099}"),
+        ('\u{305E}', "\u{305D}\u{3099}"),
+        ('\u{3060}', "\u{305F}\u{3099}"),
+        ('\u{3062}', "\u{3061}\u{3099}"),
+        ('\u{3065}', "\u{3064}\u{3099}"),
+        ('\u{3067}', "\u{3066}\u{3099}"),
+        ('\u{3069}', "\u{3068}\u{3099}"),
+        ('\u{3070}', "\u{306F}\u{3099}"),
+        ('\u{3071}', "\u{306F}\u{309A}"),
+        ('\u{3073}', "\u{3072}\u{3099}"),
+        ('\u{3074}', "\u{3072}\u{309A}"),
+        ('\u{3076}', "\u{3075}\u{3099}"),
+        ('\u{3077}', "\u{3075}\u{309A}"),
+        ('\u{3079}', "\u{3078}\u{3099}"),
+        ('\u{307A}', "\u{3078}\u{309A}"),
+        ('\u{307C}', "\u{307B}\u{3099}"),
+        ('\u{307D}', "\u{307B}\u{309A}"),
+        ('\u{3094}', "\u{3046}\u{3099}"),
+        ('\u{309E}', "\u{309D}\u{3099}"),
+        ('\u{30AC}', "\u{30AB}\u{3099}"),
+        ('\u{30AE}', "\u{30AD}\u{3099}"),
+        ('\u{30B0}', "\u{30AF}\u{3099}"),
+        ('\u{30B2}', "\u{30B1}\u{3099}"),
+        ('\u{30B4}', "\u{30B3}\u{3099}"),
+        ('\u{30B6}', "\u{30B5}\u{3099}"),
+        ('\u{30B8}', "\u{30B7}\u{3099}"),
+        ('\u{30BA}', "\u{30B9}\u{3099}"),
+        ('\u{30BC}', "\u{30BB}\u{3099}"),
+        ('\u{30BE}', "\u{30BD}\u{3099}"),
+        ('\u{30C0}', "\u{30BF}\u{3099}"),
+        ('\u{30C2}', "\u{30C1}\u{3099}"),
+        ('\u{30C5}', "\u{30C4}\u{3099}"),
+        ('\u{30C7}', "\u{30C6}\u{3099}"),
+        ('\u{30C9}', "\u{30C8}\u{3099}"),
+        ('\u{30D0}', "\u{30CF}\u{3099}"),
+        ('\u{30D1}', "\u{30CF}\u{309A}"),
+        ('\u{30D3}', "\u{30D2}\u{3099}"),
+        ('\u{30D4}', "\u{30D2}\u{309A}"),
+        ('\u{30D6}', "\u{30D5}\u{3099}"),
+        ('\u{30D7}', "\u{30D5}\u{309A}"),
+        ('\u{30D9}', "\u{30D8}\u{3099}"),
+        ('\u{30DA}', "\u{30D8}\u{309A}"),
+        ('\u{30DC}', "\u{30DB}\u{3099}"),
+        ('\u{30DD}', "\u{30DB}\u{309A}"),
+        ('\u{30F4}', "\u{30A6}\u{3099}"),
+        ('\u{30F7}', "\u{30EF}\u{3099}"),
+        ('\u{30F8}', "\u{30F0}\u{3099}"),
+        ('\u{30F9}', "\u{30F1}\u{3099}"),
+        ('\u{30FA}', "\u{30F2}\u{3099}"),
+        ('\u{30FE}', "\u{30FD}\u{3099}"),
+        ('\u{FB1F}', "\u{05F2}\u{05B7}"),
+        ('\u{FB2A}', "\u{05E9}\u{05C1}"),
+        ('\u{FB2B}', "\u{05E9}\u{05C2}"),
+        ('\u{FB2C}', "\u{05E9}\u{05BC}\u{05C1}"),
+        ('\u{FB2D}', "\u{05E9}\u{05BC}\u{05C2}"),
+        ('\u{FB2E}', "\u{05D0}\u{05B7}"),
+        ('\u{FB2F}', "\u{05D0}\u{05B8}"),
+        ('\u{FB30}', "\u{05D0}\u{05BC}"),
+        ('\u{FB31}', "\u{05D1}\u{05BC}"),
+        ('\u{FB32}', "\u{05D2}\u{05BC}"),
+        ('\u{FB33}', "\u{05D3}\u{05BC}"),
+        ('\u{FB34}', "\u{05D4}\u{05BC}"),
+        ('\u{FB35}', "\u{05D5}\u{05BC}"),
+        ('\u{FB36}', "\u{05D6}\u{05BC}"),
+        ('\u{FB38}', "\u{05D8}\u{05BC}"),
+        ('\u{FB39}', "\u{05D9}\u{05BC}"),
+        ('\u{FB3A}', "\u{05DA}\u{05BC}"),
+        ('\u{FB3B}', "\u{05DB}\u{05BC}"),
+        ('\u{FB3C}', "\u{05DC}\u{05BC}"),
+        ('\u{FB3E}', "\u{05DE}\u{05BC}"),
+        ('\u{FB40}', "\u{05E0}\u{05BC}"),
+        ('\u{FB41}', "\u{05E1}\u{05BC}"),
+        ('\u{FB43}', "\u{05E3}\u{05BC}"),
+        ('\u{FB44}', "\u{05E4}\u{05BC}"),
+        ('\u{FB46}', "\u{05E6}\u{05BC}"),
+        ('\u{FB47}', "\u{05E7}\u{05BC}"),
+        ('\u{FB48}', "\u{05E8}\u{05BC}"),
+        ('\u{FB49}', "\u{05E9}\u{05BC}"),
+        ('\u{FB4A}', "\u{05EA}\u{05BC}"),
+        ('\u{FB4B}', "\u{05D5}\u{05B9}"),
+        ('\u{FB4C}', "\u{05D1}\u{05BF}"),
+        ('\u{FB4D}', "\u{05DB}\u{05BF}"),
+        ('\u{FB4E}', "\u{05E4}\u{05BF}"),
+    ],
+    buckets: &[
+        0, 0, 0, 0, 0, 0, 0, 26, 53, 83, 108, 132, 161, 161, 165, 183,
+        206, 230, 230, 230, 230, 230, 230, 230, 230, 231, 231, 232, 232, 241, 248, 255,
+        255, 261, 262, 267, 269, 269, 269, 283, 301, 301, 301, 301, 301, 301, 301, 301,
+        301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301,
+        301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 304, 312, 312, 312, 313, 318,
+        318, 318, 318, 323, 323, 323, 323, 323, 323, 323, 323, 329, 329, 330, 330, 333,
+        333, 333, 333, 334, 334, 334, 334, 339, 339, 339, 339, 342, 342, 342, 342, 342,
+        342, 342, 343, 343, 343, 343, 344, 344, 344, 344, 344, 349, 356, 359, 363, 363,
+        363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363,
+        363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363,
+        363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363,
+        363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363,
+        363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363,
+        363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363,
+        363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363, 363,
+        363, 395, 427, 459, 491, 518, 550, 582, 608, 636, 668, 692, 722, 754, 782, 809,
+        834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834,
+        834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834,
+        834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834,
+        834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834,
+        834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834,
+        834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834,
+        834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834,
+        834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834, 834,
+        834, 834, 834, 844, 859, 861, 871, 886, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892, 892,
+        892, 892, 892, 892, 892, 892, 892, 892, 892, 893, 912, 925,
+    ],
+};
+
+const U0041_0302: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EA6}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EA4}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EAA}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EA8}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0041_0306: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EB0}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EAE}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EB4}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EB2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0041_0307: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01E0}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0041_0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01DE}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0041_030A: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01FA}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0041_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{1EAC}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1EB6}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0041: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00C0}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00C1}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00C2}'), Some(U0041_0302))),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{00C3}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{0100}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{0102}'), Some(U0041_0306))),
+        ('\u{0307}', ReverseTreeNode::new(None, Some(U0041_0307))),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00C4}'), Some(U0041_0308))),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EA2}'), None)),
+        ('\u{030A}', ReverseTreeNode::new(Some('\u{00C5}'), Some(U0041_030A))),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01CD}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0200}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{0202}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EA0}'), Some(U0041_0323))),
+        ('\u{0325}', ReverseTreeNode::new(Some('\u{1E00}'), None)),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{0104}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0042: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E02}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E04}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E06}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0043_0327: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E08}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0043: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0106}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0108}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{010A}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{010C}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{00C7}'), Some(U0043_0327))),
+    ],
+    buckets: &[],
+};
+
+const U0044: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E0A}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{010E}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E0C}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{1E10}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E12}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E0E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0045_0302: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EC0}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EBE}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EC4}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EC2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0045_0304: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1E14}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E16}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0045_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{1EC6}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0045_0327: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1E1C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0045: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00C8}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00C9}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00CA}'), Some(U0045_0302))),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EBC}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{0112}'), Some(U0045_0304))),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{0114}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{0116}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00CB}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EBA}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{011A}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0204}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{0206}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EB8}'), Some(U0045_0323))),
+        ('\u{0327}', ReverseTreeNode::new(None, Some(U0045_0327))),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{0118}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E18}'), None)),
+        ('\u{0330}', ReverseTreeNode::new(Some('\u{1E1A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0046: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E1E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0047: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01F4}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{011C}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1E20}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{011E}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{0120}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01E6}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0122}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0048: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0124}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E22}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E26}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E24}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{1E28}'), None)),
+        ('\u{032E}', ReverseTreeNode::new(Some('\u{1E2A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0049_0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E2E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0049: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00CC}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00CD}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00CE}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{0128}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{012A}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{012C}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{0130}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00CF}'), Some(U0049_0308))),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EC8}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01CF}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0208}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{020A}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1ECA}'), None)),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{012E}'), None)),
+        ('\u{0330}', ReverseTreeNode::new(Some('\u{1E2C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004A: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0134}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E30}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01E8}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E32}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0136}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E34}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004C_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1E38}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004C: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0139}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{013D}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E36}'), Some(U004C_0323))),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{013B}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E3C}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E3A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004D: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E3E}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E40}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E42}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004E: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0143}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{00D1}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E44}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{0147}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E46}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0145}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E4A}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E48}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004F_0302: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1ED2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1ED0}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1ED6}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1ED4}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004F_0303: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E4C}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E4E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004F_0304: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1E50}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E52}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004F_031B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EDC}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EDA}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EE0}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EDE}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EE2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004F_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{1ED8}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004F_0328: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01EC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U004F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00D2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00D3}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00D4}'), Some(U004F_0302))),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{00D5}'), Some(U004F_0303))),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{014C}'), Some(U004F_0304))),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{014E}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00D6}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1ECE}'), None)),
+        ('\u{030B}', ReverseTreeNode::new(Some('\u{0150}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01D1}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{020C}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{020E}'), None)),
+        ('\u{031B}', ReverseTreeNode::new(Some('\u{01A0}'), Some(U004F_031B))),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1ECC}'), Some(U004F_0323))),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{01EA}'), Some(U004F_0328))),
+    ],
+    buckets: &[],
+};
+
+const U0050: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E54}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E56}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0052_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1E5C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0052: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0154}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E58}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{0158}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0210}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{0212}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E5A}'), Some(U0052_0323))),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0156}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E5E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0053_0301: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E64}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0053_030C: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E66}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0053_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E68}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0053: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{015A}'), Some(U0053_0301))),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{015C}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E60}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{0160}'), Some(U0053_030C))),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E62}'), Some(U0053_0323))),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{015E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0054: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E6A}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{0164}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E6C}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0162}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E70}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E6E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0055_0303: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E78}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0055_0304: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E7A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0055_0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{01DB}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01D7}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01D5}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01D9}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0055_031B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EEA}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EE8}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EEE}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EEC}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EF0}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0055: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00D9}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00DA}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00DB}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{0168}'), Some(U0055_0303))),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{016A}'), Some(U0055_0304))),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{016C}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00DC}'), Some(U0055_0308))),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EE6}'), None)),
+        ('\u{030A}', ReverseTreeNode::new(Some('\u{016E}'), None)),
+        ('\u{030B}', ReverseTreeNode::new(Some('\u{0170}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01D3}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0214}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{0216}'), None)),
+        ('\u{031B}', ReverseTreeNode::new(Some('\u{01AF}'), Some(U0055_031B))),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EE4}'), None)),
+        ('\u{0324}', ReverseTreeNode::new(Some('\u{1E72}'), None)),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{0172}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E76}'), None)),
+        ('\u{0330}', ReverseTreeNode::new(Some('\u{1E74}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0056: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1E7C}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E7E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0057: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1E80}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E82}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0174}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E86}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E84}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E88}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0058: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E8A}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E8C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0059: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EF2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00DD}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0176}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EF8}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E8E}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{0178}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EF6}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EF4}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U005A: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0179}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{1E90}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{017B}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{017D}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E92}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E94}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0061_0302: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EA7}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EA5}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EAB}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EA9}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0061_0306: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EB1}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EAF}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EB5}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EB3}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0061_0307: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01E1}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0061_0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01DF}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0061_030A: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01FB}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0061_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{1EAD}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1EB7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0061: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00E0}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00E1}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00E2}'), Some(U0061_0302))),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{00E3}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{0101}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{0103}'), Some(U0061_0306))),
+        ('\u{0307}', ReverseTreeNode::new(None, Some(U0061_0307))),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00E4}'), Some(U0061_0308))),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EA3}'), None)),
+        ('\u{030A}', ReverseTreeNode::new(Some('\u{00E5}'), Some(U0061_030A))),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01CE}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0201}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{0203}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EA1}'), Some(U0061_0323))),
+        ('\u{0325}', ReverseTreeNode::new(Some('\u{1E01}'), None)),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{0105}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0062: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E03}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E05}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E07}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0063_0327: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E09}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0063: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0107}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0109}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{010B}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{010D}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{00E7}'), Some(U0063_0327))),
+    ],
+    buckets: &[],
+};
+
+const U0064: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E0B}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{010F}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E0D}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{1E11}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E13}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E0F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0065_0302: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EC1}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EBF}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EC5}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EC3}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0065_0304: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1E15}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E17}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0065_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{1EC7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0065_0327: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1E1D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0065: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00E8}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00E9}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00EA}'), Some(U0065_0302))),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EBD}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{0113}'), Some(U0065_0304))),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{0115}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{0117}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00EB}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EBB}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{011B}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0205}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{0207}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EB9}'), Some(U0065_0323))),
+        ('\u{0327}', ReverseTreeNode::new(None, Some(U0065_0327))),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{0119}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E19}'), None)),
+        ('\u{0330}', ReverseTreeNode::new(Some('\u{1E1B}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0066: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E1F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0067: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01F5}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{011D}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1E21}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{011F}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{0121}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01E7}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0123}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0068: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0125}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E23}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E27}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E25}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{1E29}'), None)),
+        ('\u{032E}', ReverseTreeNode::new(Some('\u{1E2B}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E96}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0069_0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E2F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0069: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00EC}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00ED}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00EE}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{0129}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{012B}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{012D}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00EF}'), Some(U0069_0308))),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EC9}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01D0}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0209}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{020B}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1ECB}'), None)),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{012F}'), None)),
+        ('\u{0330}', ReverseTreeNode::new(Some('\u{1E2D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006A: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0135}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01F0}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E31}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01E9}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E33}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0137}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E35}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006C_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1E39}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006C: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{013A}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{013E}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E37}'), Some(U006C_0323))),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{013C}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E3D}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E3B}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006D: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E3F}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E41}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E43}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006E: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0144}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{00F1}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E45}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{0148}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E47}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0146}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E4B}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E49}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006F_0302: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1ED3}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1ED1}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1ED7}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1ED5}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006F_0303: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E4D}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E4F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006F_0304: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1E51}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E53}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006F_031B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EDD}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EDB}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EE1}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EDF}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EE3}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006F_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{1ED9}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006F_0328: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01ED}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U006F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00F2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00F3}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00F4}'), Some(U006F_0302))),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{00F5}'), Some(U006F_0303))),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{014D}'), Some(U006F_0304))),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{014F}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00F6}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1ECF}'), None)),
+        ('\u{030B}', ReverseTreeNode::new(Some('\u{0151}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01D2}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{020D}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{020F}'), None)),
+        ('\u{031B}', ReverseTreeNode::new(Some('\u{01A1}'), Some(U006F_031B))),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1ECD}'), Some(U006F_0323))),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{01EB}'), Some(U006F_0328))),
+    ],
+    buckets: &[],
+};
+
+const U0070: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E55}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E57}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0072_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1E5D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0072: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0155}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E59}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{0159}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0211}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{0213}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E5B}'), Some(U0072_0323))),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0157}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E5F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0073_0301: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E65}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0073_030C: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E67}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0073_0323: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E69}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0073: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{015B}'), Some(U0073_0301))),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{015D}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E61}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{0161}'), Some(U0073_030C))),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E63}'), Some(U0073_0323))),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{015F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0074: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E6B}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E97}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{0165}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E6D}'), None)),
+        ('\u{0327}', ReverseTreeNode::new(Some('\u{0163}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E71}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E6F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0075_0303: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E79}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0075_0304: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E7B}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0075_0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{01DC}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01D8}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01D6}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01DA}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0075_031B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EEB}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1EE9}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EEF}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EED}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EF1}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0075: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{00F9}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00FA}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{00FB}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{0169}'), Some(U0075_0303))),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{016B}'), Some(U0075_0304))),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{016D}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00FC}'), Some(U0075_0308))),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EE7}'), None)),
+        ('\u{030A}', ReverseTreeNode::new(Some('\u{016F}'), None)),
+        ('\u{030B}', ReverseTreeNode::new(Some('\u{0171}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01D4}'), None)),
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0215}'), None)),
+        ('\u{0311}', ReverseTreeNode::new(Some('\u{0217}'), None)),
+        ('\u{031B}', ReverseTreeNode::new(Some('\u{01B0}'), Some(U0075_031B))),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EE5}'), None)),
+        ('\u{0324}', ReverseTreeNode::new(Some('\u{1E73}'), None)),
+        ('\u{0328}', ReverseTreeNode::new(Some('\u{0173}'), None)),
+        ('\u{032D}', ReverseTreeNode::new(Some('\u{1E77}'), None)),
+        ('\u{0330}', ReverseTreeNode::new(Some('\u{1E75}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0076: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1E7D}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E7F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0077: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1E81}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1E83}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0175}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E87}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E85}'), None)),
+        ('\u{030A}', ReverseTreeNode::new(Some('\u{1E98}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E89}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0078: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E8B}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{1E8D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0079: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1EF3}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{00FD}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{0177}'), None)),
+        ('\u{0303}', ReverseTreeNode::new(Some('\u{1EF9}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E8F}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{00FF}'), None)),
+        ('\u{0309}', ReverseTreeNode::new(Some('\u{1EF7}'), None)),
+        ('\u{030A}', ReverseTreeNode::new(Some('\u{1E99}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1EF5}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U007A: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{017A}'), None)),
+        ('\u{0302}', ReverseTreeNode::new(Some('\u{1E91}'), None)),
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{017C}'), None)),
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{017E}'), None)),
+        ('\u{0323}', ReverseTreeNode::new(Some('\u{1E93}'), None)),
+        ('\u{0331}', ReverseTreeNode::new(Some('\u{1E95}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U00A8: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FED}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FEE}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{0385}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FC1}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U00C6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01FC}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01E2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U00D8: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01FE}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U00E6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01FD}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{01E3}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U00F8: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{01FF}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U017F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{1E9B}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U018F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04DA}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U019F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04EA}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U01B7: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01EE}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0259: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04DB}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0275: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04EB}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0292: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{030C}', ReverseTreeNode::new(Some('\u{01EF}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0306: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0307}', ReverseTreeNode::new(Some('\u{0310}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{0344}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0391_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F0A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F0C}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F0E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0391_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F0B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F0D}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F0F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0391_0345_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F8A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F8C}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F8E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0391_0345_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F8B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F8D}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F8F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0391_0345: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F88}'), Some(U0391_0345_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F89}'), Some(U0391_0345_0314))),
+    ],
+    buckets: &[],
+};
+
+const U0391: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FBA}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FBB}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1FB9}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1FB8}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{0386}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F08}'), Some(U0391_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F09}'), Some(U0391_0314))),
+        ('\u{0345}', ReverseTreeNode::new(Some('\u{1FBC}'), Some(U0391_0345))),
+    ],
+    buckets: &[],
+};
+
+const U0395_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F1A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F1C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0395_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F1B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F1D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0395: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FC8}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FC9}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{0388}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F18}'), Some(U0395_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F19}'), Some(U0395_0314))),
+    ],
+    buckets: &[],
+};
+
+const U0397_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F2A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F2C}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F2E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0397_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F2B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F2D}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F2F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0397_0345_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F9A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F9C}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F9E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0397_0345_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F9B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F9D}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F9F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0397_0345: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F98}'), Some(U0397_0345_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F99}'), Some(U0397_0345_0314))),
+    ],
+    buckets: &[],
+};
+
+const U0397: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FCA}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FCB}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{0389}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F28}'), Some(U0397_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F29}'), Some(U0397_0314))),
+        ('\u{0345}', ReverseTreeNode::new(Some('\u{1FCC}'), Some(U0397_0345))),
+    ],
+    buckets: &[],
+};
+
+const U0399_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F3A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F3C}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F3E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0399_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F3B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F3D}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F3F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0399: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FDA}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FDB}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1FD9}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1FD8}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{03AA}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{038A}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F38}'), Some(U0399_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F39}'), Some(U0399_0314))),
+    ],
+    buckets: &[],
+};
+
+const U039F_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F4A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F4C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U039F_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F4B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F4D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U039F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FF8}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FF9}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{038C}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F48}'), Some(U039F_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F49}'), Some(U039F_0314))),
+    ],
+    buckets: &[],
+};
+
+const U03A1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1FEC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03A5_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F5B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F5D}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F5F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03A5: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FEA}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FEB}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1FE9}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1FE8}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{03AB}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{038E}'), None)),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F59}'), Some(U03A5_0314))),
+    ],
+    buckets: &[],
+};
+
+const U03A9_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F6A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F6C}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F6E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03A9_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F6B}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F6D}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F6F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03A9_0345_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FAA}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FAC}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FAE}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03A9_0345_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FAB}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FAD}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FAF}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03A9_0345: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1FA8}'), Some(U03A9_0345_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1FA9}'), Some(U03A9_0345_0314))),
+    ],
+    buckets: &[],
+};
+
+const U03A9: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FFA}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FFB}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{038F}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F68}'), Some(U03A9_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F69}'), Some(U03A9_0314))),
+        ('\u{0345}', ReverseTreeNode::new(Some('\u{1FFC}'), Some(U03A9_0345))),
+    ],
+    buckets: &[],
+};
+
+const U03B1_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F02}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F04}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F06}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B1_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F03}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F05}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F07}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B1_0345_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F82}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F84}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F86}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B1_0345_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F83}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F85}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F87}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B1_0345: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FB2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FB4}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F80}'), Some(U03B1_0345_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F81}'), Some(U03B1_0345_0314))),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FB7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F70}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F71}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1FB1}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1FB0}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03AC}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F00}'), Some(U03B1_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F01}'), Some(U03B1_0314))),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FB6}'), None)),
+        ('\u{0345}', ReverseTreeNode::new(Some('\u{1FB3}'), Some(U03B1_0345))),
+    ],
+    buckets: &[],
+};
+
+const U03B5_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F12}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F14}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B5_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F13}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F15}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B5: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F72}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F73}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03AD}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F10}'), Some(U03B5_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F11}'), Some(U03B5_0314))),
+    ],
+    buckets: &[],
+};
+
+const U03B7_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F22}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F24}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F26}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B7_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F23}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F25}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F27}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B7_0345_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F92}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F94}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F96}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B7_0345_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F93}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F95}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F97}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B7_0345: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FC2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FC4}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F90}'), Some(U03B7_0345_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F91}'), Some(U03B7_0345_0314))),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FC7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B7: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F74}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F75}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03AE}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F20}'), Some(U03B7_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F21}'), Some(U03B7_0314))),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FC6}'), None)),
+        ('\u{0345}', ReverseTreeNode::new(Some('\u{1FC3}'), Some(U03B7_0345))),
+    ],
+    buckets: &[],
+};
+
+const U03B9_0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FD2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FD3}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{0390}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FD7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B9_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F32}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F34}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F36}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B9_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F33}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F35}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F37}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03B9: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F76}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F77}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1FD1}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1FD0}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{03CA}'), Some(U03B9_0308))),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03AF}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F30}'), Some(U03B9_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F31}'), Some(U03B9_0314))),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FD6}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03BF_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F42}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F44}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03BF_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F43}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F45}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03BF_0345: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FF4}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03BF: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F78}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F79}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03CC}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F40}'), Some(U03BF_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F41}'), Some(U03BF_0314))),
+        ('\u{0345}', ReverseTreeNode::new(None, Some(U03BF_0345))),
+    ],
+    buckets: &[],
+};
+
+const U03C1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1FE4}'), None)),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1FE5}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C5_0308: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FE2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FE3}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03B0}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FE7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C5_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F52}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F54}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F56}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C5_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F53}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F55}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F57}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C5: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F7A}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F7B}'), None)),
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{1FE1}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{1FE0}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{03CB}'), Some(U03C5_0308))),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03CD}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F50}'), Some(U03C5_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F51}'), Some(U03C5_0314))),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FE6}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C9_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F62}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F64}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F66}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C9_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F63}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F65}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1F67}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C9_0345_0313: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FA2}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FA4}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FA6}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C9_0345_0314: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FA3}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FA5}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FA7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C9_0345: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FF2}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1FA0}'), Some(U03C9_0345_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1FA1}'), Some(U03C9_0345_0314))),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FF7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U03C9: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1F7C}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1F7D}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03CE}'), None)),
+        ('\u{0313}', ReverseTreeNode::new(Some('\u{1F60}'), Some(U03C9_0313))),
+        ('\u{0314}', ReverseTreeNode::new(Some('\u{1F61}'), Some(U03C9_0314))),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FF6}'), None)),
+        ('\u{0345}', ReverseTreeNode::new(Some('\u{1FF3}'), Some(U03C9_0345))),
+    ],
+    buckets: &[],
+};
+
+const U03D2: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{03D4}'), None)),
+        ('\u{030D}', ReverseTreeNode::new(Some('\u{03D3}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0406: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{0407}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0410: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{04D0}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04D2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0413: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0403}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0415: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{04D6}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{0401}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0416: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{04C1}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04DC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0417: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04DE}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0418: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{04E2}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{0419}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04E4}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U041A: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{040C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U041E: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04E6}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0423: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{04EE}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{040E}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04F0}'), None)),
+        ('\u{030B}', ReverseTreeNode::new(Some('\u{04F2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0427: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04F4}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U042B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04F8}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0430: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{04D1}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04D3}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0433: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{0453}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0435: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{04D7}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{0451}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0436: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{04C2}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04DD}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0437: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04DF}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0438: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{04E3}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{0439}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04E5}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U043A: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{045C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U043E: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04E7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0443: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0304}', ReverseTreeNode::new(Some('\u{04EF}'), None)),
+        ('\u{0306}', ReverseTreeNode::new(Some('\u{045E}'), None)),
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04F1}'), None)),
+        ('\u{030B}', ReverseTreeNode::new(Some('\u{04F3}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0447: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04F5}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U044B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{04F9}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0456: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0308}', ReverseTreeNode::new(Some('\u{0457}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0474: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0476}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0475: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{030F}', ReverseTreeNode::new(Some('\u{0477}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D0: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05B7}', ReverseTreeNode::new(Some('\u{FB2E}'), None)),
+        ('\u{05B8}', ReverseTreeNode::new(Some('\u{FB2F}'), None)),
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB30}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB31}'), None)),
+        ('\u{05BF}', ReverseTreeNode::new(Some('\u{FB4C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D2: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB32}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D3: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB33}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D4: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB34}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D5: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05B9}', ReverseTreeNode::new(Some('\u{FB4B}'), None)),
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB35}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB36}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D8: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB38}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05D9: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB39}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05DA: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB3A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05DB: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB3B}'), None)),
+        ('\u{05BF}', ReverseTreeNode::new(Some('\u{FB4D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05DC: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB3C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05DE: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB3E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E0: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB40}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB41}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E3: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB43}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E4: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB44}'), None)),
+        ('\u{05BF}', ReverseTreeNode::new(Some('\u{FB4E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB46}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E7: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB47}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E8: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB48}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E9_05BC: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05C1}', ReverseTreeNode::new(Some('\u{FB2C}'), None)),
+        ('\u{05C2}', ReverseTreeNode::new(Some('\u{FB2D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05E9: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB49}'), Some(U05E9_05BC))),
+        ('\u{05C1}', ReverseTreeNode::new(Some('\u{FB2A}'), None)),
+        ('\u{05C2}', ReverseTreeNode::new(Some('\u{FB2B}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05EA: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05BC}', ReverseTreeNode::new(Some('\u{FB4A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U05F2: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{05B7}', ReverseTreeNode::new(Some('\u{FB1F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0915: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{0958}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0916: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{0959}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0917: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{095A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U091C: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{095B}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0921: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{095C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0922: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{095D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0928: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{0929}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U092B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{095E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U092F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{095F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0930: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{0931}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0933: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{093C}', ReverseTreeNode::new(Some('\u{0934}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U09A1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{09BC}', ReverseTreeNode::new(Some('\u{09DC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U09A2: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{09BC}', ReverseTreeNode::new(Some('\u{09DD}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U09AC: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{09BC}', ReverseTreeNode::new(Some('\u{09B0}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U09AF: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{09BC}', ReverseTreeNode::new(Some('\u{09DF}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U09C7: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{09BE}', ReverseTreeNode::new(Some('\u{09CB}'), None)),
+        ('\u{09D7}', ReverseTreeNode::new(Some('\u{09CC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0A16: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A59}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0A17: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A5A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0A1C: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A5B}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0A21: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A5C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0A2B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0A3C}', ReverseTreeNode::new(Some('\u{0A5E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0B21: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0B3C}', ReverseTreeNode::new(Some('\u{0B5C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0B22: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0B3C}', ReverseTreeNode::new(Some('\u{0B5D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0B2F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0B3C}', ReverseTreeNode::new(Some('\u{0B5F}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0B47: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0B3E}', ReverseTreeNode::new(Some('\u{0B4B}'), None)),
+        ('\u{0B56}', ReverseTreeNode::new(Some('\u{0B48}'), None)),
+        ('\u{0B57}', ReverseTreeNode::new(Some('\u{0B4C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0B92: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0BD7}', ReverseTreeNode::new(Some('\u{0B94}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0BC6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0BBE}', ReverseTreeNode::new(Some('\u{0BCA}'), None)),
+        ('\u{0BD7}', ReverseTreeNode::new(Some('\u{0BCC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0BC7: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0BBE}', ReverseTreeNode::new(Some('\u{0BCB}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0C46: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0C56}', ReverseTreeNode::new(Some('\u{0C48}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0CBF: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0CD5}', ReverseTreeNode::new(Some('\u{0CC0}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0CC6_0CC2: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0CD5}', ReverseTreeNode::new(Some('\u{0CCB}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0CC6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0CC2}', ReverseTreeNode::new(Some('\u{0CCA}'), Some(U0CC6_0CC2))),
+        ('\u{0CD5}', ReverseTreeNode::new(Some('\u{0CC7}'), None)),
+        ('\u{0CD6}', ReverseTreeNode::new(Some('\u{0CC8}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0D46: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0D3E}', ReverseTreeNode::new(Some('\u{0D4A}'), None)),
+        ('\u{0D57}', ReverseTreeNode::new(Some('\u{0D4C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0D47: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0D3E}', ReverseTreeNode::new(Some('\u{0D4B}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0E4D: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0E32}', ReverseTreeNode::new(Some('\u{0E33}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0ECD: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0EB2}', ReverseTreeNode::new(Some('\u{0EB3}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F40: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB5}', ReverseTreeNode::new(Some('\u{0F69}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F42: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F43}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F4C: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F4D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F51: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F52}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F56: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F57}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F5B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F5C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F72: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0F71}', ReverseTreeNode::new(Some('\u{0F73}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F74: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0F71}', ReverseTreeNode::new(Some('\u{0F75}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F80: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0F71}', ReverseTreeNode::new(Some('\u{0F81}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F90: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB5}', ReverseTreeNode::new(Some('\u{0FB9}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F92: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F93}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0F9C: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0F9D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0FA1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0FA2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0FA6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0FA7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0FAB: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0FB7}', ReverseTreeNode::new(Some('\u{0FAC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0FB2_0F80: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0F71}', ReverseTreeNode::new(Some('\u{0F77}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0FB2: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0F80}', ReverseTreeNode::new(Some('\u{0F76}'), Some(U0FB2_0F80))),
+    ],
+    buckets: &[],
+};
+
+const U0FB3_0F80: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0F71}', ReverseTreeNode::new(Some('\u{0F79}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U0FB3: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0F80}', ReverseTreeNode::new(Some('\u{0F78}'), Some(U0FB3_0F80))),
+    ],
+    buckets: &[],
+};
+
+const U1FBF: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FCD}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FCE}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FCF}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U1FFE: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0300}', ReverseTreeNode::new(Some('\u{1FDD}'), None)),
+        ('\u{0301}', ReverseTreeNode::new(Some('\u{1FDE}'), None)),
+        ('\u{0342}', ReverseTreeNode::new(Some('\u{1FDF}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3046: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3094}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U304B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{304C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U304D: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{304E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U304F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3050}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3051: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3052}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3053: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3054}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3055: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3056}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3057: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3058}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3059: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{305A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U305B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{305C}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U305D: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{305E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U305F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3060}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3061: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3062}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3064: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3065}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3066: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3067}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3068: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3069}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U306F: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3070}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{3071}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3072: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3073}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{3074}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3075: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3076}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{3077}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U3078: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{3079}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{307A}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U307B: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{307C}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{307D}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U309D: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{309E}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30A6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30F4}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30AB: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30AC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30AD: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30AE}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30AF: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30B0}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30B1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30B2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30B3: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30B4}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30B5: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30B6}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30B7: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30B8}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30B9: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30BA}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30BB: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30BC}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30BD: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30BE}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30BF: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30C0}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30C1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30C2}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30C4: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30C5}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30C6: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30C7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30C8: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30C9}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30CF: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30D0}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{30D1}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30D2: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30D3}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{30D4}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30D5: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30D6}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{30D7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30D8: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30D9}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{30DA}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30DB: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30DC}'), None)),
+        ('\u{309A}', ReverseTreeNode::new(Some('\u{30DD}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30EF: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30F7}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30F0: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30F8}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30F1: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30F9}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30F2: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30FA}'), None)),
+    ],
+    buckets: &[],
+};
+
+const U30FD: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{3099}', ReverseTreeNode::new(Some('\u{30FE}'), None)),
+    ],
+    buckets: &[],
+};
+
+/// Dictionary (map) from decomposed components to sub dictionaries and composed characters
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(MAP_TO_NORMAL.get(&'e').unwrap().next.unwrap().get(&'\u{0301}').unwrap().current.unwrap(), '\u{00E9}');
+/// ```
+pub static MAP_TO_NORMAL: CharMap<ReverseTreeNode> = CharMap {
+    entries: &[
+        ('\u{0041}', ReverseTreeNode::new(None, Some(U0041))),
+        ('\u{0042}', ReverseTreeNode::new(None, Some(U0042))),
+        ('\u{0043}', ReverseTreeNode::new(None, Some(U0043))),
+        ('\u{0044}', ReverseTreeNode::new(None, Some(U0044))),
+        ('\u{0045}', ReverseTreeNode::new(None, Some(U0045))),
+        ('\u{0046}', ReverseTreeNode::new(None, Some(U0046))),
+        ('\u{0047}', ReverseTreeNode::new(None, Some(U0047))),
+        ('\u{0048}', ReverseTreeNode::new(None, Some(U0048))),
+        ('\u{0049}', ReverseTreeNode::new(None, Some(U0049))),
+        ('\u{004A}', ReverseTreeNode::new(None, Some(U004A))),
+        ('\u{004B}', ReverseTreeNode::new(None, Some(U004B))),
+        ('\u{004C}', ReverseTreeNode::new(None, Some(U004C))),
+        ('\u{004D}', ReverseTreeNode::new(None, Some(U004D))),
+        ('\u{004E}', ReverseTreeNode::new(None, Some(U004E))),
+        ('\u{004F}', ReverseTreeNode::new(None, Some(U004F))),
+        ('\u{0050}', ReverseTreeNode::new(None, Some(U0050))),
+        ('\u{0052}', ReverseTreeNode::new(None, Some(U0052))),
+        ('\u{0053}', ReverseTreeNode::new(None, Some(U0053))),
+        ('\u{0054}', ReverseTreeNode::new(None, Some(U0054))),
+        ('\u{0055}', ReverseTreeNode::new(None, Some(U0055))),
+        ('\u{0056}', ReverseTreeNode::new(None, Some(U0056))),
+        ('\u{0057}', ReverseTreeNode::new(None, Some(U0057))),
+        ('\u{0058}', ReverseTreeNode::new(None, Some(U0058))),
+        ('\u{0059}', ReverseTreeNode::new(None, Some(U0059))),
+        ('\u{005A}', ReverseTreeNode::new(None, Some(U005A))),
+        ('\u{0061}', ReverseTreeNode::new(None, Some(U0061))),
+        ('\u{0062}', ReverseTreeNode::new(None, Some(U0062))),
+        ('\u{0063}', ReverseTreeNode::new(None, Some(U0063))),
+        ('\u{0064}', ReverseTreeNode::new(None, Some(U0064))),
+        ('\u{0065}', ReverseTreeNode::new(None, Some(U0065))),
+        ('\u{0066}', ReverseTreeNode::new(None, Some(U0066))),
+        ('\u{0067}', ReverseTreeNode::new(None, Some(U0067))),
+        ('\u{0068}', ReverseTreeNode::new(None, Some(U0068))),
+        ('\u{0069}', ReverseTreeNode::new(None, Some(U0069))),
+        ('\u{006A}', ReverseTreeNode::new(None, Some(U006A))),
+        ('\u{006B}', ReverseTreeNode::new(None, Some(U006B))),
+        ('\u{006C}', ReverseTreeNode::new(None, Some(U006C))),
+        ('\u{006D}', ReverseTreeNode::new(None, Some(U006D))),
+        ('\u{006E}', ReverseTreeNode::new(None, Some(U006E))),
+        ('\u{006F}', ReverseTreeNode::new(None, Some(U006F))),
+        ('\u{0070}', ReverseTreeNode::new(None, Some(U0070))),
+        ('\u{0072}', ReverseTreeNode::new(None, Some(U0072))),
+        ('\u{0073}', ReverseTreeNode::new(None, Some(U0073))),
+        ('\u{0074}', ReverseTreeNode::new(None, Some(U0074))),
+        ('\u{0075}', ReverseTreeNode::new(None, Some(U0075))),
+        ('\u{0076}', ReverseTreeNode::new(None, Some(U0076))),
+        ('\u{0077}', ReverseTreeNode::new(None, Some(U0077))),
+        ('\u{0078}', ReverseTreeNode::new(None, Some(U0078))),
+        ('\u{0079}', ReverseTreeNode::new(None, Some(U0079))),
+        ('\u{007A}', ReverseTreeNode::new(None, Some(U007A))),
+        ('\u{00A8}', ReverseTreeNode::new(None, Some(U00A8))),
+        ('\u{00C6}', ReverseTreeNode::new(None, Some(U00C6))),
+        ('\u{00D8}', ReverseTreeNode::new(None, Some(U00D8))),
+        ('\u{00E6}', ReverseTreeNode::new(None, Some(U00E6))),
+        ('\u{00F8}', ReverseTreeNode::new(None, Some(U00F8))),
+        ('\u{017F}', ReverseTreeNode::new(None, Some(U017F))),
+        ('\u{018F}', ReverseTreeNode::new(None, Some(U018F))),
+        ('\u{019F}', ReverseTreeNode::new(None, Some(U019F))),
+        ('\u{01B7}', ReverseTreeNode::new(None, Some(U01B7))),
+        ('\u{0259}', ReverseTreeNode::new(None, Some(U0259))),
+        ('\u{0275}', ReverseTreeNode::new(None, Some(U0275))),
+        ('\u{0292}', ReverseTreeNode::new(None, Some(U0292))),
+        ('\u{0306}', ReverseTreeNode::new(None, Some(U0306))),
+        ('\u{0308}', ReverseTreeNode::new(None, Some(U0308))),
+        ('\u{0391}', ReverseTreeNode::new(None, Some(U0391))),
+        ('\u{0395}', ReverseTreeNode::new(None, Some(U0395))),
+        ('\u{0397}', ReverseTreeNode::new(None, Some(U0397))),
+        ('\u{0399}', ReverseTreeNode::new(None, Some(U0399))),
+        ('\u{039F}', ReverseTreeNode::new(None, Some(U039F))),
+        ('\u{03A1}', ReverseTreeNode::new(None, Some(U03A1))),
+        ('\u{03A5}', ReverseTreeNode::new(None, Some(U03A5))),
+        ('\u{03A9}', ReverseTreeNode::new(None, Some(U03A9))),
+        ('\u{03B1}', ReverseTreeNode::new(None, Some(U03B1))),
+        ('\u{03B5}', ReverseTreeNode::new(None, Some(U03B5))),
+        ('\u{03B7}', ReverseTreeNode::new(None, Some(U03B7))),
+        ('\u{03B9}', ReverseTreeNode::new(None, Some(U03B9))),
+        ('\u{03BF}', ReverseTreeNode::new(None, Some(U03BF))),
+        ('\u{03C1}', ReverseTreeNode::new(None, Some(U03C1))),
+        ('\u{03C5}', ReverseTreeNode::new(None, Some(U03C5))),
+        ('\u{03C9}', ReverseTreeNode::new(None, Some(U03C9))),
+        ('\u{03D2}', ReverseTreeNode::new(None, Some(U03D2))),
+        ('\u{0406}', ReverseTreeNode::new(None, Some(U0406))),
+        ('\u{0410}', ReverseTreeNode::new(None, Some(U0410))),
+        ('\u{0413}', ReverseTreeNode::new(None, Some(U0413))),
+        ('\u{0415}', ReverseTreeNode::new(None, Some(U0415))),
+        ('\u{0416}', ReverseTreeNode::new(None, Some(U0416))),
+        ('\u{0417}', ReverseTreeNode::new(None, Some(U0417))),
+        ('\u{0418}', ReverseTreeNode::new(None, Some(U0418))),
+        ('\u{041A}', ReverseTreeNode::new(None, Some(U041A))),
+        ('\u{041E}', ReverseTreeNode::new(None, Some(U041E))),
+        ('\u{0423}', ReverseTreeNode::new(None, Some(U0423))),
+        ('\u{0427}', ReverseTreeNode::new(None, Some(U0427))),
+        ('\u{042B}', ReverseTreeNode::new(None, Some(U042B))),
+        ('\u{0430}', ReverseTreeNode::new(None, Some(U0430))),
+        ('\u{0433}', ReverseTreeNode::new(None, Some(U0433))),
+        ('\u{0435}', ReverseTreeNode::new(None, Some(U0435))),
+        ('\u{0436}', ReverseTreeNode::new(None, Some(U0436))),
+        ('\u{0437}', ReverseTreeNode::new(None, Some(U0437))),
+        ('\u{0438}', ReverseTreeNode::new(None, Some(U0438))),
+        ('\u{043A}', ReverseTreeNode::new(None, Some(U043A))),
+        ('\u{043E}', ReverseTreeNode::new(None, Some(U043E))),
+        ('\u{0443}', ReverseTreeNode::new(None, Some(U0443))),
+        ('\u{0447}', ReverseTreeNode::new(None, Some(U0447))),
+        ('\u{044B}', ReverseTreeNode::new(None, Some(U044B))),
+        ('\u{0456}', ReverseTreeNode::new(None, Some(U0456))),
+        ('\u{0474}', ReverseTreeNode::new(None, Some(U0474))),
+        ('\u{0475}', ReverseTreeNode::new(None, Some(U0475))),
+        ('\u{05D0}', ReverseTreeNode::new(None, Some(U05D0))),
+        ('\u{05D1}', ReverseTreeNode::new(None, Some(U05D1))),
+        ('\u{05D2}', ReverseTreeNode::new(None, Some(U05D2))),
+        ('\u{05D3}', ReverseTreeNode::new(None, Some(U05D3))),
+        ('\u{05D4}', ReverseTreeNode::new(None, Some(U05D4))),
+        ('\u{05D5}', ReverseTreeNode::new(None, Some(U05D5))),
+        ('\u{05D6}', ReverseTreeNode::new(None, Some(U05D6))),
+        ('\u{05D8}', ReverseTreeNode::new(None, Some(U05D8))),
+        ('\u{05D9}', ReverseTreeNode::new(None, Some(U05D9))),
+        ('\u{05DA}', ReverseTreeNode::new(None, Some(U05DA))),
+        ('\u{05DB}', ReverseTreeNode::new(None, Some(U05DB))),
+        ('\u{05DC}', ReverseTreeNode::new(None, Some(U05DC))),
+        ('\u{05DE}', ReverseTreeNode::new(None, Some(U05DE))),
+        ('\u{05E0}', ReverseTreeNode::new(None, Some(U05E0))),
+        ('\u{05E1}', ReverseTreeNode::new(None, Some(U05E1))),
+        ('\u{05E3}', ReverseTreeNode::new(None, Some(U05E3))),
+        ('\u{05E4}', ReverseTreeNode::new(None, Some(U05E4))),
+        ('\u{05E6}', ReverseTreeNode::new(None, Some(U05E6))),
+        ('\u{05E7}', ReverseTreeNode::new(None, Some(U05E7))),
+        ('\u{05E8}', ReverseTreeNode::new(None, Some(U05E8))),
+        ('\u{05E9}', ReverseTreeNode::new(None, Some(U05E9))),
+        ('\u{05EA}', ReverseTreeNode::new(None, Some(U05EA))),
+        ('\u{05F2}', ReverseTreeNode::new(None, Some(U05F2))),
+        ('\u{0915}', ReverseTreeNode::new(None, Some(U0915))),
+        ('\u{0916}', ReverseTreeNode::new(None, Some(U0916))),
+        ('\u{0917}', ReverseTreeNode::new(None, Some(U0917))),
+        ('\u{091C}', ReverseTreeNode::new(None, Some(U091C))),
+        ('\u{0921}', ReverseTreeNode::new(None, Some(U0921))),
+        ('\u{0922}', ReverseTreeNode::new(None, Some(U0922))),
+        ('\u{0928}', ReverseTreeNode::new(None, Some(U0928))),
+        ('\u{092B}', ReverseTreeNode::new(None, Some(U092B))),
+        ('\u{092F}', ReverseTreeNode::new(None, Some(U092F))),
+        ('\u{0930}', ReverseTreeNode::new(None, Some(U0930))),
+        ('\u{0933}', ReverseTreeNode::new(None, Some(U0933))),
+        ('\u{09A1}', ReverseTreeNode::new(None, Some(U09A1))),
+        ('\u{09A2}', ReverseTreeNode::new(None, Some(U09A2))),
+        ('\u{09AC}', ReverseTreeNode::new(None, Some(U09AC))),
+        ('\u{09AF}', ReverseTreeNode::new(None, Some(U09AF))),
+        ('\u{09C7}', ReverseTreeNode::new(None, Some(U09C7))),
+        ('\u{0A16}', ReverseTreeNode::new(None, Some(U0A16))),
+        ('\u{0A17}', ReverseTreeNode::new(None, Some(U0A17))),
+        ('\u{0A1C}', ReverseTreeNode::new(None, Some(U0A1C))),
+        ('\u{0A21}', ReverseTreeNode::new(None, Some(U0A21))),
+        ('\u{0A2B}', ReverseTreeNode::new(None, Some(U0A2B))),
+        ('\u{0B21}', ReverseTreeNode::new(None, Some(U0B21))),
+        ('\u{0B22}', ReverseTreeNode::new(None, Some(U0B22))),
+        ('\u{0B2F}', ReverseTreeNode::new(None, Some(U0B2F))),
+        ('\u{0B47}', ReverseTreeNode::new(None, Some(U0B47))),
+        ('\u{0B92}', ReverseTreeNode::new(None, Some(U0B92))),
+        ('\u{0BC6}', ReverseTreeNode::new(None, Some(U0BC6))),
+        ('\u{0BC7}', ReverseTreeNode::new(None, Some(U0BC7))),
+        ('\u{0C46}', ReverseTreeNode::new(None, Some(U0C46))),
+        ('\u{0CBF}', ReverseTreeNode::new(None, Some(U0CBF))),
+        ('\u{0CC6}', ReverseTreeNode::new(None, Some(U0CC6))),
+        ('\u{0D46}', ReverseTreeNode::new(None, Some(U0D46))),
+        ('\u{0D47}', ReverseTreeNode::new(None, Some(U0D47))),
+        ('\u{0E4D}', ReverseTreeNode::new(None, Some(U0E4D))),
+        ('\u{0ECD}', ReverseTreeNode::new(None, Some(U0ECD))),
+        ('\u{0F40}', ReverseTreeNode::new(None, Some(U0F40))),
+        ('\u{0F42}', ReverseTreeNode::new(None, Some(U0F42))),
+        ('\u{0F4C}', ReverseTreeNode::new(None, Some(U0F4C))),
+        ('\u{0F51}', ReverseTreeNode::new(None, Some(U0F51))),
+        ('\u{0F56}', ReverseTreeNode::new(None, Some(U0F56))),
+        ('\u{0F5B}', ReverseTreeNode::new(None, Some(U0F5B))),
+        ('\u{0F72}', ReverseTreeNode::new(None, Some(U0F72))),
+        ('\u{0F74}', ReverseTreeNode::new(None, Some(U0F74))),
+        ('\u{0F80}', ReverseTreeNode::new(None, Some(U0F80))),
+        ('\u{0F90}', ReverseTreeNode::new(None, Some(U0F90))),
+        ('\u{0F92}', ReverseTreeNode::new(None, Some(U0F92))),
+        ('\u{0F9C}', ReverseTreeNode::new(None, Some(U0F9C))),
+        ('\u{0FA1}', ReverseTreeNode::new(None, Some(U0FA1))),
+        ('\u{0FA6}', ReverseTreeNode::new(None, Some(U0FA6))),
+        ('\u{0FAB}', ReverseTreeNode::new(None, Some(U0FAB))),
+        ('\u{0FB2}', ReverseTreeNode::new(None, Some(U0FB2))),
+        ('\u{0FB3}', ReverseTreeNode::new(None, Some(U0FB3))),
+        ('\u{1FBF}', ReverseTreeNode::new(None, Some(U1FBF))),
+        ('\u{1FFE}', ReverseTreeNode::new(None, Some(U1FFE))),
+        ('\u{3046}', ReverseTreeNode::new(None, Some(U3046))),
+        ('\u{304B}', ReverseTreeNode::new(None, Some(U304B))),
+        ('\u{304D}', ReverseTreeNode::new(None, Some(U304D))),
+        ('\u{304F}', ReverseTreeNode::new(None, Some(U304F))),
+        ('\u{3051}', ReverseTreeNode::new(None, Some(U3051))),
+        ('\u{3053}', ReverseTreeNode::new(None, Some(U3053))),
+        ('\u{3055}', ReverseTreeNode::new(None, Some(U3055))),
+        ('\u{3057}', ReverseTreeNode::new(None, Some(U3057))),
+        ('\u{3059}', ReverseTreeNode::new(None, Some(U3059))),
+        ('\u{305B}', ReverseTreeNode::new(None, Some(U305B))),
+        ('\u{305D}', ReverseTreeNode::new(None, Some(U305D))),
+        ('\u{305F}', ReverseTreeNode::new(None, Some(U305F))),
+        ('\u{3061}', ReverseTreeNode::new(None, Some(U3061))),
+        ('\u{3064}', ReverseTreeNode::new(None, Some(U3064))),
+        ('\u{3066}', ReverseTreeNode::new(None, Some(U3066))),
+        ('\u{3068}', ReverseTreeNode::new(None, Some(U3068))),
+        ('\u{306F}', ReverseTreeNode::new(None, Some(U306F))),
+        ('\u{3072}', ReverseTreeNode::new(None, Some(U3072))),
+        ('\u{3075}', ReverseTreeNode::new(None, Some(U3075))),
+        ('\u{3078}', ReverseTreeNode::new(None, Some(U3078))),
+        ('\u{307B}', ReverseTreeNode::new(None, Some(U307B))),
+        ('\u{309D}', ReverseTreeNode::new(None, Some(U309D))),
+        ('\u{30A6}', ReverseTreeNode::new(None, Some(U30A6))),
+        ('\u{30AB}', ReverseTreeNode::new(None, Some(U30AB))),
+        ('\u{30AD}', ReverseTreeNode::new(None, Some(U30AD))),
+        ('\u{30AF}', ReverseTreeNode::new(None, Some(U30AF))),
+        ('\u{30B1}', ReverseTreeNode::new(None, Some(U30B1))),
+        ('\u{30B3}', ReverseTreeNode::new(None, Some(U30B3))),
+        ('\u{30B5}', ReverseTreeNode::new(None, Some(U30B5))),
+        ('\u{30B7}', ReverseTreeNode::new(None, Some(U30B7))),
+        ('\u{30B9}', ReverseTreeNode::new(None, Some(U30B9))),
+        ('\u{30BB}', ReverseTreeNode::new(None, Some(U30BB))),
+        ('\u{30BD}', ReverseTreeNode::new(None, Some(U30BD))),
+        ('\u{30BF}', ReverseTreeNode::new(None, Some(U30BF))),
+        ('\u{30C1}', ReverseTreeNode::new(None, Some(U30C1))),
+        ('\u{30C4}', ReverseTreeNode::new(None, Some(U30C4))),
+        ('\u{30C6}', ReverseTreeNode::new(None, Some(U30C6))),
+        ('\u{30C8}', ReverseTreeNode::new(None, Some(U30C8))),
+        ('\u{30CF}', ReverseTreeNode::new(None, Some(U30CF))),
+        ('\u{30D2}', ReverseTreeNode::new(None, Some(U30D2))),
+        ('\u{30D5}', ReverseTreeNode::new(None, Some(U30D5))),
+        ('\u{30D8}', ReverseTreeNode::new(None, Some(U30D8))),
+        ('\u{30DB}', ReverseTreeNode::new(None, Some(U30DB))),
+        ('\u{30EF}', ReverseTreeNode::new(None, Some(U30EF))),
+        ('\u{30F0}', ReverseTreeNode::new(None, Some(U30F0))),
+        ('\u{30F1}', ReverseTreeNode::new(None, Some(U30F1))),
+        ('\u{30F2}', ReverseTreeNode::new(None, Some(U30F2))),
+        ('\u{30FD}', ReverseTreeNode::new(None, Some(U30FD))),
+    ],
+    buckets: &[
+        0, 0, 0, 25, 50, 50, 51, 53, 55, 55, 55, 55, 56, 58, 59, 59,
+        59, 59, 59, 60, 61, 62, 62, 62, 62, 64, 64, 64, 64, 69, 77, 81,
+        81, 90, 101, 105, 107, 107, 107, 107, 107, 107, 107, 107, 107, 107, 107, 120,
+        130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 130, 130,
+        130, 130, 130, 130, 130, 130, 130, 130, 130, 134, 141, 141, 141, 141, 145, 146,
+        146, 149, 151, 151, 151, 151, 151, 151, 151, 151, 154, 155, 155, 156, 156, 158,
+        158, 158, 158, 159, 159, 159, 160, 161, 161, 161, 161, 163, 163, 163, 163, 163,
+        163, 163, 163, 164, 164, 164, 164, 165, 165, 165, 165, 171, 173, 177, 182, 182,
+        182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182,
+        182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182,
+        182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182,
+        182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182,
+        182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182,
+        182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182,
+        182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182,
+        182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 182, 183, 183,
+        184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        184, 184, 184, 196, 205, 206, 218, 227, 232,
+    ],
+};
+
+/// Characters following the first one in the decompositions (i.e. combining marks)
+///
+/// Sorted so that it can be binary-searched.
+pub static TRAILING_COMPONENTS: &[char] = &[
+    '\u{0300}',
+    '\u{0301}',
+    '\u{0302}',
+    '\u{0303}',
+    '\u{0304}',
+    '\u{0306}',
+    '\u{0307}',
+    '\u{0308}',
+    '\u{0309}',
+    '\u{030A}',
+    '\u{030B}',
+    '\u{030C}',
+    '\u{030D}',
+    '\u{030F}',
+    '\u{0311}',
+    '\u{0313}',
+    '\u{0314}',
+    '\u{031B}',
+    '\u{0323}',
+    '\u{0324}',
+    '\u{0325}',
+    '\u{0327}',
+    '\u{0328}',
+    '\u{032D}',
+    '\u{032E}',
+    '\u{0330}',
+    '\u{0331}',
+    '\u{0342}',
+    '\u{0345}',
+    '\u{05B7}',
+    '\u{05B8}',
+    '\u{05B9}',
+    '\u{05BC}',
+    '\u{05BF}',
+    '\u{05C1}',
+    '\u{05C2}',
+    '\u{093C}',
+    '\u{09BC}',
+    '\u{09BE}',
+    '\u{09D7}',
+    '\u{0A3C}',
+    '\u{0B3C}',
+    '\u{0B3E}',
+    '\u{0B56}',
+    '\u{0B57}',
+    '\u{0BBE}',
+    '\u{0BD7}',
+    '\u{0C56}',
+    '\u{0CC2}',
+    '\u{0CD5}',
+    '\u{0CD6}',
+    '\u{0D3E}',
+    '\u{0D57}',
+    '\u{0E32}',
+    '\u{0EB2}',
+    '\u{0F71}',
+    '\u{0F80}',
+    '\u{0FB5}',
+    '\u{0FB7}',
+    '\u{3099}',
+    '\u{309A}',
+];
@@ -22,14 +22,14 @@ mod test {
     /// # Panics
     /// When test is failed
     fn try_compose(src: &str, target: char) {
-        let mut sub_dic = &*MAP_TO_NORMAL;
+        let mut sub_dic = MAP_TO_NORMAL;
         for element in src.chars().take(src.chars().count() - 1) {
             match sub_dic.get(&element) {
                 Some(ReverseTreeNode {
                     current: _,
                     next: Some(next),
                 }) => {
-                    sub_dic = next;
+                    sub_dic = *next;
                 }
                 Some(_) => {
                     panic!("No next sub-dictionary for U+{:04X} (target: `{}` aka U+{:04X}) in the entry in reverse dictionary",
@@ -46,22 +46,12 @@ mod test {
         let last_element = src.chars().last().unwrap();
         let generated = sub_dic
             .get(&last_element)
-            .expect(
-                format!(
-                    "No entry for U+{:04X} (target: `{}` aka U+{:04X}) in the reverse dictionary",
-                    last_element as u32, target, target as u32
-                )
-                .as_str(),
-            )
+            .unwrap_or_else(|| panic!("No entry for U+{:04X} (target: `{}` aka U+{:04X}) in the reverse dictionary",
+                    last_element as u32, target, target as u32))
             .current
-            .expect(
-                format!(
-            "No next sub-dictionary for U+{:04x} (target: `{}` aka U+{:04X}) in the entry in reverse dictionary",
+            .unwrap_or_else(|| panic!("No next sub-dictionary for U+{:04x} (target: `{}` aka U+{:04X}) in the entry in reverse dictionary",
             last_element as u32,
-            target, target as u32
-        )
-                .as_str(),
-            );
+            target, target as u32));
         assert_eq!(
             generated, target,
             "Composed character different: source: `{}` / target: `{}` (U+{:04X}) / actual: `{}` (U+{:04X})",
@@ -97,15 +87,15 @@ mod test {
     }
     #[test]
     fn hfs_to_normal_all() {
-        for (composed, target) in (*MAP_TO_HFS).iter() {
-            try_decompose(*composed, *target);
+        for (composed, target) in MAP_TO_HFS {
+            try_decompose(*composed, target);
         }
     }
 
     #[test]
     fn normal_to_hfs_all() {
         let mut queue = VecDeque::new();
-        for (ch_ref, node_ref) in &*MAP_TO_NORMAL {
+        for (ch_ref, node_ref) in MAP_TO_NORMAL {
             queue.push_back((ch_ref.to_string(), node_ref));
         }
         while let Some((decomposed_str, node_ref)) = queue.pop_back() {
@@ -113,7 +103,7 @@ mod test {
                 try_compose(&decomposed_str, target);
             }
             if let Some(next_dic) = &node_ref.next {
-                for (ch_ref, node_ref) in next_dic.as_ref() {
+                for (ch_ref, node_ref) in *next_dic {
                     let mut s = decomposed_str.clone();
                     s.push(*ch_ref);
                     queue.push_back((s, node_ref));
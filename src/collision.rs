@@ -0,0 +1,142 @@
+//! Detection of sibling names which HFS+ regards as the same name
+//!
+//! e.g. a Linux directory can contain both `café` in NFC and in NFD, but only one of them survives when the directory is
+//! copied to a Mac.
+
+use super::decompose_into_hfs_nfd;
+use super::ignorable::{strip_hfs_ignorables, IgnorablePolicy};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Names which become identical after [`decompose_into_hfs_nfd`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollisionGroup<T> {
+    /// The name shared by the group after the decomposition
+    pub hfs_name: String,
+    /// The original names, in the order they are given
+    pub names: Vec<T>,
+}
+
+/// Groups the names which become identical after [`decompose_into_hfs_nfd`]
+///
+/// Only the groups with two or more names are returned, in the order of their first names.
+///
+/// # Arguments
+///
+/// * `names` - Names of the entries in a directory
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::find_hfs_collisions;
+/// let groups = find_hfs_collisions(vec!["caf\u{00E9}", "README", "cafe\u{0301}"]);
+/// assert_eq!(groups.len(), 1);
+/// assert_eq!(groups[0].hfs_name, "cafe\u{0301}");
+/// assert_eq!(groups[0].names, vec!["caf\u{00E9}", "cafe\u{0301}"]);
+/// ```
+pub fn find_hfs_collisions<T, I>(names: I) -> Vec<CollisionGroup<T>>
+where
+    T: AsRef<str>,
+    I: IntoIterator<Item = T>,
+{
+    find_hfs_collisions_with_policy(names, IgnorablePolicy::Keep)
+}
+
+/// Groups the names which become identical after [`decompose_into_hfs_nfd`], handling the ignorable code points
+/// according to `policy`
+///
+/// With [`IgnorablePolicy::Strip`], the ignorable code points are removed from [`CollisionGroup::hfs_name`] too.
+///
+/// # Arguments
+///
+/// * `names` - Names of the entries in a directory
+/// * `policy` - How the ignorable code points are handled
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{find_hfs_collisions_with_policy, IgnorablePolicy};
+/// let names = vec!["caf\u{00E9}\u{200E}", "cafe\u{0301}"];
+/// assert!(find_hfs_collisions_with_policy(names.clone(), IgnorablePolicy::Keep).is_empty());
+/// let groups = find_hfs_collisions_with_policy(names, IgnorablePolicy::Strip);
+/// assert_eq!(groups[0].hfs_name, "cafe\u{0301}");
+/// ```
+pub fn find_hfs_collisions_with_policy<T, I>(
+    names: I,
+    policy: IgnorablePolicy,
+) -> Vec<CollisionGroup<T>>
+where
+    T: AsRef<str>,
+    I: IntoIterator<Item = T>,
+{
+    let mut groups = Vec::<CollisionGroup<T>>::new();
+    let mut indices = BTreeMap::<String, usize>::new();
+    for name in names {
+        let mut hfs_name = decompose_into_hfs_nfd(name.as_ref());
+        if policy == IgnorablePolicy::Strip {
+            hfs_name = strip_hfs_ignorables(&hfs_name).into_owned();
+        }
+        match indices.get(&hfs_name) {
+            Some(&index) => groups[index].names.push(name),
+            None => {
+                indices.insert(hfs_name.clone(), groups.len());
+                groups.push(CollisionGroup {
+                    hfs_name,
+                    names: vec![name],
+                });
+            }
+        }
+    }
+    groups.retain(|group| group.names.len() > 1);
+    groups
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn collision_test() {
+        let names = vec![
+            "Pok\u{00E9}mon".to_string(),
+            "\u{D55C}".to_string(),
+            "e\u{0323}\u{0302}".to_string(),
+            "Poke\u{0301}mon".to_string(),
+            "\u{1112}\u{1161}\u{11AB}".to_string(),
+            "\u{1EC7}".to_string(),
+            "e\u{0302}\u{0323}".to_string(),
+            "Pikachu".to_string(),
+        ];
+        let groups = find_hfs_collisions(&names);
+        assert_eq!(
+            groups,
+            vec![
+                CollisionGroup {
+                    hfs_name: "Poke\u{0301}mon".to_string(),
+                    names: vec![&names[0], &names[3]],
+                },
+                CollisionGroup {
+                    hfs_name: "\u{1112}\u{1161}\u{11AB}".to_string(),
+                    names: vec![&names[1], &names[4]],
+                },
+                CollisionGroup {
+                    hfs_name: "e\u{0323}\u{0302}".to_string(),
+                    names: vec![&names[2], &names[5], &names[6]],
+                },
+            ]
+        );
+        assert!(find_hfs_collisions(vec!["a", "b", "A"]).is_empty());
+        assert_eq!(
+            find_hfs_collisions_with_policy(
+                vec!["\u{FEFF}a", "b", "a\u{202C}"],
+                IgnorablePolicy::Strip
+            ),
+            vec![CollisionGroup {
+                hfs_name: "a".to_string(),
+                names: vec!["\u{FEFF}a", "a\u{202C}"],
+            }]
+        );
+    }
+}
@@ -0,0 +1,1742 @@
+//! Definition of the Unicode canonical combining class tables
+//!
+//! Generated based on the Unicode Character Database 14.0.0 and 3.2.0
+
+use super::reverse_tree::CharMap;
+
+/// Map from code points to their non-zero canonical combining classes
+///
+/// Most code points (e.g. kana and CJK ideographs) fall in empty buckets, so they are looked up without searching.
+pub static COMBINING_CLASS_TABLE: CharMap<u8> = CharMap {
+    entries: &[
+        ('\u{0300}', 230),
+        ('\u{0301}', 230),
+        ('\u{0302}', 230),
+        ('\u{0303}', 230),
+        ('\u{0304}', 230),
+        ('\u{0305}', 230),
+        ('\u{0306}', 230),
+        ('\u{0307}', 230),
+        ('\u{0308}', 230),
+        ('\u{0309}', 230),
+        ('\u{030A}', 230),
+        ('\u{030B}', 230),
+        ('\u{030C}', 230),
+        ('\u{030D}', 230),
+        ('\u{030E}', 230),
+        ('\u{030F}', 230),
+        ('\u{0310}', 230),
+        ('\u{0311}', 230),
+        ('\u{0312}', 230),
+        ('\u{0313}', 230),
+        ('\u{0314}', 230),
+        ('\u{0315}', 232),
+        ('\u{0316}', 220),
+        ('\u{0317}', 220),
+        ('\u{0318}', 220),
+        ('\u{0319}', 220),
+        ('\u{031A}', 232),
+        ('\u{031B}', 216),
+        ('\u{031C}', 220),
+        ('\u{031D}', 220),
+        ('\u{031E}', 220),
+        ('\u{031F}', 220),
+        ('\u{0320}', 220),
+        ('\u{0321}', 202),
+        ('\u{0322}', 202),
+        ('\u{0323}', 220),
+        ('\u{0324}', 220),
+        ('\u{0325}', 220),
+        ('\u{0326}', 220),
+        ('\u{0327}', 202),
+        ('\u{0328}', 202),
+        ('\u{0329}', 220),
+        ('\u{032A}', 220),
+        ('\u{032B}', 220),
+        ('\u{032C}', 220),
+        ('\u{032D}', 220),
+        ('\u{032E}', 220),
+        ('\u{032F}', 220),
+        ('\u{0330}', 220),
+        ('\u{0331}', 220),
+        ('\u{0332}', 220),
+        ('\u{0333}', 220),
+        ('\u{0334}', 1),
+        ('\u{0335}', 1),
+        ('\u{0336}', 1),
+        ('\u{0337}', 1),
+        ('\u{0338}', 1),
+        ('\u{0339}', 220),
+        ('\u{033A}', 220),
+        ('\u{033B}', 220),
+        ('\u{033C}', 220),
+        ('\u{033D}', 230),
+        ('\u{033E}', 230),
+        ('\u{033F}', 230),
+        ('\u{0340}', 230),
+        ('\u{0341}', 230),
+        ('\u{0342}', 230),
+        ('\u{0343}', 230),
+        ('\u{0344}', 230),
+        ('\u{0345}', 240),
+        ('\u{0346}', 230),
+        ('\u{0347}', 220),
+        ('\u{0348}', 220),
+        ('\u{0349}', 220),
+        ('\u{034A}', 230),
+        ('\u{034B}', 230),
+        ('\u{034C}', 230),
+        ('\u{034D}', 220),
+        ('\u{034E}', 220),
+        ('\u{0350}', 230),
+        ('\u{0351}', 230),
+        ('\u{0352}', 230),
+        ('\u{0353}', 220),
+        ('\u{0354}', 220),
+        ('\u{0355}', 220),
+        ('\u{0356}', 220),
+        ('\u{0357}', 230),
+        ('\u{0358}', 232),
+        ('\u{0359}', 220),
+        ('\u{035A}', 220),
+        ('\u{035B}', 230),
+        ('\u{035C}', 233),
+        ('\u{035D}', 234),
+        ('\u{035E}', 234),
+        ('\u{035F}', 233),
+        ('\u{0360}', 234),
+        ('\u{0361}', 234),
+        ('\u{0362}', 233),
+        ('\u{0363}', 230),
+        ('\u{0364}', 230),
+        ('\u{0365}', 230),
+        ('\u{0366}', 230),
+        ('\u{0367}', 230),
+        ('\u{0368}', 230),
+        ('\u{0369}', 230),
+        ('\u{036A}', 230),
+        ('\u{036B}', 230),
+        ('\u{036C}', 230),
+        ('\u{036D}', 230),
+        ('\u{036E}', 230),
+        ('\u{036F}', 230),
+        ('\u{0483}', 230),
+        ('\u{0484}', 230),
+        ('\u{0485}', 230),
+        ('\u{0486}', 230),
+        ('\u{0487}', 230),
+        ('\u{0591}', 220),
+        ('\u{0592}', 230),
+        ('\u{0593}', 230),
+        ('\u{0594}', 230),
+        ('\u{0595}', 230),
+        ('\u{0596}', 220),
+        ('\u{0597}', 230),
+        ('\u{0598}', 230),
+        ('\u{0599}', 230),
+        ('\u{059A}', 222),
+        ('\u{059B}', 220),
+        ('\u{059C}', 230),
+        ('\u{059D}', 230),
+        ('\u{059E}', 230),
+        ('\u{059F}', 230),
+        ('\u{05A0}', 230),
+        ('\u{05A1}', 230),
+        ('\u{05A2}', 220),
+        ('\u{05A3}', 220),
+        ('\u{05A4}', 220),
+        ('\u{05A5}', 220),
+        ('\u{05A6}', 220),
+        ('\u{05A7}', 220),
+        ('\u{05A8}', 230),
+        ('\u{05A9}', 230),
+        ('\u{05AA}', 220),
+        ('\u{05AB}', 230),
+        ('\u{05AC}', 230),
+        ('\u{05AD}', 222),
+        ('\u{05AE}', 228),
+        ('\u{05AF}', 230),
+        ('\u{05B0}', 10),
+        ('\u{05B1}', 11),
+        ('\u{05B2}', 12),
+        ('\u{05B3}', 13),
+        ('\u{05B4}', 14),
+        ('\u{05B5}', 15),
+        ('\u{05B6}', 16),
+        ('\u{05B7}', 17),
+        ('\u{05B8}', 18),
+        ('\u{05B9}', 19),
+        ('\u{05BA}', 19),
+        ('\u{05BB}', 20),
+        ('\u{05BC}', 21),
+        ('\u{05BD}', 22),
+        ('\u{05BF}', 23),
+        ('\u{05C1}', 24),
+        ('\u{05C2}', 25),
+        ('\u{05C4}', 230),
+        ('\u{05C5}', 220),
+        ('\u{05C7}', 18),
+        ('\u{0610}', 230),
+        ('\u{0611}', 230),
+        ('\u{0612}', 230),
+        ('\u{0613}', 230),
+        ('\u{0614}', 230),
+        ('\u{0615}', 230),
+        ('\u{0616}', 230),
+        ('\u{0617}', 230),
+        ('\u{0618}', 30),
+        ('\u{0619}', 31),
+        ('\u{061A}', 32),
+        ('\u{064B}', 27),
+        ('\u{064C}', 28),
+        ('\u{064D}', 29),
+        ('\u{064E}', 30),
+        ('\u{064F}', 31),
+        ('\u{0650}', 32),
+        ('\u{0651}', 33),
+        ('\u{0652}', 34),
+        ('\u{0653}', 230),
+        ('\u{0654}', 230),
+        ('\u{0655}', 220),
+        ('\u{0656}', 220),
+        ('\u{0657}', 230),
+        ('\u{0658}', 230),
+        ('\u{0659}', 230),
+        ('\u{065A}', 230),
+        ('\u{065B}', 230),
+        ('\u{065C}', 220),
+        ('\u{065D}', 230),
+        ('\u{065E}', 230),
+        ('\u{065F}', 220),
+        ('\u{0670}', 35),
+        ('\u{06D6}', 230),
+        ('\u{06D7}', 230),
+        ('\u{06D8}', 230),
+        ('\u{06D9}', 230),
+        ('\u{06DA}', 230),
+        ('\u{06DB}', 230),
+        ('\u{06DC}', 230),
+        ('\u{06DF}', 230),
+        ('\u{06E0}', 230),
+        ('\u{06E1}', 230),
+        ('\u{06E2}', 230),
+        ('\u{06E3}', 220),
+        ('\u{06E4}', 230),
+        ('\u{06E7}', 230),
+        ('\u{06E8}', 230),
+        ('\u{06EA}', 220),
+        ('\u{06EB}', 230),
+        ('\u{06EC}', 230),
+        ('\u{06ED}', 220),
+        ('\u{0711}', 36),
+        ('\u{0730}', 230),
+        ('\u{0731}', 220),
+        ('\u{0732}', 230),
+        ('\u{0733}', 230),
+        ('\u{0734}', 220),
+        ('\u{0735}', 230),
+        ('\u{0736}', 230),
+        ('\u{0737}', 220),
+        ('\u{0738}', 220),
+        ('\u{0739}', 220),
+        ('\u{073A}', 230),
+        ('\u{073B}', 220),
+        ('\u{073C}', 220),
+        ('\u{073D}', 230),
+        ('\u{073E}', 220),
+        ('\u{073F}', 230),
+        ('\u{0740}', 230),
+        ('\u{0741}', 230),
+        ('\u{0742}', 220),
+        ('\u{0743}', 230),
+        ('\u{0744}', 220),
+        ('\u{0745}', 230),
+        ('\u{0746}', 220),
+        ('\u{0747}', 230),
+        ('\u{0748}', 220),
+        ('\u{0749}', 230),
+        ('\u{074A}', 230),
+        ('\u{07EB}', 230),
+        ('\u{07EC}', 230),
+        ('\u{07ED}', 230),
+        ('\u{07EE}', 230),
+        ('\u{07EF}', 230),
+        ('\u{07F0}', 230),
+        ('\u{07F1}', 230),
+        ('\u{07F2}', 220),
+        ('\u{07F3}', 230),
+        ('\u{07FD}', 220),
+        ('\u{0816}', 230),
+        ('\u{0817}', 230),
+        ('\u{0818}', 230),
+        ('\u{0819}', 230),
+        ('\u{081B}', 230),
+        ('\u{081C}', 230),
+        ('\u{081D}', 230),
+        ('\u{081E}', 230),
+        ('\u{081F}', 230),
+        ('\u{0820}', 230),
+        ('\u{0821}', 230),
+        ('\u{0822}', 230),
+        ('\u{0823}', 230),
+        ('\u{0825}', 230),
+        ('\u{0826}', 230),
+        ('\u{0827}', 230),
+        ('\u{0829}', 230),
+        ('\u{082A}', 230),
+        ('\u{082B}', 230),
+        ('\u{082C}', 230),
+        ('\u{082D}', 230),
+        ('\u{0859}', 220),
+        ('\u{085A}', 220),
+        ('\u{085B}', 220),
+        ('\u{0898}', 230),
+        ('\u{0899}', 220),
+        ('\u{089A}', 220),
+        ('\u{089B}', 220),
+        ('\u{089C}', 230),
+        ('\u{089D}', 230),
+        ('\u{089E}', 230),
+        ('\u{089F}', 230),
+        ('\u{08CA}', 230),
+        ('\u{08CB}', 230),
+        ('\u{08CC}', 230),
+        ('\u{08CD}', 230),
+        ('\u{08CE}', 230),
+        ('\u{08CF}', 220),
+        ('\u{08D0}', 220),
+        ('\u{08D1}', 220),
+        ('\u{08D2}', 220),
+        ('\u{08D3}', 220),
+        ('\u{08D4}', 230),
+        ('\u{08D5}', 230),
+        ('\u{08D6}', 230),
+        ('\u{08D7}', 230),
+        ('\u{08D8}', 230),
+        ('\u{08D9}', 230),
+        ('\u{08DA}', 230),
+        ('\u{08DB}', 230),
+        ('\u{08DC}', 230),
+        ('\u{08DD}', 230),
+        ('\u{08DE}', 230),
+        ('\u{08DF}', 230),
+        ('\u{08E0}', 230),
+        ('\u{08E1}', 230),
+        ('\u{08E3}', 220),
+        ('\u{08E4}', 230),
+        ('\u{08E5}', 230),
+        ('\u{08E6}', 220),
+        ('\u{08E7}', 230),
+        ('\u{08E8}', 230),
+        ('\u{08E9}', 220),
+        ('\u{08EA}', 230),
+        ('\u{08EB}', 230),
+        ('\u{08EC}', 230),
+        ('\u{08ED}', 220),
+        ('\u{08EE}', 220),
+        ('\u{08EF}', 220),
+        ('\u{08F0}', 27),
+        ('\u{08F1}', 28),
+        ('\u{08F2}', 29),
+        ('\u{08F3}', 230),
+        ('\u{08F4}', 230),
+        ('\u{08F5}', 230),
+        ('\u{08F6}', 220),
+        ('\u{08F7}', 230),
+        ('\u{08F8}', 230),
+        ('\u{08F9}', 220),
+        ('\u{08FA}', 220),
+        ('\u{08FB}', 230),
+        ('\u{08FC}', 230),
+        ('\u{08FD}', 230),
+        ('\u{08FE}', 230),
+        ('\u{08FF}', 230),
+        ('\u{093C}', 7),
+        ('\u{094D}', 9),
+        ('\u{0951}', 230),
+        ('\u{0952}', 220),
+        ('\u{0953}', 230),
+        ('\u{0954}', 230),
+        ('\u{09BC}', 7),
+        ('\u{09CD}', 9),
+        ('\u{09FE}', 230),
+        ('\u{0A3C}', 7),
+        ('\u{0A4D}', 9),
+        ('\u{0ABC}', 7),
+        ('\u{0ACD}', 9),
+        ('\u{0B3C}', 7),
+        ('\u{0B4D}', 9),
+        ('\u{0BCD}', 9),
+        ('\u{0C3C}', 7),
+        ('\u{0C4D}', 9),
+        ('\u{0C55}', 84),
+        ('\u{0C56}', 91),
+        ('\u{0CBC}', 7),
+        ('\u{0CCD}', 9),
+        ('\u{0D3B}', 9),
+        ('\u{0D3C}', 9),
+        ('\u{0D4D}', 9),
+        ('\u{0DCA}', 9),
+        ('\u{0E38}', 103),
+        ('\u{0E39}', 103),
+        ('\u{0E3A}', 9),
+        ('\u{0E48}', 107),
+        ('\u{0E49}', 107),
+        ('\u{0E4A}', 107),
+        ('\u{0E4B}', 107),
+        ('\u{0EB8}', 118),
+        ('\u{0EB9}', 118),
+        ('\u{0EBA}', 9),
+        ('\u{0EC8}', 122),
+        ('\u{0EC9}', 122),
+        ('\u{0ECA}', 122),
+        ('\u{0ECB}', 122),
+        ('\u{0F18}', 220),
+        ('\u{0F19}', 220),
+        ('\u{0F35}', 220),
+        ('\u{0F37}', 220),
+        ('\u{0F39}', 216),
+        ('\u{0F71}', 129),
+        ('\u{0F72}', 130),
+        ('\u{0F74}', 132),
+        ('\u{0F7A}', 130),
+        ('\u{0F7B}', 130),
+        ('\u{0F7C}', 130),
+        ('\u{0F7D}', 130),
+        ('\u{0F80}', 130),
+        ('\u{0F82}', 230),
+        ('\u{0F83}', 230),
+        ('\u{0F84}', 9),
+        ('\u{0F86}', 230),
+        ('\u{0F87}', 230),
+        ('\u{0FC6}', 220),
+        ('\u{1037}', 7),
+        ('\u{1039}', 9),
+        ('\u{103A}', 9),
+        ('\u{108D}', 220),
+        ('\u{135D}', 230),
+        ('\u{135E}', 230),
+        ('\u{135F}', 230),
+        ('\u{1714}', 9),
+        ('\u{1715}', 9),
+        ('\u{1734}', 9),
+        ('\u{17D2}', 9),
+        ('\u{17DD}', 230),
+        ('\u{18A9}', 228),
+        ('\u{1939}', 222),
+        ('\u{193A}', 230),
+        ('\u{193B}', 220),
+        ('\u{1A17}', 230),
+        ('\u{1A18}', 220),
+        ('\u{1A60}', 9),
+        ('\u{1A75}', 230),
+        ('\u{1A76}', 230),
+        ('\u{1A77}', 230),
+        ('\u{1A78}', 230),
+        ('\u{1A79}', 230),
+        ('\u{1A7A}', 230),
+        ('\u{1A7B}', 230),
+        ('\u{1A7C}', 230),
+        ('\u{1A7F}', 220),
+        ('\u{1AB0}', 230),
+        ('\u{1AB1}', 230),
+        ('\u{1AB2}', 230),
+        ('\u{1AB3}', 230),
+        ('\u{1AB4}', 230),
+        ('\u{1AB5}', 220),
+        ('\u{1AB6}', 220),
+        ('\u{1AB7}', 220),
+        ('\u{1AB8}', 220),
+        ('\u{1AB9}', 220),
+        ('\u{1ABA}', 220),
+        ('\u{1ABB}', 230),
+        ('\u{1ABC}', 230),
+        ('\u{1ABD}', 220),
+        ('\u{1ABF}', 220),
+        ('\u{1AC0}', 220),
+        ('\u{1AC1}', 230),
+        ('\u{1AC2}', 230),
+        ('\u{1AC3}', 220),
+        ('\u{1AC4}', 220),
+        ('\u{1AC5}', 230),
+        ('\u{1AC6}', 230),
+        ('\u{1AC7}', 230),
+        ('\u{1AC8}', 230),
+        ('\u{1AC9}', 230),
+        ('\u{1ACA}', 220),
+        ('\u{1ACB}', 230),
+        ('\u{1ACC}', 230),
+        ('\u{1ACD}', 230),
+        ('\u{1ACE}', 230),
+        ('\u{1B34}', 7),
+        ('\u{1B44}', 9),
+        ('\u{1B6B}', 230),
+        ('\u{1B6C}', 220),
+        ('\u{1B6D}', 230),
+        ('\u{1B6E}', 230),
+        ('\u{1B6F}', 230),
+        ('\u{1B70}', 230),
+        ('\u{1B71}', 230),
+        ('\u{1B72}', 230),
+        ('\u{1B73}', 230),
+        ('\u{1BAA}', 9),
+        ('\u{1BAB}', 9),
+        ('\u{1BE6}', 7),
+        ('\u{1BF2}', 9),
+        ('\u{1BF3}', 9),
+        ('\u{1C37}', 7),
+        ('\u{1CD0}', 230),
+        ('\u{1CD1}', 230),
+        ('\u{1CD2}', 230),
+        ('\u{1CD4}', 1),
+        ('\u{1CD5}', 220),
+        ('\u{1CD6}', 220),
+        ('\u{1CD7}', 220),
+        ('\u{1CD8}', 220),
+        ('\u{1CD9}', 220),
+        ('\u{1CDA}', 230),
+        ('\u{1CDB}', 230),
+        ('\u{1CDC}', 220),
+        ('\u{1CDD}', 220),
+        ('\u{1CDE}', 220),
+        ('\u{1CDF}', 220),
+        ('\u{1CE0}', 230),
+        ('\u{1CE2}', 1),
+        ('\u{1CE3}', 1),
+        ('\u{1CE4}', 1),
+        ('\u{1CE5}', 1),
+        ('\u{1CE6}', 1),
+        ('\u{1CE7}', 1),
+        ('\u{1CE8}', 1),
+        ('\u{1CED}', 220),
+        ('\u{1CF4}', 230),
+        ('\u{1CF8}', 230),
+        ('\u{1CF9}', 230),
+        ('\u{1DC0}', 230),
+        ('\u{1DC1}', 230),
+        ('\u{1DC2}', 220),
+        ('\u{1DC3}', 230),
+        ('\u{1DC4}', 230),
+        ('\u{1DC5}', 230),
+        ('\u{1DC6}', 230),
+        ('\u{1DC7}', 230),
+        ('\u{1DC8}', 230),
+        ('\u{1DC9}', 230),
+        ('\u{1DCA}', 220),
+        ('\u{1DCB}', 230),
+        ('\u{1DCC}', 230),
+        ('\u{1DCD}', 234),
+        ('\u{1DCE}', 214),
+        ('\u{1DCF}', 220),
+        ('\u{1DD0}', 202),
+        ('\u{1DD1}', 230),
+        ('\u{1DD2}', 230),
+        ('\u{1DD3}', 230),
+        ('\u{1DD4}', 230),
+        ('\u{1DD5}', 230),
+        ('\u{1DD6}', 230),
+        ('\u{1DD7}', 230),
+        ('\u{1DD8}', 230),
+        ('\u{1DD9}', 230),
+        ('\u{1DDA}', 230),
+        ('\u{1DDB}', 230),
+        ('\u{1DDC}', 230),
+        ('\u{1DDD}', 230),
+        ('\u{1DDE}', 230),
+        ('\u{1DDF}', 230),
+        ('\u{1DE0}', 230),
+        ('\u{1DE1}', 230),
+        ('\u{1DE2}', 230),
+        ('\u{1DE3}', 230),
+        ('\u{1DE4}', 230),
+        ('\u{1DE5}', 230),
+        ('\u{1DE6}', 230),
+        ('\u{1DE7}', 230),
+        ('\u{1DE8}', 230),
+        ('\u{1DE9}', 230),
+        ('\u{1DEA}', 230),
+        ('\u{1DEB}', 230),
+        ('\u{1DEC}', 230),
+        ('\u{1DED}', 230),
+        ('\u{1DEE}', 230),
+        ('\u{1DEF}', 230),
+        ('\u{1DF0}', 230),
+        ('\u{1DF1}', 230),
+        ('\u{1DF2}', 230),
+        ('\u{1DF3}', 230),
+        ('\u{1DF4}', 230),
+        ('\u{1DF5}', 230),
+        ('\u{1DF6}', 232),
+        ('\u{1DF7}', 228),
+        ('\u{1DF8}', 228),
+        ('\u{1DF9}', 220),
+        ('\u{1DFA}', 218),
+        ('\u{1DFB}', 230),
+        ('\u{1DFC}', 233),
+        ('\u{1DFD}', 220),
+        ('\u{1DFE}', 230),
+        ('\u{1DFF}', 220),
+        ('\u{20D0}', 230),
+        ('\u{20D1}', 230),
+        ('\u{20D2}', 1),
+        ('\u{20D3}', 1),
+        ('\u{20D4}', 230),
+        ('\u{20D5}', 230),
+        ('\u{20D6}', 230),
+        ('\u{20D7}', 230),
+        ('\u{20D8}', 1),
+        ('\u{20D9}', 1),
+        ('\u{20DA}', 1),
+        ('\u{20DB}', 230),
+        ('\u{20DC}', 230),
+        ('\u{20E1}', 230),
+        ('\u{20E5}', 1),
+        ('\u{20E6}', 1),
+        ('\u{20E7}', 230),
+        ('\u{20E8}', 220),
+        ('\u{20E9}', 230),
+        ('\u{20EA}', 1),
+        ('\u{20EB}', 1),
+        ('\u{20EC}', 220),
+        ('\u{20ED}', 220),
+        ('\u{20EE}', 220),
+        ('\u{20EF}', 220),
+        ('\u{20F0}', 230),
+        ('\u{2CEF}', 230),
+        ('\u{2CF0}', 230),
+        ('\u{2CF1}', 230),
+        ('\u{2D7F}', 9),
+        ('\u{2DE0}', 230),
+        ('\u{2DE1}', 230),
+        ('\u{2DE2}', 230),
+        ('\u{2DE3}', 230),
+        ('\u{2DE4}', 230),
+        ('\u{2DE5}', 230),
+        ('\u{2DE6}', 230),
+        ('\u{2DE7}', 230),
+        ('\u{2DE8}', 230),
+        ('\u{2DE9}', 230),
+        ('\u{2DEA}', 230),
+        ('\u{2DEB}', 230),
+        ('\u{2DEC}', 230),
+        ('\u{2DED}', 230),
+        ('\u{2DEE}', 230),
+        ('\u{2DEF}', 230),
+        ('\u{2DF0}', 230),
+        ('\u{2DF1}', 230),
+        ('\u{2DF2}', 230),
+        ('\u{2DF3}', 230),
+        ('\u{2DF4}', 230),
+        ('\u{2DF5}', 230),
+        ('\u{2DF6}', 230),
+        ('\u{2DF7}', 230),
+        ('\u{2DF8}', 230),
+        ('\u{2DF9}', 230),
+        ('\u{2DFA}', 230),
+        ('\u{2DFB}', 230),
+        ('\u{2DFC}', 230),
+        ('\u{2DFD}', 230),
+        ('\u{2DFE}', 230),
+        ('\u{2DFF}', 230),
+        ('\u{302A}', 218),
+        ('\u{302B}', 228),
+        ('\u{302C}', 232),
+        ('\u{302D}', 222),
+        ('\u{302E}', 224),
+        ('\u{302F}', 224),
+        ('\u{3099}', 8),
+        ('\u{309A}', 8),
+        ('\u{A66F}', 230),
+        ('\u{A674}', 230),
+        ('\u{A675}', 230),
+        ('\u{A676}', 230),
+        ('\u{A677}', 230),
+        ('\u{A678}', 230),
+        ('\u{A679}', 230),
+        ('\u{A67A}', 230),
+        ('\u{A67B}', 230),
+        ('\u{A67C}', 230),
+        ('\u{A67D}', 230),
+        ('\u{A69E}', 230),
+        ('\u{A69F}', 230),
+        ('\u{A6F0}', 230),
+        ('\u{A6F1}', 230),
+        ('\u{A806}', 9),
+        ('\u{A82C}', 9),
+        ('\u{A8C4}', 9),
+        ('\u{A8E0}', 230),
+        ('\u{A8E1}', 230),
+        ('\u{A8E2}', 230),
+        ('\u{A8E3}', 230),
+        ('\u{A8E4}', 230),
+        ('\u{A8E5}', 230),
+        ('\u{A8E6}', 230),
+        ('\u{A8E7}', 230),
+        ('\u{A8E8}', 230),
+        ('\u{A8E9}', 230),
+        ('\u{A8EA}', 230),
+        ('\u{A8EB}', 230),
+        ('\u{A8EC}', 230),
+        ('\u{A8ED}', 230),
+        ('\u{A8EE}', 230),
+        ('\u{A8EF}', 230),
+        ('\u{A8F0}', 230),
+        ('\u{A8F1}', 230),
+        ('\u{A92B}', 220),
+        ('\u{A92C}', 220),
+        ('\u{A92D}', 220),
+        ('\u{A953}', 9),
+        ('\u{A9B3}', 7),
+        ('\u{A9C0}', 9),
+        ('\u{AAB0}', 230),
+        ('\u{AAB2}', 230),
+        ('\u{AAB3}', 230),
+        ('\u{AAB4}', 220),
+        ('\u{AAB7}', 230),
+        ('\u{AAB8}', 230),
+        ('\u{AABE}', 230),
+        ('\u{AABF}', 230),
+        ('\u{AAC1}', 230),
+        ('\u{AAF6}', 9),
+        ('\u{ABED}', 9),
+        ('\u{FB1E}', 26),
+        ('\u{FE20}', 230),
+        ('\u{FE21}', 230),
+        ('\u{FE22}', 230),
+        ('\u{FE23}', 230),
+        ('\u{FE24}', 230),
+        ('\u{FE25}', 230),
+        ('\u{FE26}', 230),
+        ('\u{FE27}', 220),
+        ('\u{FE28}', 220),
+        ('\u{FE29}', 220),
+        ('\u{FE2A}', 220),
+        ('\u{FE2B}', 220),
+        ('\u{FE2C}', 220),
+        ('\u{FE2D}', 220),
+        ('\u{FE2E}', 230),
+        ('\u{FE2F}', 230),
+        ('\u{101FD}', 220),
+        ('\u{102E0}', 220),
+        ('\u{10376}', 230),
+        ('\u{10377}', 230),
+        ('\u{10378}', 230),
+        ('\u{10379}', 230),
+        ('\u{1037A}', 230),
+        ('\u{10A0D}', 220),
+        ('\u{10A0F}', 230),
+        ('\u{10A38}', 230),
+        ('\u{10A39}', 1),
+        ('\u{10A3A}', 220),
+        ('\u{10A3F}', 9),
+        ('\u{10AE5}', 230),
+        ('\u{10AE6}', 220),
+        ('\u{10D24}', 230),
+        ('\u{10D25}', 230),
+        ('\u{10D26}', 230),
+        ('\u{10D27}', 230),
+        ('\u{10EAB}', 230),
+        ('\u{10EAC}', 230),
+        ('\u{10F46}', 220),
+        ('\u{10F47}', 220),
+        ('\u{10F48}', 230),
+        ('\u{10F49}', 230),
+        ('\u{10F4A}', 230),
+        ('\u{10F4B}', 220),
+        ('\u{10F4C}', 230),
+        ('\u{10F4D}', 220),
+        ('\u{10F4E}', 220),
+        ('\u{10F4F}', 220),
+        ('\u{10F50}', 220),
+        ('\u{10F82}', 230),
+        ('\u{10F83}', 220),
+        ('\u{10F84}', 230),
+        ('\u{10F85}', 220),
+        ('\u{11046}', 9),
+        ('\u{11070}', 9),
+        ('\u{1107F}', 9),
+        ('\u{110B9}', 9),
+        ('\u{110BA}', 7),
+        ('\u{11100}', 230),
+        ('\u{11101}', 230),
+        ('\u{11102}', 230),
+        ('\u{11133}', 9),
+        ('\u{11134}', 9),
+        ('\u{11173}', 7),
+        ('\u{111C0}', 9),
+        ('\u{111CA}', 7),
+        ('\u{11235}', 9),
+        ('\u{11236}', 7),
+        ('\u{112E9}', 7),
+        ('\u{112EA}', 9),
+        ('\u{1133B}', 7),
+        ('\u{1133C}', 7),
+        ('\u{1134D}', 9),
+        ('\u{11366}', 230),
+        ('\u{11367}', 230),
+        ('\u{11368}', 230),
+        ('\u{11369}', 230),
+        ('\u{1136A}', 230),
+        ('\u{1136B}', 230),
+        ('\u{1136C}', 230),
+        ('\u{11370}', 230),
+        ('\u{11371}', 230),
+        ('\u{11372}', 230),
+        ('\u{11373}', 230),
+        ('\u{11374}', 230),
+        ('\u{11442}', 9),
+        ('\u{11446}', 7),
+        ('\u{1145E}', 230),
+        ('\u{114C2}', 9),
+        ('\u{114C3}', 7),
+        ('\u{115BF}', 9),
+        ('\u{115C0}', 7),
+        ('\u{1163F}', 9),
+        ('\u{116B6}', 9),
+        ('\u{116B7}', 7),
+        ('\u{1172B}', 9),
+        ('\u{11839}', 9),
+        ('\u{1183A}', 7),
+        ('\u{1193D}', 9),
+        ('\u{1193E}', 9),
+        ('\u{11943}', 7),
+        ('\u{119E0}', 9),
+        ('\u{11A34}', 9),
+        ('\u{11A47}', 9),
+        ('\u{11A99}', 9),
+        ('\u{11C3F}', 9),
+        ('\u{11D42}', 7),
+        ('\u{11D44}', 9),
+        ('\u{11D45}', 9),
+        ('\u{11D97}', 9),
+        ('\u{16AF0}', 1),
+        ('\u{16AF1}', 1),
+        ('\u{16AF2}', 1),
+        ('\u{16AF3}', 1),
+        ('\u{16AF4}', 1),
+        ('\u{16B30}', 230),
+        ('\u{16B31}', 230),
+        ('\u{16B32}', 230),
+        ('\u{16B33}', 230),
+        ('\u{16B34}', 230),
+        ('\u{16B35}', 230),
+        ('\u{16B36}', 230),
+        ('\u{16FF0}', 6),
+        ('\u{16FF1}', 6),
+        ('\u{1BC9E}', 1),
+        ('\u{1D165}', 216),
+        ('\u{1D166}', 216),
+        ('\u{1D167}', 1),
+        ('\u{1D168}', 1),
+        ('\u{1D169}', 1),
+        ('\u{1D16D}', 226),
+        ('\u{1D16E}', 216),
+        ('\u{1D16F}', 216),
+        ('\u{1D170}', 216),
+        ('\u{1D171}', 216),
+        ('\u{1D172}', 216),
+        ('\u{1D17B}', 220),
+        ('\u{1D17C}', 220),
+        ('\u{1D17D}', 220),
+        ('\u{1D17E}', 220),
+        ('\u{1D17F}', 220),
+        ('\u{1D180}', 220),
+        ('\u{1D181}', 220),
+        ('\u{1D182}', 220),
+        ('\u{1D185}', 230),
+        ('\u{1D186}', 230),
+        ('\u{1D187}', 230),
+        ('\u{1D188}', 230),
+        ('\u{1D189}', 230),
+        ('\u{1D18A}', 220),
+        ('\u{1D18B}', 220),
+        ('\u{1D1AA}', 230),
+        ('\u{1D1AB}', 230),
+        ('\u{1D1AC}', 230),
+        ('\u{1D1AD}', 230),
+        ('\u{1D242}', 230),
+        ('\u{1D243}', 230),
+        ('\u{1D244}', 230),
+        ('\u{1E000}', 230),
+        ('\u{1E001}', 230),
+        ('\u{1E002}', 230),
+        ('\u{1E003}', 230),
+        ('\u{1E004}', 230),
+        ('\u{1E005}', 230),
+        ('\u{1E006}', 230),
+        ('\u{1E008}', 230),
+        ('\u{1E009}', 230),
+        ('\u{1E00A}', 230),
+        ('\u{1E00B}', 230),
+        ('\u{1E00C}', 230),
+        ('\u{1E00D}', 230),
+        ('\u{1E00E}', 230),
+        ('\u{1E00F}', 230),
+        ('\u{1E010}', 230),
+        ('\u{1E011}', 230),
+        ('\u{1E012}', 230),
+        ('\u{1E013}', 230),
+        ('\u{1E014}', 230),
+        ('\u{1E015}', 230),
+        ('\u{1E016}', 230),
+        ('\u{1E017}', 230),
+        ('\u{1E018}', 230),
+        ('\u{1E01B}', 230),
+        ('\u{1E01C}', 230),
+        ('\u{1E01D}', 230),
+        ('\u{1E01E}', 230),
+        ('\u{1E01F}', 230),
+        ('\u{1E020}', 230),
+        ('\u{1E021}', 230),
+        ('\u{1E023}', 230),
+        ('\u{1E024}', 230),
+        ('\u{1E026}', 230),
+        ('\u{1E027}', 230),
+        ('\u{1E028}', 230),
+        ('\u{1E029}', 230),
+        ('\u{1E02A}', 230),
+        ('\u{1E130}', 230),
+        ('\u{1E131}', 230),
+        ('\u{1E132}', 230),
+        ('\u{1E133}', 230),
+        ('\u{1E134}', 230),
+        ('\u{1E135}', 230),
+        ('\u{1E136}', 230),
+        ('\u{1E2AE}', 230),
+        ('\u{1E2EC}', 230),
+        ('\u{1E2ED}', 230),
+        ('\u{1E2EE}', 230),
+        ('\u{1E2EF}', 230),
+        ('\u{1E8D0}', 220),
+        ('\u{1E8D1}', 220),
+        ('\u{1E8D2}', 220),
+        ('\u{1E8D3}', 220),
+        ('\u{1E8D4}', 220),
+        ('\u{1E8D5}', 220),
+        ('\u{1E8D6}', 220),
+        ('\u{1E944}', 230),
+        ('\u{1E945}', 230),
+        ('\u{1E946}', 230),
+        ('\u{1E947}', 230),
+        ('\u{1E948}', 230),
+        ('\u{1E949}', 230),
+        ('\u{1E94A}', 7),
+    ],
+    buckets: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 64, 95, 111, 111, 111, 111,
+        111, 111, 111, 111, 111, 116, 116, 116, 116, 116, 116, 116, 116, 131, 162, 167,
+        167, 178, 178, 199, 200, 200, 200, 208, 219, 220, 236, 247, 247, 247, 247, 247,
+        257, 266, 278, 281, 281, 289, 289, 311, 342, 342, 343, 348, 348, 348, 349, 350,
+        351, 351, 352, 353, 353, 353, 354, 355, 355, 355, 356, 357, 357, 357, 357, 358,
+        358, 358, 359, 362, 362, 362, 363, 364, 364, 364, 366, 367, 367, 367, 367, 368,
+        368, 368, 371, 375, 375, 375, 378, 382, 382, 384, 387, 387, 394, 400, 400, 401,
+        401, 401, 404, 404, 404, 405, 405, 405, 405, 405, 405, 405, 405, 405, 405, 405,
+        405, 405, 405, 405, 405, 405, 405, 405, 405, 405, 405, 408, 408, 408, 408, 408,
+        408, 408, 408, 408, 408, 408, 408, 408, 408, 408, 408, 408, 408, 408, 408, 408,
+        408, 408, 408, 408, 408, 408, 408, 408, 408, 410, 411, 411, 411, 411, 411, 413,
+        413, 413, 413, 413, 413, 413, 414, 414, 414, 414, 417, 417, 417, 417, 417, 417,
+        417, 419, 419, 419, 429, 429, 444, 459, 459, 459, 460, 461, 470, 470, 472, 472,
+        475, 475, 476, 476, 476, 476, 476, 491, 503, 503, 503, 503, 503, 503, 503, 535,
+        567, 567, 567, 567, 567, 567, 567, 567, 567, 567, 567, 567, 567, 567, 567, 567,
+        567, 567, 567, 567, 567, 567, 567, 580, 593, 593, 593, 593, 593, 593, 593, 593,
+        593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593,
+        593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593,
+        593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593,
+        593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593,
+        593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593, 593,
+        593, 593, 593, 593, 593, 593, 593, 593, 596, 596, 596, 596, 597, 597, 597, 597,
+        629, 629, 629, 629, 629, 629, 629, 629, 629, 629, 629, 629, 629, 629, 629, 629,
+        629, 629, 635, 635, 635, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637, 637,
+        637, 637, 637, 637, 648, 650, 650, 650, 652, 652, 652, 652, 652, 652, 652, 652,
+        652, 653, 654, 654, 654, 654, 654, 655, 673, 673, 676, 677, 677, 677, 678, 679,
+        679, 679, 679, 679, 679, 679, 687, 688, 689, 689, 689, 689, 689, 689, 689, 689,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690, 690,
+        690, 690, 690, 690, 690, 690, 690, 690, 690, 691, 691, 691, 691, 691, 691, 691,
+        691, 691, 691, 691, 691, 691, 691, 691, 691, 691, 691, 691, 691, 691, 691, 691,
+        691, 691, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707,
+        707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707, 707,
+        708, 708, 708, 708, 708, 708, 708, 708, 709, 709, 709, 709, 714, 714, 714, 714,
+        714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714,
+        714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714,
+        714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714, 714,
+        714, 716, 720, 720, 720, 720, 720, 720, 722, 722, 722, 722, 722, 722, 722, 722,
+        722, 722, 722, 722, 722, 722, 722, 722, 722, 722, 726, 726, 726, 726, 726, 726,
+        726, 726, 726, 726, 726, 726, 728, 728, 728, 728, 728, 739, 739, 743, 743, 743,
+        743, 743, 743, 744, 746, 746, 748, 748, 748, 751, 753, 753, 754, 754, 754, 756,
+        756, 756, 758, 758, 758, 758, 758, 758, 760, 760, 762, 763, 775, 775, 775, 775,
+        775, 775, 775, 778, 778, 778, 778, 780, 780, 780, 780, 780, 780, 780, 781, 782,
+        782, 782, 783, 783, 783, 783, 785, 785, 785, 785, 786, 786, 786, 786, 786, 786,
+        786, 786, 788, 788, 788, 788, 788, 788, 788, 788, 790, 791, 791, 791, 791, 791,
+        792, 792, 793, 794, 794, 795, 795, 795, 795, 795, 795, 795, 795, 795, 795, 795,
+        795, 795, 796, 796, 796, 796, 796, 796, 796, 796, 796, 799, 799, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800,
+        800, 800, 800, 800, 800, 800, 800, 800, 805, 805, 812, 812, 812, 812, 812, 812,
+        812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812,
+        812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812, 812,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814, 814,
+        814, 814, 814, 814, 814, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815,
+        815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 815, 831, 841, 845, 845,
+        845, 845, 845, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848,
+        848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848,
+        848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848,
+        848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848,
+        848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848,
+        848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848,
+        848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848, 848,
+        848, 877, 886, 886, 886, 886, 886, 886, 886, 886, 893, 893, 893, 893, 893, 893,
+        893, 893, 893, 893, 893, 893, 894, 894, 898, 898, 898, 898, 898, 898, 898, 898,
+        898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898,
+        898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898, 898,
+        898, 898, 898, 898, 898, 898, 898, 905, 905, 905, 905, 912,
+    ],
+};
+
+/// Map from code points to their non-zero canonical combining classes in Unicode 3.2
+///
+/// The classes of the marks assigned in Unicode 3.2 have not changed since, but the later ones are 0 here.
+pub static COMBINING_CLASS_TABLE_UNICODE32: CharMap<u8> = CharMap {
+    entries: &[
+        ('\u{0300}', 230),
+        ('\u{0301}', 230),
+        ('\u{0302}', 230),
+        ('\u{0303}', 230),
+        ('\u{0304}', 230),
+        ('\u{0305}', 230),
+        ('\u{0306}', 230),
+        ('\u{0307}', 230),
+        ('\u{0308}', 230),
+        ('\u{0309}', 230),
+        ('\u{030A}', 230),
+        ('\u{030B}', 230),
+        ('\u{030C}', 230),
+        ('\u{030D}', 230),
+        ('\u{030E}', 230),
+        ('\u{030F}', 230),
+        ('\u{0310}', 230),
+        ('\u{0311}', 230),
+        ('\u{0312}', 230),
+        ('\u{0313}', 230),
+        ('\u{0314}', 230),
+        ('\u{0315}', 232),
+        ('\u{0316}', 220),
+        ('\u{0317}', 220),
+        ('\u{0318}', 220),
+        ('\u{0319}', 220),
+        ('\u{031A}', 232),
+        ('\u{031B}', 216),
+        ('\u{031C}', 220),
+        ('\u{031D}', 220),
+        ('\u{031E}', 220),
+        ('\u{031F}', 220),
+        ('\u{0320}', 220),
+        ('\u{0321}', 202),
+        ('\u{0322}', 202),
+        ('\u{0323}', 220),
+        ('\u{0324}', 220),
+        ('\u{0325}', 220),
+        ('\u{0326}', 220),
+        ('\u{0327}', 202),
+        ('\u{0328}', 202),
+        ('\u{0329}', 220),
+        ('\u{032A}', 220),
+        ('\u{032B}', 220),
+        ('\u{032C}', 220),
+        ('\u{032D}', 220),
+        ('\u{032E}', 220),
+        ('\u{032F}', 220),
+        ('\u{0330}', 220),
+        ('\u{0331}', 220),
+        ('\u{0332}', 220),
+        ('\u{0333}', 220),
+        ('\u{0334}', 1),
+        ('\u{0335}', 1),
+        ('\u{0336}', 1),
+        ('\u{0337}', 1),
+        ('\u{0338}', 1),
+        ('\u{0339}', 220),
+        ('\u{033A}', 220),
+        ('\u{033B}', 220),
+        ('\u{033C}', 220),
+        ('\u{033D}', 230),
+        ('\u{033E}', 230),
+        ('\u{033F}', 230),
+        ('\u{0340}', 230),
+        ('\u{0341}', 230),
+        ('\u{0342}', 230),
+        ('\u{0343}', 230),
+        ('\u{0344}', 230),
+        ('\u{0345}', 240),
+        ('\u{0346}', 230),
+        ('\u{0347}', 220),
+        ('\u{0348}', 220),
+        ('\u{0349}', 220),
+        ('\u{034A}', 230),
+        ('\u{034B}', 230),
+        ('\u{034C}', 230),
+        ('\u{034D}', 220),
+        ('\u{034E}', 220),
+        ('\u{0360}', 234),
+        ('\u{0361}', 234),
+        ('\u{0362}', 233),
+        ('\u{0363}', 230),
+        ('\u{0364}', 230),
+        ('\u{0365}', 230),
+        ('\u{0366}', 230),
+        ('\u{0367}', 230),
+        ('\u{0368}', 230),
+        ('\u{0369}', 230),
+        ('\u{036A}', 230),
+        ('\u{036B}', 230),
+        ('\u{036C}', 230),
+        ('\u{036D}', 230),
+        ('\u{036E}', 230),
+        ('\u{036F}', 230),
+        ('\u{0483}', 230),
+        ('\u{0484}', 230),
+        ('\u{0485}', 230),
+        ('\u{0486}', 230),
+        ('\u{0591}', 220),
+        ('\u{0592}', 230),
+        ('\u{0593}', 230),
+        ('\u{0594}', 230),
+        ('\u{0595}', 230),
+        ('\u{0596}', 220),
+        ('\u{0597}', 230),
+        ('\u{0598}', 230),
+        ('\u{0599}', 230),
+        ('\u{059A}', 222),
+        ('\u{059B}', 220),
+        ('\u{059C}', 230),
+        ('\u{059D}', 230),
+        ('\u{059E}', 230),
+        ('\u{059F}', 230),
+        ('\u{05A0}', 230),
+        ('\u{05A1}', 230),
+        ('\u{05A3}', 220),
+        ('\u{05A4}', 220),
+        ('\u{05A5}', 220),
+        ('\u{05A6}', 220),
+        ('\u{05A7}', 220),
+        ('\u{05A8}', 230),
+        ('\u{05A9}', 230),
+        ('\u{05AA}', 220),
+        ('\u{05AB}', 230),
+        ('\u{05AC}', 230),
+        ('\u{05AD}', 222),
+        ('\u{05AE}', 228),
+        ('\u{05AF}', 230),
+        ('\u{05B0}', 10),
+        ('\u{05B1}', 11),
+        ('\u{05B2}', 12),
+        ('\u{05B3}', 13),
+        ('\u{05B4}', 14),
+        ('\u{05B5}', 15),
+        ('\u{05B6}', 16),
+        ('\u{05B7}', 17),
+        ('\u{05B8}', 18),
+        ('\u{05B9}', 19),
+        ('\u{05BB}', 20),
+        ('\u{05BC}', 21),
+        ('\u{05BD}', 22),
+        ('\u{05BF}', 23),
+        ('\u{05C1}', 24),
+        ('\u{05C2}', 25),
+        ('\u{05C4}', 230),
+        ('\u{064B}', 27),
+        ('\u{064C}', 28),
+        ('\u{064D}', 29),
+        ('\u{064E}', 30),
+        ('\u{064F}', 31),
+        ('\u{0650}', 32),
+        ('\u{0651}', 33),
+        ('\u{0652}', 34),
+        ('\u{0653}', 230),
+        ('\u{0654}', 230),
+        ('\u{0655}', 220),
+        ('\u{0670}', 35),
+        ('\u{06D6}', 230),
+        ('\u{06D7}', 230),
+        ('\u{06D8}', 230),
+        ('\u{06D9}', 230),
+        ('\u{06DA}', 230),
+        ('\u{06DB}', 230),
+        ('\u{06DC}', 230),
+        ('\u{06DF}', 230),
+        ('\u{06E0}', 230),
+        ('\u{06E1}', 230),
+        ('\u{06E2}', 230),
+        ('\u{06E3}', 220),
+        ('\u{06E4}', 230),
+        ('\u{06E7}', 230),
+        ('\u{06E8}', 230),
+        ('\u{06EA}', 220),
+        ('\u{06EB}', 230),
+        ('\u{06EC}', 230),
+        ('\u{06ED}', 220),
+        ('\u{0711}', 36),
+        ('\u{0730}', 230),
+        ('\u{0731}', 220),
+        ('\u{0732}', 230),
+        ('\u{0733}', 230),
+        ('\u{0734}', 220),
+        ('\u{0735}', 230),
+        ('\u{0736}', 230),
+        ('\u{0737}', 220),
+        ('\u{0738}', 220),
+        ('\u{0739}', 220),
+        ('\u{073A}', 230),
+        ('\u{073B}', 220),
+        ('\u{073C}', 220),
+        ('\u{073D}', 230),
+        ('\u{073E}', 220),
+        ('\u{073F}', 230),
+        ('\u{0740}', 230),
+        ('\u{0741}', 230),
+        ('\u{0742}', 220),
+        ('\u{0743}', 230),
+        ('\u{0744}', 220),
+        ('\u{0745}', 230),
+        ('\u{0746}', 220),
+        ('\u{0747}', 230),
+        ('\u{0748}', 220),
+        ('\u{0749}', 230),
+        ('\u{074A}', 230),
+        ('\u{093C}', 7),
+        ('\u{094D}', 9),
+        ('\u{0951}', 230),
+        ('\u{0952}', 220),
+        ('\u{0953}', 230),
+        ('\u{0954}', 230),
+        ('\u{09BC}', 7),
+        ('\u{09CD}', 9),
+        ('\u{0A3C}', 7),
+        ('\u{0A4D}', 9),
+        ('\u{0ABC}', 7),
+        ('\u{0ACD}', 9),
+        ('\u{0B3C}', 7),
+        ('\u{0B4D}', 9),
+        ('\u{0BCD}', 9),
+        ('\u{0C4D}', 9),
+        ('\u{0C55}', 84),
+        ('\u{0C56}', 91),
+        ('\u{0CCD}', 9),
+        ('\u{0D4D}', 9),
+        ('\u{0DCA}', 9),
+        ('\u{0E38}', 103),
+        ('\u{0E39}', 103),
+        ('\u{0E3A}', 9),
+        ('\u{0E48}', 107),
+        ('\u{0E49}', 107),
+        ('\u{0E4A}', 107),
+        ('\u{0E4B}', 107),
+        ('\u{0EB8}', 118),
+        ('\u{0EB9}', 118),
+        ('\u{0EC8}', 122),
+        ('\u{0EC9}', 122),
+        ('\u{0ECA}', 122),
+        ('\u{0ECB}', 122),
+        ('\u{0F18}', 220),
+        ('\u{0F19}', 220),
+        ('\u{0F35}', 220),
+        ('\u{0F37}', 220),
+        ('\u{0F39}', 216),
+        ('\u{0F71}', 129),
+        ('\u{0F72}', 130),
+        ('\u{0F74}', 132),
+        ('\u{0F7A}', 130),
+        ('\u{0F7B}', 130),
+        ('\u{0F7C}', 130),
+        ('\u{0F7D}', 130),
+        ('\u{0F80}', 130),
+        ('\u{0F82}', 230),
+        ('\u{0F83}', 230),
+        ('\u{0F84}', 9),
+        ('\u{0F86}', 230),
+        ('\u{0F87}', 230),
+        ('\u{0FC6}', 220),
+        ('\u{1037}', 7),
+        ('\u{1039}', 9),
+        ('\u{1714}', 9),
+        ('\u{1734}', 9),
+        ('\u{17D2}', 9),
+        ('\u{18A9}', 228),
+        ('\u{20D0}', 230),
+        ('\u{20D1}', 230),
+        ('\u{20D2}', 1),
+        ('\u{20D3}', 1),
+        ('\u{20D4}', 230),
+        ('\u{20D5}', 230),
+        ('\u{20D6}', 230),
+        ('\u{20D7}', 230),
+        ('\u{20D8}', 1),
+        ('\u{20D9}', 1),
+        ('\u{20DA}', 1),
+        ('\u{20DB}', 230),
+        ('\u{20DC}', 230),
+        ('\u{20E1}', 230),
+        ('\u{20E5}', 1),
+        ('\u{20E6}', 1),
+        ('\u{20E7}', 230),
+        ('\u{20E8}', 220),
+        ('\u{20E9}', 230),
+        ('\u{20EA}', 1),
+        ('\u{302A}', 218),
+        ('\u{302B}', 228),
+        ('\u{302C}', 232),
+        ('\u{302D}', 222),
+        ('\u{302E}', 224),
+        ('\u{302F}', 224),
+        ('\u{3099}', 8),
+        ('\u{309A}', 8),
+        ('\u{FB1E}', 26),
+        ('\u{FE20}', 230),
+        ('\u{FE21}', 230),
+        ('\u{FE22}', 230),
+        ('\u{FE23}', 230),
+        ('\u{1D165}', 216),
+        ('\u{1D166}', 216),
+        ('\u{1D167}', 1),
+        ('\u{1D168}', 1),
+        ('\u{1D169}', 1),
+        ('\u{1D16D}', 226),
+        ('\u{1D16E}', 216),
+        ('\u{1D16F}', 216),
+        ('\u{1D170}', 216),
+        ('\u{1D171}', 216),
+        ('\u{1D172}', 216),
+        ('\u{1D17B}', 220),
+        ('\u{1D17C}', 220),
+        ('\u{1D17D}', 220),
+        ('\u{1D17E}', 220),
+        ('\u{1D17F}', 220),
+        ('\u{1D180}', 220),
+        ('\u{1D181}', 220),
+        ('\u{1D182}', 220),
+        ('\u{1D185}', 230),
+        ('\u{1D186}', 230),
+        ('\u{1D187}', 230),
+        ('\u{1D188}', 230),
+        ('\u{1D189}', 230),
+        ('\u{1D18A}', 220),
+        ('\u{1D18B}', 220),
+        ('\u{1D1AA}', 230),
+        ('\u{1D1AB}', 230),
+        ('\u{1D1AC}', 230),
+        ('\u{1D1AD}', 230),
+    ],
+    buckets: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 64, 79, 95, 95, 95, 95,
+        95, 95, 95, 95, 95, 99, 99, 99, 99, 99, 99, 99, 99, 114, 143, 146,
+        146, 146, 146, 157, 158, 158, 158, 166, 177, 178, 194, 205, 205, 205, 205, 205,
+        205, 205, 205, 205, 205, 205, 205, 205, 205, 205, 206, 211, 211, 211, 212, 213,
+        213, 213, 214, 215, 215, 215, 216, 217, 217, 217, 218, 219, 219, 219, 219, 220,
+        220, 220, 220, 223, 223, 223, 223, 224, 224, 224, 224, 225, 225, 225, 225, 226,
+        226, 226, 229, 233, 233, 233, 235, 239, 239, 241, 244, 244, 251, 257, 257, 258,
+        258, 258, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260,
+        260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260,
+        260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260, 260,
+        260, 260, 260, 260, 260, 260, 260, 260, 260, 261, 262, 262, 262, 262, 262, 263,
+        263, 263, 263, 263, 263, 263, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264,
+        264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264,
+        264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264,
+        264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264,
+        264, 264, 264, 264, 264, 264, 264, 277, 284, 284, 284, 284, 284, 284, 284, 284,
+        284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284,
+        284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284,
+        284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284,
+        284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284,
+        284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284,
+        284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284,
+        284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284, 284,
+        284, 284, 290, 290, 290, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292, 292,
+        292, 292, 292, 292, 292, 292, 292, 292, 292, 293, 293, 293, 293, 293, 293, 293,
+        293, 293, 293, 293, 293, 293, 293, 293, 293, 293, 293, 293, 293, 293, 293, 293,
+        293, 293, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297,
+        297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 297, 313, 323, 327,
+    ],
+};
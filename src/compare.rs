@@ -0,0 +1,405 @@
+//! Comparison of strings insensitive to the HFS+ decomposition
+
+use super::case_table::hfs_lower_case;
+#[cfg(feature = "alloc")]
+use super::ignorable::{apply_ignorable_policy, is_hfs_ignorable, IgnorablePolicy};
+#[cfg(feature = "alloc")]
+use super::iter::HfsNormalization;
+#[cfg(feature = "alloc")]
+use super::profile::NormalizationProfile;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::cmp::Ordering;
+
+/// Checks whether two strings are equal after [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd) is applied
+/// to both of them, without decomposing them as a whole
+///
+/// # Arguments
+///
+/// * `a` - A string to be compared
+/// * `b` - Another string to be compared
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::hfs_eq;
+/// assert!(hfs_eq("Pok\u{00E9}mon", "Poke\u{0301}mon"));
+/// assert!(!hfs_eq("Pok\u{00E9}mon", "Pokemon"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hfs_eq(a: &str, b: &str) -> bool {
+    hfs_eq_with_profile(a, b, NormalizationProfile::HfsPlus)
+}
+
+/// Checks whether two strings are equal after the decomposition according to `profile` is applied to both of them,
+/// without decomposing them as a whole
+///
+/// # Arguments
+///
+/// * `a` - A string to be compared
+/// * `b` - Another string to be compared
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{hfs_eq_with_profile, NormalizationProfile};
+/// assert!(hfs_eq_with_profile("\u{01F8}", "N\u{0300}", NormalizationProfile::Unicode32));
+/// assert!(!hfs_eq_with_profile("\u{01F8}", "N\u{0300}", NormalizationProfile::HfsPlus));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hfs_eq_with_profile(a: &str, b: &str, profile: NormalizationProfile) -> bool {
+    a == b
+        || a.hfs_nfd_chars_with_profile(profile)
+            .eq(b.hfs_nfd_chars_with_profile(profile))
+}
+
+/// Checks whether two strings are equal after [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd) is applied
+/// to both of them, handling the ignorable code points according to `policy`
+///
+/// The ignorable code points are removed after the decomposition, so they still block the canonical reordering of
+/// combining marks as they do on HFS+.
+///
+/// # Arguments
+///
+/// * `a` - A string to be compared
+/// * `b` - Another string to be compared
+/// * `policy` - How the ignorable code points are handled
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{hfs_eq_with_policy, IgnorablePolicy};
+/// assert!(hfs_eq_with_policy("Pok\u{00E9}mon\u{200E}", "Poke\u{0301}mon", IgnorablePolicy::Strip));
+/// assert!(!hfs_eq_with_policy("Pok\u{00E9}mon\u{200E}", "Poke\u{0301}mon", IgnorablePolicy::Keep));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hfs_eq_with_policy(a: &str, b: &str, policy: IgnorablePolicy) -> bool {
+    match policy {
+        IgnorablePolicy::Keep => hfs_eq(a, b),
+        IgnorablePolicy::Strip => {
+            a == b
+                || a.hfs_nfd_chars()
+                    .filter(|&c| !is_hfs_ignorable(c))
+                    .eq(b.hfs_nfd_chars().filter(|&c| !is_hfs_ignorable(c)))
+        }
+    }
+}
+
+/// Compares two strings by the code points after
+/// [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd) is applied to both of them, without decomposing them as
+/// a whole
+///
+/// # Arguments
+///
+/// * `a` - A string to be compared
+/// * `b` - Another string to be compared
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::hfs_cmp;
+/// use std::cmp::Ordering;
+/// assert_eq!(hfs_cmp("Pok\u{00E9}mon", "Poke\u{0301}mon"), Ordering::Equal);
+/// // U+0065 U+0301 < U+0066
+/// assert_eq!(hfs_cmp("\u{00E9}", "f"), Ordering::Less);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hfs_cmp(a: &str, b: &str) -> Ordering {
+    hfs_cmp_with_profile(a, b, NormalizationProfile::HfsPlus)
+}
+
+/// Compares two strings by the code points after the decomposition according to `profile` is applied to both of
+/// them, without decomposing them as a whole
+///
+/// # Arguments
+///
+/// * `a` - A string to be compared
+/// * `b` - Another string to be compared
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{hfs_cmp_with_profile, NormalizationProfile};
+/// use std::cmp::Ordering;
+/// // U+03B1 U+0301 < U+03B1 U+030D
+/// assert_eq!(hfs_cmp_with_profile("\u{03AC}", "\u{1F71}", NormalizationProfile::HfsPlus), Ordering::Greater);
+/// assert_eq!(hfs_cmp_with_profile("\u{03AC}", "\u{1F71}", NormalizationProfile::Unicode32), Ordering::Equal);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hfs_cmp_with_profile(a: &str, b: &str, profile: NormalizationProfile) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    a.hfs_nfd_chars_with_profile(profile)
+        .cmp(b.hfs_nfd_chars_with_profile(profile))
+}
+
+/// Compares two strings by the code points after
+/// [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd) is applied to both of them, handling the ignorable code
+/// points according to `policy`
+///
+/// # Arguments
+///
+/// * `a` - A string to be compared
+/// * `b` - Another string to be compared
+/// * `policy` - How the ignorable code points are handled
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{hfs_cmp_with_policy, IgnorablePolicy};
+/// use std::cmp::Ordering;
+/// assert_eq!(hfs_cmp_with_policy("\u{FEFF}a", "b", IgnorablePolicy::Strip), Ordering::Less);
+/// assert_eq!(hfs_cmp_with_policy("\u{FEFF}a", "b", IgnorablePolicy::Keep), Ordering::Greater);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hfs_cmp_with_policy(a: &str, b: &str, policy: IgnorablePolicy) -> Ordering {
+    match policy {
+        IgnorablePolicy::Keep => hfs_cmp(a, b),
+        IgnorablePolicy::Strip => {
+            if a == b {
+                return Ordering::Equal;
+            }
+            a.hfs_nfd_chars()
+                .filter(|&c| !is_hfs_ignorable(c))
+                .cmp(b.hfs_nfd_chars().filter(|&c| !is_hfs_ignorable(c)))
+        }
+    }
+}
+
+/// Compares two HFS+ catalog names (UTF-16 code units) in the order of the catalog B-tree of case-insensitive HFS+
+/// volumes, as `FastUnicodeCompare` in TN1150 does
+///
+/// Each code unit is folded with the HFS+ lower case table and ignorable code points are skipped. The names are
+/// expected to be decomposed with [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd) already.
+///
+/// # Arguments
+///
+/// * `a` - A name to be compared
+/// * `b` - Another name to be compared
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::fast_unicode_compare;
+/// use std::cmp::Ordering;
+/// let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+/// assert_eq!(fast_unicode_compare(&utf16("README"), &utf16("readme")), Ordering::Equal);
+/// assert_eq!(fast_unicode_compare(&utf16("a\u{200D}b"), &utf16("ab")), Ordering::Equal);
+/// assert_eq!(fast_unicode_compare(&utf16("B"), &utf16("a")), Ordering::Greater);
+/// ```
+pub fn fast_unicode_compare(a: &[u16], b: &[u16]) -> Ordering {
+    let mut a = a.iter().map(|&c| hfs_lower_case(c)).filter(|&c| c != 0);
+    let mut b = b.iter().map(|&c| hfs_lower_case(c)).filter(|&c| c != 0);
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(c1), Some(c2)) if c1 != c2 => return c1.cmp(&c2),
+            _ => {}
+        }
+    }
+}
+
+/// Maps a character with the HFS+ lower case table, leaving ignorable code points and U+0000 as is
+#[cfg(feature = "alloc")]
+fn hfs_lower_case_char(c: char) -> char {
+    if c > '\u{FFFF}' {
+        return c;
+    }
+    match hfs_lower_case(c as u16) {
+        0 | 0xFFFF => c,
+        lower => core::char::from_u32(lower as u32).unwrap_or(c),
+    }
+}
+
+/// Applies the lower case mapping used by case-insensitive HFS+ volumes (`gLowerCaseTable` in TN1150), instead of the
+/// full Unicode case folding
+///
+/// The table has no entries for characters decomposed by HFS+ (e.g. U+00C0), so apply
+/// [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd) first to names not decomposed yet. Two decomposed names
+/// collide on a case-insensitive HFS+ volume if they are equal after this function is applied, except for the
+/// ignorable code points, which are left as is.
+///
+/// # Arguments
+///
+/// * `input` - A string to be folded
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_into_hfs_nfd, hfs_case_fold};
+/// assert_eq!(hfs_case_fold("README.md"), "readme.md");
+/// assert_eq!(hfs_case_fold(&decompose_into_hfs_nfd("\u{00C9}T\u{00C9}")), "e\u{0301}te\u{0301}");
+/// // Unlike the Unicode case folding, ß is not mapped to ss
+/// assert_eq!(hfs_case_fold("STRA\u{00DF}E"), "stra\u{00DF}e");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hfs_case_fold(input: &str) -> Cow<'_, str> {
+    match input.find(|c| hfs_lower_case_char(c) != c) {
+        None => Cow::Borrowed(input),
+        Some(index) => {
+            let mut result = String::with_capacity(input.len());
+            result.push_str(&input[..index]);
+            result.extend(input[index..].chars().map(hfs_lower_case_char));
+            Cow::Owned(result)
+        }
+    }
+}
+
+/// Applies the lower case mapping used by case-insensitive HFS+ volumes, handling the ignorable code points according
+/// to `policy`
+///
+/// With [`IgnorablePolicy::Strip`], two decomposed names collide on a case-insensitive HFS+ volume if and only if they
+/// are equal after this function is applied.
+///
+/// # Arguments
+///
+/// * `input` - A string to be folded
+/// * `policy` - How the ignorable code points are handled
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{hfs_case_fold_with_policy, IgnorablePolicy};
+/// assert_eq!(hfs_case_fold_with_policy("\u{FEFF}README", IgnorablePolicy::Strip), "readme");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hfs_case_fold_with_policy(input: &str, policy: IgnorablePolicy) -> Cow<'_, str> {
+    apply_ignorable_policy(hfs_case_fold(input), policy)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::decompose_into_hfs_nfd;
+
+    static EXAMINEE: &[&str] = &[
+        "",
+        "Pok\u{00E9}mon",
+        "Poke\u{0301}mon",
+        "Pokemon",
+        "Pok\u{00E9}",
+        "\u{D55C}\u{AE00}",
+        "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}",
+        "\u{30DD}\u{30B1}\u{30E2}\u{30F3}",
+        "\u{30DB}\u{309A}\u{30B1}\u{30E2}\u{30F3}",
+        "f",
+    ];
+
+    #[test]
+    fn compare_with_decomposed_test() {
+        for a in EXAMINEE {
+            for b in EXAMINEE {
+                let expected = decompose_into_hfs_nfd(a).cmp(&decompose_into_hfs_nfd(b));
+                assert_eq!(hfs_cmp(a, b), expected, "{:?} vs {:?}", a, b);
+                assert_eq!(hfs_eq(a, b), expected == Ordering::Equal);
+            }
+        }
+    }
+
+    #[test]
+    fn fast_unicode_compare_fixed_test() {
+        let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+        let examinee = vec![
+            ("", "", Ordering::Equal),
+            ("", "a", Ordering::Less),
+            ("Desktop", "desktop", Ordering::Equal),
+            ("\u{0391}\u{0392}", "\u{03B1}\u{03B2}", Ordering::Equal),
+            ("\u{FEFF}abc\u{200E}", "ABC", Ordering::Equal),
+            ("abc", "abd", Ordering::Less),
+            ("abc", "ab", Ordering::Greater),
+            // U+0000 is sorted after any other character
+            ("a\u{0000}", "a\u{FFFD}", Ordering::Greater),
+            // Uppercase letters are compared as lowercase ones
+            ("_", "A", Ordering::Less),
+        ];
+        for (a, b, expected) in examinee {
+            assert_eq!(fast_unicode_compare(&utf16(a), &utf16(b)), expected);
+            assert_eq!(
+                fast_unicode_compare(&utf16(b), &utf16(a)),
+                expected.reverse()
+            );
+        }
+    }
+
+    #[test]
+    fn hfs_case_fold_test() {
+        let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+        let examinee = [
+            "Desktop",
+            "DESKTOP",
+            "\u{0391}\u{0392}",
+            "\u{03B1}\u{03B2}",
+            "\u{0410}\u{0490}",
+            "\u{0430}\u{0491}",
+            "\u{FF21}\u{2160}",
+            "\u{FF41}\u{2170}",
+            "E\u{0301}",
+            "e\u{0301}",
+            "\u{1D400}",
+            "I",
+            "\u{0131}",
+        ];
+        for a in &examinee {
+            for b in &examinee {
+                assert_eq!(
+                    hfs_case_fold(a) == hfs_case_fold(b),
+                    fast_unicode_compare(&utf16(a), &utf16(b)) == Ordering::Equal,
+                    "{:?} vs {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+        assert!(matches!(hfs_case_fold("desktop"), Cow::Borrowed(_)));
+        assert_eq!(hfs_case_fold("A\u{200D}\u{0000}"), "a\u{200D}\u{0000}");
+    }
+
+    #[test]
+    fn ignorable_policy_test() {
+        let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+        let examinee = [
+            "ab",
+            "a\u{200D}b",
+            "\u{FEFF}AB\u{206F}",
+            "e\u{0301}\u{200D}\u{0323}",
+            "e\u{0301}\u{0323}",
+            "e\u{0323}\u{0301}",
+            "\u{1EB9}\u{0301}",
+        ];
+        for a in &examinee {
+            for b in &examinee {
+                let (a_nfd, b_nfd) = (decompose_into_hfs_nfd(a), decompose_into_hfs_nfd(b));
+                let expected =
+                    fast_unicode_compare(&utf16(&a_nfd), &utf16(&b_nfd)) == Ordering::Equal;
+                assert_eq!(
+                    hfs_case_fold_with_policy(&a_nfd, IgnorablePolicy::Strip)
+                        == hfs_case_fold_with_policy(&b_nfd, IgnorablePolicy::Strip),
+                    expected,
+                    "{:?} vs {:?}",
+                    a,
+                    b
+                );
+                // Not reordered again after stripped
+                let expected = a_nfd
+                    .replace(is_hfs_ignorable, "")
+                    .cmp(&b_nfd.replace(is_hfs_ignorable, ""));
+                assert_eq!(hfs_cmp_with_policy(a, b, IgnorablePolicy::Strip), expected);
+                assert_eq!(
+                    hfs_eq_with_policy(a, b, IgnorablePolicy::Strip),
+                    expected == Ordering::Equal
+                );
+                assert_eq!(
+                    hfs_cmp_with_policy(a, b, IgnorablePolicy::Keep),
+                    hfs_cmp(a, b)
+                );
+            }
+        }
+    }
+}
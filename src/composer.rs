@@ -0,0 +1,175 @@
+use super::canonical_order::MarkBuffer;
+use super::hangul::{compose_hangul_jamos, is_hangul_conjoinable_jamo};
+use super::profile::NormalizationProfile;
+use super::reverse_tree::{CharMap, ReverseTreeNode};
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// Resumable state machine restoring a commonly encoded string from HFS+ decomposed characters
+///
+/// Combining marks are sorted in the canonical order before composed. Characters are fed one by one with
+/// [`Composer::push`], and the composed ones are written as soon as they are determined. [`Composer::finish`] must be
+/// called after the last character to flush the pending ones.
+pub struct Composer {
+    profile: NormalizationProfile,
+    /// Root dictionary of `profile`
+    root_dict: CharMap<ReverseTreeNode>,
+    /// (Sub)dictionary used to look up the next character
+    referencing_dict: CharMap<ReverseTreeNode>,
+    /// Characters consumed by the dictionary walk that are not composed (yet)
+    pending_chars: String,
+    /// Conjoinable Hangul jamos waiting to be composed into syllables
+    pending_hangul_jamos: String,
+    /// The longest composed character found so far in the dictionary walk
+    tentative_composed: Option<char>,
+    /// Combining marks not yet sorted in the canonical order
+    marks: MarkBuffer,
+}
+
+impl Composer {
+    /// Create a state machine instance composing characters according to `profile`.
+    pub fn with_profile(profile: NormalizationProfile) -> Self {
+        let root_dict = profile.composition_table();
+        Self {
+            profile,
+            root_dict,
+            referencing_dict: root_dict,
+            pending_chars: String::new(),
+            pending_hangul_jamos: String::new(),
+            tentative_composed: None,
+            marks: MarkBuffer::new(),
+        }
+    }
+
+    /// Feeds a character and writes the characters determined by it to `output`
+    pub fn push<W: Write + ?Sized>(&mut self, c: char, output: &mut W) -> fmt::Result {
+        match self.profile.combining_class(c) {
+            0 => {
+                self.flush_marks(output)?;
+                self.push_ordered(c, output)
+            }
+            class => {
+                self.marks.push(class, c);
+                Ok(())
+            }
+        }
+    }
+
+    /// Feeds the buffered marks in the canonical order to the dictionary walk
+    #[inline]
+    fn flush_marks<W: Write + ?Sized>(&mut self, output: &mut W) -> fmt::Result {
+        if self.marks.is_empty() {
+            Ok(())
+        } else {
+            self.push_marks(output)
+        }
+    }
+
+    fn push_marks<W: Write + ?Sized>(&mut self, output: &mut W) -> fmt::Result {
+        let mut marks = core::mem::replace(&mut self.marks, MarkBuffer::new());
+        for c in marks.drain_sorted() {
+            self.push_ordered(c, output)?;
+        }
+        self.marks = marks;
+        Ok(())
+    }
+
+    /// Walks the dictionary with a character, with the combining marks sorted already
+    fn push_ordered<W: Write + ?Sized>(&mut self, c: char, output: &mut W) -> fmt::Result {
+        loop {
+            match self.referencing_dict.get(&c) {
+                None
+                | Some(ReverseTreeNode {
+                    current: None,
+                    next: None,
+                }) => {
+                    let mut try_again = false;
+                    if let Some(ch) = self.tentative_composed.take() {
+                        self.flush_hangul_jamos(output)?;
+                        output.write_char(ch)?;
+                        try_again = true;
+                    }
+                    if !self.pending_chars.is_empty() {
+                        self.flush_hangul_jamos(output)?;
+                        output.write_str(&self.pending_chars)?;
+                        self.pending_chars.clear();
+                        try_again = true;
+                    }
+                    self.referencing_dict = self.root_dict;
+                    if try_again {
+                        continue;
+                    }
+                    // Out of the Apple's table
+
+                    // Korean hangul jamo
+                    if is_hangul_conjoinable_jamo(c) {
+                        self.pending_hangul_jamos.push(c);
+                    } else {
+                        self.flush_hangul_jamos(output)?;
+                        output.write_char(c)?;
+                    }
+                    return Ok(());
+                }
+                Some(ReverseTreeNode {
+                    current: None,
+                    next: Some(sub_dict),
+                }) => {
+                    self.referencing_dict = *sub_dict;
+                    self.pending_chars.push(c);
+                    return Ok(());
+                }
+                Some(ReverseTreeNode {
+                    current: Some(composed_char),
+                    next: Some(sub_dict),
+                }) => {
+                    // All the characters consumed so far are represented by the tentative one
+                    self.referencing_dict = *sub_dict;
+                    self.tentative_composed = Some(*composed_char);
+                    self.pending_chars.clear();
+                    return Ok(());
+                }
+                Some(ReverseTreeNode {
+                    current: Some(composed_char),
+                    next: None,
+                }) => {
+                    self.flush_hangul_jamos(output)?;
+                    self.pending_chars.clear();
+                    output.write_char(*composed_char)?;
+                    self.tentative_composed = None;
+                    self.referencing_dict = self.root_dict;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Writes all the pending characters to `output` and resets the state
+    pub fn finish<W: Write + ?Sized>(&mut self, output: &mut W) -> fmt::Result {
+        self.flush_marks(output)?;
+        self.flush_hangul_jamos(output)?;
+        if let Some(c) = self.tentative_composed.take() {
+            output.write_char(c)?;
+        }
+        if !self.pending_chars.is_empty() {
+            output.write_str(&self.pending_chars)?;
+            self.pending_chars.clear();
+        }
+        self.referencing_dict = self.root_dict;
+        Ok(())
+    }
+
+    #[inline]
+    fn flush_hangul_jamos<W: Write + ?Sized>(&mut self, output: &mut W) -> fmt::Result {
+        if self.pending_hangul_jamos.is_empty() {
+            Ok(())
+        } else {
+            self.compose_hangul_jamos(output)
+        }
+    }
+
+    fn compose_hangul_jamos<W: Write + ?Sized>(&mut self, output: &mut W) -> fmt::Result {
+        output.write_str(&compose_hangul_jamos(&self.pending_hangul_jamos))?;
+        self.pending_hangul_jamos.clear();
+        Ok(())
+    }
+}
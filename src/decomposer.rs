@@ -0,0 +1,89 @@
+use super::canonical_order::MarkBuffer;
+use super::hangul::{decomopse_hangul_syllable, is_hangul_precomposed_syllable};
+use super::profile::NormalizationProfile;
+use super::reverse_tree::CharMap;
+use core::fmt::{self, Write};
+
+/// The first character decomposed by any profile, below which no combining marks exist either
+const FIRST_DECOMPOSABLE: char = '\u{00C0}';
+
+/// Resumable state machine applying the HFS+ decomposition and the canonical ordering of combining marks
+///
+/// Characters are fed one by one with [`Decomposer::push`], and the decomposed ones are written as soon as they are
+/// determined. [`Decomposer::finish`] must be called after the last character to flush the pending marks.
+pub struct Decomposer {
+    profile: NormalizationProfile,
+    /// Decomposition table of `profile`
+    table: CharMap<&'static str>,
+    /// Combining marks following the last written starter
+    marks: MarkBuffer,
+}
+
+impl Decomposer {
+    /// Create a state machine instance.
+    pub fn new() -> Self {
+        Self::with_profile(NormalizationProfile::HfsPlus)
+    }
+
+    /// Create a state machine instance decomposing characters according to `profile`.
+    pub fn with_profile(profile: NormalizationProfile) -> Self {
+        Self {
+            profile,
+            table: profile.decomposition_table(),
+            marks: MarkBuffer::new(),
+        }
+    }
+
+    /// Feeds a character and writes the characters determined by it to `output`
+    pub fn push<W: Write + ?Sized>(&mut self, c: char, output: &mut W) -> fmt::Result {
+        if c < FIRST_DECOMPOSABLE {
+            // Neither decomposed nor a combining mark
+            self.finish(output)?;
+            return output.write_char(c);
+        }
+        match self.table.get(&c) {
+            Some(decomposed) => {
+                for d in decomposed.chars() {
+                    self.push_decomposed(d, output)?;
+                }
+                Ok(())
+            }
+            None if is_hangul_precomposed_syllable(c) => {
+                // All the conjoining jamos are starters
+                self.finish(output)?;
+                output.write_str(&decomopse_hangul_syllable(c))
+            }
+            None => self.push_decomposed(c, output),
+        }
+    }
+
+    fn push_decomposed<W: Write + ?Sized>(&mut self, c: char, output: &mut W) -> fmt::Result {
+        match self.profile.combining_class(c) {
+            0 => {
+                self.finish(output)?;
+                output.write_char(c)
+            }
+            class => {
+                self.marks.push(class, c);
+                Ok(())
+            }
+        }
+    }
+
+    /// Writes all the pending marks to `output`
+    #[inline]
+    pub fn finish<W: Write + ?Sized>(&mut self, output: &mut W) -> fmt::Result {
+        if self.marks.is_empty() {
+            Ok(())
+        } else {
+            self.write_marks(output)
+        }
+    }
+
+    fn write_marks<W: Write + ?Sized>(&mut self, output: &mut W) -> fmt::Result {
+        for c in self.marks.drain_sorted() {
+            output.write_char(c)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+/// Errors reported by the conversion functions of this crate
+///
+/// It implements [`core::error::Error`] (and so `std::error::Error`) with or without the `std` feature. New variants
+/// may be added in minor releases.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// A byte sequence expected to be UTF-16 has an odd length
+    #[error("UTF-16 byte sequence has an odd length ({0} bytes)")]
+    OddUtf16Length(usize),
+    /// A name is longer than the limit of `HFSUniStr255` after the HFS+ decomposition
+    #[error("name has {0} UTF-16 code units after the decomposition, exceeding the limit of 255")]
+    NameTooLong(usize),
+    /// A byte sequence ends before the end of the `HFSUniStr255` it should contain
+    #[error("HFSUniStr255 needs {expected} bytes but only {actual} bytes are given")]
+    TruncatedHfsUniStr255 { expected: usize, actual: usize },
+    /// A byte string contains an invalid UTF-8 sequence at the given byte offset
+    #[error("invalid UTF-8 sequence at byte {0}")]
+    InvalidUtf8(usize),
+    /// A byte string contains a sequence not defined in the legacy Mac OS text encoding at the given byte offset
+    #[error("byte sequence at {0} is not defined in the Mac text encoding")]
+    UnmappableMacEncoding(usize),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display_test() {
+        fn assert_error<E: core::error::Error>(_: &E) {}
+        assert_error(&Error::InvalidUtf8(0));
+        assert_eq!(
+            Error::OddUtf16Length(3).to_string(),
+            "UTF-16 byte sequence has an odd length (3 bytes)"
+        );
+        assert_eq!(
+            Error::TruncatedHfsUniStr255 {
+                expected: 6,
+                actual: 5
+            }
+            .to_string(),
+            "HFSUniStr255 needs 6 bytes but only 5 bytes are given"
+        );
+    }
+}
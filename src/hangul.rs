@@ -1,3 +1,6 @@
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
 const SBASE: u32 = 0xAC00;
 const LBASE: u32 = 0x1100;
 const VBASE: u32 = 0x1161;
@@ -8,16 +11,39 @@ const TCOUNT: u32 = 28;
 const NCOUNT: u32 = 588; // (VCount * TCount)
 const SCOUNT: u32 = 11172; // (LCount * NCount)
 
+#[inline]
 pub fn is_hangul_precomposed_syllable(ch: char) -> bool {
-    return '\u{AC00}' <= ch && ch <= '\u{D7A3}';
+    ('\u{AC00}'..='\u{D7A3}').contains(&ch)
 }
 
+#[inline]
 pub fn is_hangul_conjoinable_jamo(ch: char) -> bool {
-    return '\u{1100}' <= ch && ch <= '\u{1112}'
-        || '\u{1161}' <= ch && ch <= '\u{1175}'
-        || '\u{11A8}' <= ch && ch <= '\u{11C2}';
+    ('\u{1100}'..='\u{1112}').contains(&ch)
+        || ('\u{1161}'..='\u{1175}').contains(&ch)
+        || ('\u{11A8}'..='\u{11C2}').contains(&ch)
+}
+
+#[inline]
+pub fn is_hangul_leading_jamo(ch: char) -> bool {
+    (LBASE..LBASE + LCOUNT).contains(&(ch as u32))
+}
+
+/// Number of jamos a precomposed syllable is decomposed into (2 or 3)
+pub fn hangul_syllable_jamo_count(syllable: char) -> usize {
+    let tindex = (syllable as u32 - SBASE) % TCOUNT;
+    if tindex == 0 {
+        2
+    } else {
+        3
+    }
 }
 
+/// Whether `compose_hangul_jamos` combines `first` and `second` (a leading consonant and a vowel) into a syllable
+pub fn is_hangul_composable_pair(first: char, second: char) -> bool {
+    is_hangul_leading_jamo(first) && (VBASE..VBASE + VCOUNT).contains(&(second as u32))
+}
+
+#[cfg(feature = "alloc")]
 pub fn compose_hangul_jamos(source: &str) -> String {
     if source.is_empty() {
         return "".to_string();
@@ -34,7 +60,7 @@ pub fn compose_hangul_jamos(source: &str) -> String {
                 if let Some(vindex) = u32::checked_sub(ch as u32, VBASE) {
                     if vindex < VCOUNT {
                         // make syllable of form LV
-                        last = std::char::from_u32(SBASE + (lindex * VCOUNT + vindex) * TCOUNT)
+                        last = core::char::from_u32(SBASE + (lindex * VCOUNT + vindex) * TCOUNT)
                             .unwrap();
                         tentative_composed_syllable = Some(last); // reset last
 
@@ -49,7 +75,7 @@ pub fn compose_hangul_jamos(source: &str) -> String {
                 if let Some(tindex) = u32::checked_sub(ch as u32, TBASE) {
                     if tindex < TCOUNT {
                         // make syllable of form LVT
-                        last = std::char::from_u32(last as u32 + tindex).unwrap();
+                        last = core::char::from_u32(last as u32 + tindex).unwrap();
                         // reset last
                         tentative_composed_syllable = Some(last);
                         continue; // discard ch
@@ -71,9 +97,10 @@ pub fn compose_hangul_jamos(source: &str) -> String {
     } else {
         result.push(last);
     }
-    return result;
+    result
 }
 
+#[cfg(feature = "alloc")]
 pub fn decomopse_hangul_syllable(syllable: char) -> String {
     if !is_hangul_precomposed_syllable(syllable) {
         return syllable.to_string();
@@ -83,20 +110,20 @@ pub fn decomopse_hangul_syllable(syllable: char) -> String {
     let l = LBASE + sindex / NCOUNT;
     let v = VBASE + (sindex % NCOUNT) / TCOUNT;
     let t = TBASE + sindex % TCOUNT;
-    result.push(std::char::from_u32(l).unwrap());
-    result.push(std::char::from_u32(v).unwrap());
+    result.push(core::char::from_u32(l).unwrap());
+    result.push(core::char::from_u32(v).unwrap());
     if t != TBASE {
-        result.push(std::char::from_u32(t).unwrap());
+        result.push(core::char::from_u32(t).unwrap());
     }
-    return result;
+    result
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod test {
     use super::*;
 
     // (NFC, NFD)
-    static EXAMINEE: &[(&'static str, &'static str)] = &[
+    static EXAMINEE: &[(&str, &str)] = &[
         ("김갑환", "김갑환"),
         ("장거한", "장거한"),
         ("최번개", "최번개"),
@@ -111,7 +138,7 @@ mod test {
         ("비빔밥", "비빔밥"),
         ("삼성전자", "삼성전자"),
     ];
-    static MIXED_EXAMINEE: &[(&'static str, &'static str)] = &[
+    static MIXED_EXAMINEE: &[(&str, &str)] = &[
         (
             "《펌프 잇 업》(Pump It Up), 줄여서 펌프, 펌피럽은 안다미로가 개발한 리듬 게임이다.",
             "《펌프 잇 업》(Pump It Up), 줄여서 펌프, 펌피럽은 안다미로가 개발한 리듬 게임이다.",
@@ -125,9 +152,7 @@ mod test {
     #[test]
     fn hangul_precomposed_test() {
         for (composed, decomposed) in EXAMINEE {
-            assert!(composed
-                .chars()
-                .all(|ch| is_hangul_precomposed_syllable(ch)));
+            assert!(composed.chars().all(is_hangul_precomposed_syllable));
             assert!(decomposed
                 .chars()
                 .all(|ch| !is_hangul_precomposed_syllable(ch)));
@@ -136,7 +161,7 @@ mod test {
     #[test]
     fn hangul_jamo_test() {
         for (composed, decomposed) in EXAMINEE {
-            assert!(decomposed.chars().all(|ch| is_hangul_conjoinable_jamo(ch)));
+            assert!(decomposed.chars().all(is_hangul_conjoinable_jamo));
             assert!(composed.chars().all(|ch| !is_hangul_conjoinable_jamo(ch)));
         }
     }
@@ -146,14 +171,14 @@ mod test {
         for (composed, decomposed) in EXAMINEE {
             let genrated = composed
                 .chars()
-                .map(|ch| decomopse_hangul_syllable(ch))
+                .map(decomopse_hangul_syllable)
                 .collect::<String>();
             assert_eq!(&genrated, decomposed);
         }
         for (composed, decomposed) in MIXED_EXAMINEE {
             let genrated = composed
                 .chars()
-                .map(|ch| decomopse_hangul_syllable(ch))
+                .map(decomopse_hangul_syllable)
                 .collect::<String>();
             assert_eq!(&genrated, decomposed);
         }
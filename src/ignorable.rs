@@ -0,0 +1,126 @@
+//! Code points ignored by the comparison of names on HFS+ volumes
+//!
+//! `FastUnicodeCompare` in TN1150 skips the zero-width and directional formatting characters (U+200C–U+200F,
+//! U+202A–U+202E, U+206A–U+206F, U+FEFF), so names differing only in them are the same name on HFS+ even though they
+//! look different nowhere and compare unequal everywhere else.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// How the ignorable code points are handled by comparisons and key-building functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IgnorablePolicy {
+    /// Ignorable code points are treated as ordinary characters
+    #[default]
+    Keep,
+    /// Ignorable code points are removed before names are compared or used as keys, as HFS+ does
+    Strip,
+}
+
+/// Checks whether a character is ignored by the comparison of names on HFS+ volumes
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::is_hfs_ignorable;
+/// assert!(is_hfs_ignorable('\u{200D}'));
+/// assert!(is_hfs_ignorable('\u{FEFF}'));
+/// assert!(!is_hfs_ignorable('\u{200B}'));
+/// ```
+pub fn is_hfs_ignorable(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200C}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{206A}'..='\u{206F}' | '\u{FEFF}'
+    )
+}
+
+/// Lists the ignorable code points in a name with their byte positions
+///
+/// # Arguments
+///
+/// * `name` - A file name
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::find_hfs_ignorables;
+/// assert_eq!(
+///     find_hfs_ignorables("\u{FEFF}report\u{200E}.txt"),
+///     vec![(0, '\u{FEFF}'), (9, '\u{200E}')]
+/// );
+/// assert!(find_hfs_ignorables("report.txt").is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn find_hfs_ignorables(name: &str) -> Vec<(usize, char)> {
+    name.char_indices()
+        .filter(|&(_, c)| is_hfs_ignorable(c))
+        .collect()
+}
+
+/// Removes the ignorable code points from a name
+///
+/// # Arguments
+///
+/// * `name` - A file name
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::strip_hfs_ignorables;
+/// assert_eq!(strip_hfs_ignorables("\u{FEFF}report\u{200E}.txt"), "report.txt");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn strip_hfs_ignorables(name: &str) -> Cow<'_, str> {
+    if !name.contains(is_hfs_ignorable) {
+        return Cow::Borrowed(name);
+    }
+    Cow::Owned(name.chars().filter(|&c| !is_hfs_ignorable(c)).collect())
+}
+
+/// Removes the ignorable code points from a string if `policy` is [`IgnorablePolicy::Strip`]
+#[cfg(feature = "alloc")]
+pub(crate) fn apply_ignorable_policy(s: Cow<'_, str>, policy: IgnorablePolicy) -> Cow<'_, str> {
+    match (policy, s) {
+        (IgnorablePolicy::Keep, s) => s,
+        (IgnorablePolicy::Strip, Cow::Borrowed(s)) => strip_hfs_ignorables(s),
+        (IgnorablePolicy::Strip, Cow::Owned(s)) => {
+            Cow::Owned(strip_hfs_ignorables(&s).into_owned())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::case_table::hfs_lower_case;
+
+    #[test]
+    fn ignorable_matches_lower_case_table_test() {
+        for code in 1..=0xFFFF {
+            if let Some(c) = core::char::from_u32(code) {
+                assert_eq!(
+                    is_hfs_ignorable(c),
+                    hfs_lower_case(code as u16) == 0,
+                    "U+{:04X}",
+                    code
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn strip_test() {
+        assert!(matches!(strip_hfs_ignorables("abc"), Cow::Borrowed(_)));
+        assert_eq!(strip_hfs_ignorables("\u{202A}\u{202E}"), "");
+        assert_eq!(
+            apply_ignorable_policy(Cow::Borrowed("a\u{200C}"), IgnorablePolicy::Keep),
+            "a\u{200C}"
+        );
+        assert_eq!(
+            apply_ignorable_policy(Cow::Owned("a\u{200C}".to_string()), IgnorablePolicy::Strip),
+            "a"
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Lazy `char` iterator adaptors applying the HFS+ decomposition and composition
+
+use super::composer::Composer;
+use super::decomposer::Decomposer;
+use super::profile::NormalizationProfile;
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::iter::FusedIterator;
+use core::str::Chars;
+
+/// Queue of characters already converted but not yet yielded
+struct CharBuffer {
+    buffer: String,
+    position: usize,
+}
+
+impl CharBuffer {
+    fn new() -> Self {
+        Self {
+            buffer: String::new(),
+            position: 0,
+        }
+    }
+
+    fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    /// Returns the characters not yet yielded
+    fn as_str(&self) -> &str {
+        &self.buffer[self.position..]
+    }
+}
+
+impl Write for CharBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.position == self.buffer.len() {
+            self.buffer.clear();
+            self.position = 0;
+        }
+        self.buffer += s;
+        Ok(())
+    }
+}
+
+/// An iterator adaptor yielding the characters decomposed in the HFS+ way
+///
+/// Created by [`HfsNormalization::hfs_nfd_chars`] or [`HfsNormalization::hfs_nfd_chars_with_profile`].
+pub struct HfsDecompose<I> {
+    iter: I,
+    decomposer: Decomposer,
+    buffer: CharBuffer,
+    finished: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for HfsDecompose<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.buffer.pop() {
+                return Some(c);
+            }
+            if self.finished {
+                return None;
+            }
+            match self.iter.next() {
+                Some(c) => self.decomposer.push(c, &mut self.buffer),
+                None => {
+                    self.finished = true;
+                    self.decomposer.finish(&mut self.buffer)
+                }
+            }
+            .expect("writing to a String never fails");
+        }
+    }
+
+    /// Every character fed yields at least one character, while the upper bound is unknown.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.as_str().chars().count();
+        let (lower, _) = if self.finished {
+            (0, None)
+        } else {
+            self.iter.size_hint()
+        };
+        (buffered.saturating_add(lower), None)
+    }
+}
+
+impl<I: Iterator<Item = char>> FusedIterator for HfsDecompose<I> {}
+
+impl<I: fmt::Debug> fmt::Debug for HfsDecompose<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HfsDecompose")
+            .field("iter", &self.iter)
+            .field("buffered", &self.buffer.as_str())
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+/// An iterator adaptor yielding the characters restored from the HFS+ decomposition
+///
+/// Created by [`HfsNormalization::hfs_nfc_chars`] or [`HfsNormalization::hfs_nfc_chars_with_profile`].
+pub struct HfsCompose<I> {
+    iter: I,
+    composer: Composer,
+    buffer: CharBuffer,
+    finished: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for HfsCompose<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.buffer.pop() {
+                return Some(c);
+            }
+            if self.finished {
+                return None;
+            }
+            match self.iter.next() {
+                Some(c) => self.composer.push(c, &mut self.buffer),
+                None => {
+                    self.finished = true;
+                    self.composer.finish(&mut self.buffer)
+                }
+            }
+            .expect("writing to a String never fails");
+        }
+    }
+
+    /// Any number of fed characters may be composed into one, so at least one more character is certain at most.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.as_str().chars().count();
+        let remaining = !self.finished && self.iter.size_hint().0 > 0;
+        (buffered + remaining as usize, None)
+    }
+}
+
+impl<I: Iterator<Item = char>> FusedIterator for HfsCompose<I> {}
+
+impl<I: fmt::Debug> fmt::Debug for HfsCompose<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HfsCompose")
+            .field("iter", &self.iter)
+            .field("buffered", &self.buffer.as_str())
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+/// Methods to lazily apply the HFS+ decomposition and composition to `str` and `char` iterators
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{HfsNormalization, NormalizationProfile};
+/// assert_eq!("Pok\u{00E9}mon".hfs_nfd_chars().collect::<String>(), "Poke\u{0301}mon");
+/// let restored = "Poke\u{0301}mon.txt"
+///     .chars()
+///     .take_while(|&c| c != '.')
+///     .hfs_nfc_chars()
+///     .collect::<String>();
+/// assert_eq!(restored, "Pok\u{00E9}mon");
+/// let decomposed = "\u{03AC}"
+///     .hfs_nfd_chars_with_profile(NormalizationProfile::Unicode32)
+///     .collect::<String>();
+/// assert_eq!(decomposed, "\u{03B1}\u{0301}");
+/// ```
+pub trait HfsNormalization<I: Iterator<Item = char>> {
+    /// Returns an iterator over the characters decomposed like [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd)
+    fn hfs_nfd_chars(self) -> HfsDecompose<I>;
+    /// Returns an iterator over the characters restored like [`compose_from_hfs_nfd`](crate::compose_from_hfs_nfd)
+    fn hfs_nfc_chars(self) -> HfsCompose<I>;
+    /// Returns an iterator over the characters decomposed according to `profile`
+    fn hfs_nfd_chars_with_profile(self, profile: NormalizationProfile) -> HfsDecompose<I>;
+    /// Returns an iterator over the characters restored from the decomposition according to `profile`
+    fn hfs_nfc_chars_with_profile(self, profile: NormalizationProfile) -> HfsCompose<I>;
+}
+
+impl<'a> HfsNormalization<Chars<'a>> for &'a str {
+    fn hfs_nfd_chars(self) -> HfsDecompose<Chars<'a>> {
+        self.chars().hfs_nfd_chars()
+    }
+
+    fn hfs_nfc_chars(self) -> HfsCompose<Chars<'a>> {
+        self.chars().hfs_nfc_chars()
+    }
+
+    fn hfs_nfd_chars_with_profile(self, profile: NormalizationProfile) -> HfsDecompose<Chars<'a>> {
+        self.chars().hfs_nfd_chars_with_profile(profile)
+    }
+
+    fn hfs_nfc_chars_with_profile(self, profile: NormalizationProfile) -> HfsCompose<Chars<'a>> {
+        self.chars().hfs_nfc_chars_with_profile(profile)
+    }
+}
+
+impl<I: Iterator<Item = char>> HfsNormalization<I> for I {
+    fn hfs_nfd_chars(self) -> HfsDecompose<I> {
+        self.hfs_nfd_chars_with_profile(NormalizationProfile::HfsPlus)
+    }
+
+    fn hfs_nfc_chars(self) -> HfsCompose<I> {
+        self.hfs_nfc_chars_with_profile(NormalizationProfile::HfsPlus)
+    }
+
+    fn hfs_nfd_chars_with_profile(self, profile: NormalizationProfile) -> HfsDecompose<I> {
+        HfsDecompose {
+            iter: self,
+            decomposer: Decomposer::with_profile(profile),
+            buffer: CharBuffer::new(),
+            finished: false,
+        }
+    }
+
+    fn hfs_nfc_chars_with_profile(self, profile: NormalizationProfile) -> HfsCompose<I> {
+        HfsCompose {
+            iter: self,
+            composer: Composer::with_profile(profile),
+            buffer: CharBuffer::new(),
+            finished: false,
+        }
+    }
+}
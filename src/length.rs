@@ -0,0 +1,127 @@
+//! Prediction of the length of names after the HFS+ decomposition and truncation to fit in the limit
+
+use super::canonical_order::canonical_combining_class;
+use super::code_table::{MAP_TO_HFS, TRAILING_COMPONENTS};
+use super::hangul::{
+    hangul_syllable_jamo_count, is_hangul_conjoinable_jamo, is_hangul_leading_jamo,
+    is_hangul_precomposed_syllable,
+};
+/// Whether `c` is attached to the preceding character and must not be separated from it
+///
+/// Combining marks are attached whether they appear in the HFS+ table or not. The trailing components of the table
+/// also include some characters of the combining class 0 (e.g. U+09BE in U+09CB).
+fn is_trailing_component(c: char) -> bool {
+    canonical_combining_class(c) != 0
+        || TRAILING_COMPONENTS.binary_search(&c).is_ok()
+        || (is_hangul_conjoinable_jamo(c) && !is_hangul_leading_jamo(c))
+}
+
+/// Number of UTF-16 code units of the HFS+ decomposition of a character
+fn decomposed_utf16_len(c: char) -> usize {
+    match MAP_TO_HFS.get(&c) {
+        Some(decomposed) => decomposed.encode_utf16().count(),
+        None if is_hangul_precomposed_syllable(c) => hangul_syllable_jamo_count(c),
+        None => c.len_utf16(),
+    }
+}
+
+/// Computes the number of UTF-16 code units after [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd) is
+/// applied, without decomposing the string
+///
+/// # Arguments
+///
+/// * `input` - A string to be measured
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::hfs_nfd_utf16_len;
+/// assert_eq!(hfs_nfd_utf16_len("Pok\u{00E9}mon"), 8);
+/// assert_eq!(hfs_nfd_utf16_len("\u{D55C}\u{1F600}"), 5);
+/// ```
+pub fn hfs_nfd_utf16_len(input: &str) -> usize {
+    input.chars().map(decomposed_utf16_len).sum()
+}
+
+/// Shortens a string so that it fits in `max_units` UTF-16 code units after
+/// [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd) is applied
+///
+/// The string is cut only before a character that starts a new combining sequence, so that a base character is never
+/// separated from the combining marks attached to it (nor a Hangul syllable from its vowel or trailing consonant).
+///
+/// # Arguments
+///
+/// * `input` - A string to be shortened
+/// * `max_units` - The maximum number of UTF-16 code units after the decomposition (255 for HFS+)
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::truncate_to_hfs_limit;
+/// // "é" takes 2 code units after the decomposition
+/// assert_eq!(truncate_to_hfs_limit("caf\u{00E9}s", 4), "caf");
+/// assert_eq!(truncate_to_hfs_limit("caf\u{00E9}s", 5), "caf\u{00E9}");
+/// // "e" and U+0301 are never separated
+/// assert_eq!(truncate_to_hfs_limit("cafe\u{0301}s", 4), "caf");
+/// ```
+pub fn truncate_to_hfs_limit(input: &str, max_units: usize) -> &str {
+    let mut units = 0;
+    let mut boundary = 0;
+    for (index, c) in input.char_indices() {
+        if !is_trailing_component(c) {
+            boundary = index;
+        }
+        units += decomposed_utf16_len(c);
+        if units > max_units {
+            return &input[..boundary];
+        }
+    }
+    input
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::decompose_into_hfs_nfd;
+
+    static EXAMINEE: &[&str] = &[
+        "Pok\u{00E9}mon",
+        "Poke\u{0301}mon",
+        "\u{1F8A}\u{30DD}\u{1F600}",
+        "\u{D55C}\u{AE00}\u{1112}\u{1161}\u{11AB}",
+        "\u{1E69}\u{0323}\u{00E9}",
+        // Combining marks out of the HFS+ table
+        "e\u{20D0}\u{20D0}x\u{1D165}",
+    ];
+
+    #[test]
+    fn utf16_len_test() {
+        for s in EXAMINEE {
+            assert_eq!(
+                hfs_nfd_utf16_len(s),
+                decompose_into_hfs_nfd(s).encode_utf16().count()
+            );
+        }
+    }
+
+    #[test]
+    fn truncate_test() {
+        for s in EXAMINEE {
+            let length = hfs_nfd_utf16_len(s);
+            assert_eq!(truncate_to_hfs_limit(s, length), *s);
+            for max_units in 0..length {
+                let truncated = truncate_to_hfs_limit(s, max_units);
+                assert!(hfs_nfd_utf16_len(truncated) <= max_units);
+                let rest = &s[truncated.len()..];
+                assert!(!rest.starts_with(is_trailing_component), "{:?}", rest);
+            }
+        }
+        assert_eq!(truncate_to_hfs_limit("\u{1112}\u{1161}\u{11AB}", 2), "");
+        assert_eq!(truncate_to_hfs_limit("a\u{1E69}\u{0323}", 4), "a");
+        assert_eq!(truncate_to_hfs_limit("e\u{20D0}\u{20D0}x", 2), "");
+        assert_eq!(
+            truncate_to_hfs_limit("e\u{20D0}\u{20D0}x", 3),
+            "e\u{20D0}\u{20D0}"
+        );
+    }
+}
@@ -1,17 +1,126 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(feature = "bench", feature(test))]
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "bench")]
 extern crate test;
+#[cfg(feature = "alloc")]
+mod apfs;
+#[cfg(feature = "alloc")]
+mod bytes;
+mod canonical_order;
+#[cfg(feature = "alloc")]
+mod case_folding_table;
+mod case_table;
+#[rustfmt::skip]
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
 mod code_table;
 #[cfg(test)]
 mod code_table_test;
+#[rustfmt::skip]
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+mod code_table_unicode32;
+#[rustfmt::skip]
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+mod code_table_unicode_latest;
+#[cfg(feature = "alloc")]
+mod collision;
+#[rustfmt::skip]
+mod combining_class_table;
+mod compare;
+#[cfg(feature = "alloc")]
+mod composer;
+#[cfg(feature = "alloc")]
+mod decomposer;
+mod error;
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
 mod hangul;
+mod ignorable;
+#[cfg(feature = "alloc")]
+mod iter;
+mod length;
+mod mac_encoding;
+#[cfg(feature = "alloc")]
+mod mac_encoding_table;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "alloc")]
+mod posix;
+mod profile;
+#[cfg(feature = "std")]
+mod rename;
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
 mod reverse_tree;
-use code_table::{MAP_TO_HFS, MAP_TO_NORMAL};
-use hangul::{
-    compose_hangul_jamos, decomopse_hangul_syllable, is_hangul_conjoinable_jamo,
-    is_hangul_precomposed_syllable,
+#[cfg(feature = "alloc")]
+mod sfm;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "alloc")]
+mod uni_str;
+#[cfg(feature = "alloc")]
+mod utf16;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+pub use apfs::{
+    apfs_name_hash, apfs_name_hash_with_profile, apfs_name_len_and_hash,
+    apfs_name_len_and_hash_with_profile,
+};
+#[cfg(feature = "alloc")]
+pub use bytes::{
+    compose_bytes, compose_bytes_with_policy, decompose_bytes, decompose_bytes_with_policy,
+    InvalidUtf8Policy,
+};
+#[cfg(feature = "alloc")]
+pub use collision::{find_hfs_collisions, find_hfs_collisions_with_policy, CollisionGroup};
+pub use compare::fast_unicode_compare;
+#[cfg(feature = "alloc")]
+pub use compare::{
+    hfs_case_fold, hfs_case_fold_with_policy, hfs_cmp, hfs_cmp_with_policy, hfs_cmp_with_profile,
+    hfs_eq, hfs_eq_with_policy, hfs_eq_with_profile,
+};
+#[cfg(feature = "alloc")]
+use core::fmt::{self, Write};
+pub use error::Error;
+#[cfg(feature = "alloc")]
+pub use ignorable::{find_hfs_ignorables, strip_hfs_ignorables};
+pub use ignorable::{is_hfs_ignorable, IgnorablePolicy};
+#[cfg(feature = "alloc")]
+pub use iter::{HfsCompose, HfsDecompose, HfsNormalization};
+pub use length::{hfs_nfd_utf16_len, truncate_to_hfs_limit};
+#[cfg(feature = "alloc")]
+pub use mac_encoding::decode_mac_into_hfs_nfd;
+pub use mac_encoding::MacEncoding;
+#[cfg(feature = "std")]
+pub use path::{Direction, HfsPathNormalization};
+#[cfg(feature = "alloc")]
+pub use posix::{hfs_name_to_posix, posix_name_to_hfs};
+#[cfg(feature = "alloc")]
+pub use profile::{
+    compose_from_hfs_nfd_cow_with_profile, compose_from_hfs_nfd_into_with_profile,
+    compose_from_hfs_nfd_with_profile, compose_from_hfs_nfd_write_with_profile,
+    decompose_into_hfs_nfd_cow_with_profile, decompose_into_hfs_nfd_into_with_profile,
+    decompose_into_hfs_nfd_with_profile, decompose_into_hfs_nfd_write_with_profile,
+};
+pub use profile::{is_hfs_composed_with_profile, is_hfs_nfd_with_profile, NormalizationProfile};
+#[cfg(feature = "std")]
+pub use rename::{RenameRecord, RenameStatus, TreeRenamer};
+#[cfg(feature = "alloc")]
+pub use sfm::{compose_from_sfm, decode_sfm, decompose_into_sfm, encode_sfm};
+#[cfg(feature = "std")]
+pub use stream::{HfsNfcReader, HfsNfcWriter, HfsNfdReader, HfsNfdWriter};
+#[cfg(feature = "alloc")]
+pub use uni_str::HfsUniStr255;
+#[cfg(feature = "alloc")]
+pub use utf16::{
+    compose_from_hfs_nfd_utf16, compose_from_hfs_nfd_utf16_be,
+    compose_from_hfs_nfd_utf16_be_with_profile, compose_from_hfs_nfd_utf16_with_profile,
+    compose_utf16, compose_utf16_be, compose_utf16_be_with_profile, compose_utf16_with_profile,
+    decompose_into_hfs_nfd_utf16, decompose_into_hfs_nfd_utf16_with_profile, decompose_utf16,
+    decompose_utf16_be, decompose_utf16_be_with_profile, decompose_utf16_with_profile,
 };
-use reverse_tree::ReverseTreeNode;
 
 /// Applies the Unicode decomposition similar to NFD used in HFS+
 ///
@@ -25,22 +134,93 @@ use reverse_tree::ReverseTreeNode;
 /// use hfs_nfd::decompose_into_hfs_nfd;
 /// assert_eq!(&decompose_into_hfs_nfd("Pok\u{00E9}mon"), "Poke\u{0301}mon");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn decompose_into_hfs_nfd(input: &str) -> String {
     let mut result = String::new();
+    decompose_into_hfs_nfd_into(input, &mut result);
+    result
+}
 
-    for c in input.chars() {
-        match MAP_TO_HFS.get(&c) {
-            None => {
-                if is_hangul_precomposed_syllable(c) {
-                    result += &decomopse_hangul_syllable(c).into_boxed_str();
-                } else {
-                    result.push(c);
-                }
-            }
-            Some(&decomposed) => result += decomposed,
-        }
-    }
-    return result;
+/// Applies the Unicode decomposition similar to NFD used in HFS+, borrowing the input if it is left unchanged
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decompose_into_hfs_nfd_cow;
+/// use std::borrow::Cow;
+/// assert_eq!(decompose_into_hfs_nfd_cow("Pok\u{00E9}mon"), "Poke\u{0301}mon");
+/// assert!(matches!(decompose_into_hfs_nfd_cow("Pikachu"), Cow::Borrowed(_)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decompose_into_hfs_nfd_cow(input: &str) -> Cow<'_, str> {
+    decompose_into_hfs_nfd_cow_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Checks whether a string is left unchanged by [`decompose_into_hfs_nfd`] without allocating
+///
+/// # Arguments
+///
+/// * `input` - A string to be checked
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::is_hfs_nfd;
+/// assert!(is_hfs_nfd("Poke\u{0301}mon"));
+/// assert!(!is_hfs_nfd("Pok\u{00E9}mon"));
+/// ```
+pub fn is_hfs_nfd(input: &str) -> bool {
+    is_hfs_nfd_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Applies the Unicode decomposition similar to NFD used in HFS+ and appends the result to an existing buffer
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+/// * `output` - A buffer the decomposed string is appended to
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decompose_into_hfs_nfd_into;
+/// let mut buffer = String::from("/Users/");
+/// decompose_into_hfs_nfd_into("Pok\u{00E9}mon", &mut buffer);
+/// assert_eq!(buffer, "/Users/Poke\u{0301}mon");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decompose_into_hfs_nfd_into(input: &str, output: &mut String) {
+    decompose_into_hfs_nfd_write(input, output).expect("writing to a String never fails");
+}
+
+/// Applies the Unicode decomposition similar to NFD used in HFS+ and writes the result to any [`fmt::Write`]
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+/// * `output` - A destination the decomposed string is written to
+///
+/// # Errors
+///
+/// Returns an error if `output` fails to be written.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decompose_into_hfs_nfd_write;
+/// use std::fmt::Write;
+/// let mut buffer = String::new();
+/// decompose_into_hfs_nfd_write("Pok\u{00E9}mon", &mut buffer).unwrap();
+/// write!(&mut buffer, " #{}", 25).unwrap();
+/// assert_eq!(buffer, "Poke\u{0301}mon #25");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decompose_into_hfs_nfd_write<W: Write + ?Sized>(input: &str, output: &mut W) -> fmt::Result {
+    decompose_into_hfs_nfd_write_with_profile(input, output, NormalizationProfile::HfsPlus)
 }
 
 /// Restores a commonly encoded string from one applied the Unicode decomposition similar to NFS used in HFS+ to
@@ -55,122 +235,102 @@ pub fn decompose_into_hfs_nfd(input: &str) -> String {
 /// use hfs_nfd::compose_from_hfs_nfd;
 /// assert_eq!(&compose_from_hfs_nfd("Poke\u{0301}mon"), "Pok\u{00E9}mon");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn compose_from_hfs_nfd(input: &str) -> String {
     let mut result = String::new();
-    let mut referencing_dict = &*MAP_TO_NORMAL;
-    let mut pending_chars = String::new();
-    let mut pending_hangul_jamos = String::new();
-    let mut tentative_determined_chars: Option<Box<String>> = None;
-    let mut tentative_composed = None;
+    compose_from_hfs_nfd_into(input, &mut result);
+    result
+}
 
-    for c in input.chars() {
-        loop {
-            match referencing_dict.get(&c) {
-                None
-                | Some(ReverseTreeNode {
-                    current: None,
-                    next: None,
-                }) => {
-                    let mut try_again = false;
-                    if let Some(ch) = tentative_composed {
-                        if !pending_hangul_jamos.is_empty() {
-                            result += &compose_hangul_jamos(&pending_hangul_jamos).into_boxed_str();
-                            pending_hangul_jamos.clear();
-                        }
-                        result.push(ch);
-                        tentative_composed = None;
-                        try_again = true;
-                        tentative_determined_chars = None;
-                    }
-                    if !pending_chars.is_empty() {
-                        if !pending_hangul_jamos.is_empty() {
-                            result += &compose_hangul_jamos(&pending_hangul_jamos).into_boxed_str();
-                            pending_hangul_jamos.clear();
-                        }
-                        result += &pending_chars;
-                        try_again = true;
-                        pending_chars.clear();
-                    }
-                    referencing_dict = &*MAP_TO_NORMAL;
-                    if try_again {
-                        continue;
-                    }
-                    // Out of the Apple's table
+/// Restores a commonly encoded string like [`compose_from_hfs_nfd`], borrowing the input if it is left unchanged
+///
+/// # Arguments
+///
+/// * `input` - A string to be restored from
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_from_hfs_nfd_cow;
+/// use std::borrow::Cow;
+/// assert_eq!(compose_from_hfs_nfd_cow("Poke\u{0301}mon"), "Pok\u{00E9}mon");
+/// assert!(matches!(compose_from_hfs_nfd_cow("Pikachu"), Cow::Borrowed(_)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compose_from_hfs_nfd_cow(input: &str) -> Cow<'_, str> {
+    compose_from_hfs_nfd_cow_with_profile(input, NormalizationProfile::HfsPlus)
+}
 
-                    // Korean hangul jamo
-                    if is_hangul_conjoinable_jamo(c) {
-                        pending_hangul_jamos.push(c);
-                    } else {
-                        if !pending_hangul_jamos.is_empty() {
-                            result += &compose_hangul_jamos(&pending_hangul_jamos).into_boxed_str();
-                            pending_hangul_jamos.clear();
-                        }
-                        result.push(c);
-                    }
-                    break;
-                }
-                Some(ReverseTreeNode {
-                    current: None,
-                    next: Some(sub_dict),
-                }) => {
-                    referencing_dict = sub_dict.as_ref();
-                    pending_chars.push(c);
-                    break;
-                }
-                Some(ReverseTreeNode {
-                    current: Some(composed_char),
-                    next: Some(sub_dict),
-                }) => {
-                    referencing_dict = sub_dict.as_ref();
-                    tentative_composed = Some(*composed_char);
-                    match tentative_determined_chars.as_mut() {
-                        Some(existing_chars) => {
-                            existing_chars.push(c);
-                        }
-                        None => {
-                            pending_chars.push(c);
-                            tentative_determined_chars = Some(Box::from(pending_chars.clone()));
-                            pending_chars.clear();
-                        }
-                    }
-                    break;
-                }
-                Some(ReverseTreeNode {
-                    current: Some(composed_char),
-                    next: None,
-                }) => {
-                    if !pending_hangul_jamos.is_empty() {
-                        result += &compose_hangul_jamos(&pending_hangul_jamos).into_boxed_str();
-                        pending_hangul_jamos.clear();
-                    }
-                    pending_chars.clear();
-                    result.push(*composed_char);
-                    tentative_composed = None;
-                    tentative_determined_chars = None;
-                    referencing_dict = &*MAP_TO_NORMAL;
-                    break;
-                }
-            }
-        }
-    }
-    if let Some(c) = tentative_composed {
-        result.push(c);
-    }
-    if !pending_hangul_jamos.is_empty() {
-        result += &compose_hangul_jamos(&pending_hangul_jamos).into_boxed_str();
-    }
-    if !pending_chars.is_empty() {
-        result += &pending_chars;
-    }
-    return result;
+/// Checks whether a string is left unchanged by [`compose_from_hfs_nfd`]
+///
+/// The dictionary walk of the composition is followed without buffering the characters, so no allocation takes
+/// place.
+///
+/// # Arguments
+///
+/// * `input` - A string to be checked
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::is_hfs_composed;
+/// assert!(is_hfs_composed("Pok\u{00E9}mon"));
+/// assert!(!is_hfs_composed("Poke\u{0301}mon"));
+/// ```
+pub fn is_hfs_composed(input: &str) -> bool {
+    is_hfs_composed_with_profile(input, NormalizationProfile::HfsPlus)
 }
 
-#[cfg(test)]
+/// Restores a commonly encoded string like [`compose_from_hfs_nfd`] and appends the result to an existing buffer
+///
+/// # Arguments
+///
+/// * `input` - A string to be restored from
+/// * `output` - A buffer the restored string is appended to
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_from_hfs_nfd_into;
+/// let mut buffer = String::from("/Users/");
+/// compose_from_hfs_nfd_into("Poke\u{0301}mon", &mut buffer);
+/// assert_eq!(buffer, "/Users/Pok\u{00E9}mon");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compose_from_hfs_nfd_into(input: &str, output: &mut String) {
+    compose_from_hfs_nfd_write(input, output).expect("writing to a String never fails");
+}
+
+/// Restores a commonly encoded string like [`compose_from_hfs_nfd`] and writes the result to any [`fmt::Write`]
+///
+/// # Arguments
+///
+/// * `input` - A string to be restored from
+/// * `output` - A destination the restored string is written to
+///
+/// # Errors
+///
+/// Returns an error if `output` fails to be written.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_from_hfs_nfd_write;
+/// let mut buffer = String::new();
+/// compose_from_hfs_nfd_write("Poke\u{0301}mon", &mut buffer).unwrap();
+/// assert_eq!(buffer, "Pok\u{00E9}mon");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compose_from_hfs_nfd_write<W: Write + ?Sized>(input: &str, output: &mut W) -> fmt::Result {
+    compose_from_hfs_nfd_write_with_profile(input, output, NormalizationProfile::HfsPlus)
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
     #[cfg(feature = "bench")]
     use test::Bencher;
-    static EXAMINEE: &[(&'static str, &'static str)] = &[
+    static EXAMINEE: &[(&str, &str)] = &[
             ("Pokémonポケモン", "Pokémonポケモン"),
             ("ポプテピピック", "ポプテピピック"),
             (
@@ -190,7 +350,7 @@ mod tests {
             ("チョイ・ボンゲ최번개ハン・ジュリ한주리", "チョイ・ボンゲ최번개ハン・ジュリ한주리"),
             ("か카ka아a에éゲ게gé", "か카ka아a에éゲ게gé")
         ];
-    static EXAMINEE_IMMUTABLE: &[&'static str] = &[
+    static EXAMINEE_IMMUTABLE: &[&str] = &[
         "Immutable",
         "Can't be changed",
         "かわらない",
@@ -200,45 +360,209 @@ mod tests {
     #[test]
     fn decompose_fixed_strings_test() {
         for (composed, decomposed) in EXAMINEE {
-            let converted = decompose_into_hfs_nfd(*composed);
+            let converted = decompose_into_hfs_nfd(composed);
             assert_eq!(&converted, *decomposed);
         }
     }
     #[test]
     fn compose_from_fixed_strings_test() {
         for (composed, decomposed) in EXAMINEE {
-            let converted = compose_from_hfs_nfd(*decomposed);
+            let converted = compose_from_hfs_nfd(decomposed);
             assert_eq!(&converted, *composed);
         }
     }
     #[test]
     fn compose_already_composed_fixed_strings_test() {
         for (composed, _) in EXAMINEE {
-            let converted = compose_from_hfs_nfd(*composed);
+            let converted = compose_from_hfs_nfd(composed);
             assert_eq!(&converted, *composed);
         }
     }
     #[test]
     fn decompose_already_deomposed_identity_fixed_strings_test() {
         for (_, decomposed) in EXAMINEE {
-            let converted = decompose_into_hfs_nfd(*decomposed);
+            let converted = decompose_into_hfs_nfd(decomposed);
             assert_eq!(&converted, *decomposed);
         }
     }
     #[test]
     fn compose_immutable_fixed_strings_test() {
         for s in EXAMINEE_IMMUTABLE {
-            let converted = compose_from_hfs_nfd(*s);
+            let converted = compose_from_hfs_nfd(s);
             assert_eq!(&converted, *s);
         }
     }
     #[test]
     fn decompose_immutable_fixed_strings_test() {
         for s in EXAMINEE_IMMUTABLE {
-            let converted = decompose_into_hfs_nfd(*s);
+            let converted = decompose_into_hfs_nfd(s);
             assert_eq!(&converted, *s);
         }
     }
+    #[test]
+    fn decompose_cow_fixed_strings_test() {
+        for (composed, decomposed) in EXAMINEE {
+            assert_eq!(decompose_into_hfs_nfd_cow(composed), *decomposed);
+            assert!(matches!(
+                decompose_into_hfs_nfd_cow(decomposed),
+                Cow::Borrowed(_)
+            ));
+        }
+        for s in EXAMINEE_IMMUTABLE {
+            assert!(matches!(decompose_into_hfs_nfd_cow(s), Cow::Borrowed(_)));
+        }
+    }
+    #[test]
+    fn compose_cow_fixed_strings_test() {
+        for (composed, decomposed) in EXAMINEE {
+            assert_eq!(compose_from_hfs_nfd_cow(decomposed), *composed);
+            assert_eq!(compose_from_hfs_nfd_cow(composed), *composed);
+        }
+        for s in EXAMINEE_IMMUTABLE {
+            assert!(matches!(compose_from_hfs_nfd_cow(s), Cow::Borrowed(_)));
+        }
+        assert!(matches!(
+            compose_from_hfs_nfd_cow("Pok\u{00E9}mon \u{D55C}"),
+            Cow::Borrowed(_)
+        ));
+    }
+    #[test]
+    fn buffer_reusing_fixed_strings_test() {
+        let mut buffer = String::new();
+        for (composed, decomposed) in EXAMINEE {
+            buffer.clear();
+            decompose_into_hfs_nfd_into(composed, &mut buffer);
+            assert_eq!(&buffer, *decomposed);
+            buffer.clear();
+            compose_from_hfs_nfd_into(decomposed, &mut buffer);
+            assert_eq!(&buffer, *composed);
+        }
+        buffer.clear();
+        for (composed, _) in EXAMINEE {
+            decompose_into_hfs_nfd_into(composed, &mut buffer);
+        }
+        let expected = EXAMINEE.iter().map(|(_, nfd)| *nfd).collect::<String>();
+        assert_eq!(buffer, expected);
+    }
+    #[test]
+    fn iterator_fixed_strings_test() {
+        for (composed, decomposed) in EXAMINEE {
+            assert_eq!(&composed.hfs_nfd_chars().collect::<String>(), *decomposed);
+            assert_eq!(&decomposed.hfs_nfc_chars().collect::<String>(), *composed);
+            assert_eq!(
+                &decomposed
+                    .chars()
+                    .hfs_nfc_chars()
+                    .hfs_nfd_chars()
+                    .collect::<String>(),
+                *decomposed
+            );
+        }
+    }
+    #[test]
+    fn iterator_traits_test() {
+        fn assert_fused<I: core::iter::FusedIterator>(_: &I) {}
+        let input = "Pok\u{00E9}mon";
+        let mut decompose = input.hfs_nfd_chars();
+        assert_fused(&decompose);
+        assert_eq!(decompose.size_hint(), (input.chars().size_hint().0, None));
+        assert_eq!(decompose.nth(3), Some('e'));
+        // U+0301 is buffered until the next starter
+        assert!(decompose.size_hint().0 <= 4);
+        assert!(format!("{:?}", decompose).starts_with("HfsDecompose { iter: Chars("));
+        assert_eq!(decompose.by_ref().count(), 4);
+        assert_eq!(decompose.size_hint(), (0, None));
+        assert_eq!(decompose.next(), None);
+
+        let mut compose = "e\u{0301}".hfs_nfc_chars();
+        assert_fused(&compose);
+        assert_eq!(compose.size_hint(), (1, None));
+        assert_eq!(compose.next(), Some('\u{00E9}'));
+        assert_eq!(compose.size_hint(), (0, None));
+        assert_eq!(compose.next(), None);
+        assert!(format!("{:?}", compose).starts_with("HfsCompose { iter: Chars("));
+    }
+    #[test]
+    fn compose_hangul_jamos_before_pending_chars_test() {
+        assert_eq!(
+            &compose_from_hfs_nfd("\u{1112}\u{1161}o\u{0302}"),
+            "\u{D558}\u{00F4}"
+        );
+        assert_eq!(
+            &"\u{1112}\u{1161}o\u{0302}"
+                .hfs_nfc_chars()
+                .collect::<String>(),
+            "\u{D558}\u{00F4}"
+        );
+    }
+    #[test]
+    fn quick_check_fixed_strings_test() {
+        for (composed, decomposed) in EXAMINEE {
+            assert!(is_hfs_nfd(decomposed));
+            assert_eq!(is_hfs_nfd(composed), composed == decomposed);
+            assert!(is_hfs_composed(composed));
+            assert_eq!(is_hfs_composed(decomposed), composed == decomposed);
+        }
+        for s in EXAMINEE_IMMUTABLE {
+            assert!(is_hfs_nfd(s));
+            assert!(is_hfs_composed(s));
+        }
+        // A path in the dictionary that does not end with a composed character
+        assert!(is_hfs_composed("\u{03BF}\u{0345}"));
+    }
+
+    #[test]
+    fn quick_check_agrees_with_composition_test() {
+        // Starters, marks in the dictionary paths and out of them, and Hangul jamos
+        let chars = [
+            'a', 'e', 'o', '\u{03B1}', '\u{03BF}', '\u{30DB}', '\u{0300}', '\u{0301}', '\u{0302}',
+            '\u{0313}', '\u{0323}', '\u{0345}', '\u{20D0}', '\u{3099}', '\u{309A}', '\u{1100}',
+            '\u{1161}', '\u{11A8}',
+        ];
+        let mut s = String::new();
+        for &first in &chars {
+            for &second in &chars {
+                for &third in &chars {
+                    s.clear();
+                    s.extend([first, second, third]);
+                    assert_eq!(
+                        is_hfs_composed(&s),
+                        compose_from_hfs_nfd(&s) == s,
+                        "{:?}",
+                        s
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn canonical_ordering_fixed_strings_test() {
+        // U+0323 (below, 220) precedes U+0302 (above, 230)
+        for s in ["e\u{0302}\u{0323}", "e\u{0323}\u{0302}"] {
+            assert_eq!(decompose_into_hfs_nfd(s), "e\u{0323}\u{0302}");
+            assert_eq!(compose_from_hfs_nfd(s), "\u{1EC7}");
+            assert_eq!(s.hfs_nfd_chars().collect::<String>(), "e\u{0323}\u{0302}");
+            assert_eq!(s.hfs_nfc_chars().collect::<String>(), "\u{1EC7}");
+        }
+        assert_eq!(
+            decompose_into_hfs_nfd("\u{00EA}\u{0323}"),
+            "e\u{0323}\u{0302}"
+        );
+        assert!(!is_hfs_nfd("Ae\u{0302}\u{0323}"));
+        assert!(!is_hfs_composed("Ae\u{0302}\u{0323}"));
+        assert!(!is_hfs_composed("A\u{0302}\u{0323}b"));
+        assert_eq!(compose_from_hfs_nfd("A\u{0302}\u{0323}b"), "\u{1EAC}b");
+        match decompose_into_hfs_nfd_cow("xe\u{0302}\u{0323}") {
+            Cow::Owned(s) => assert_eq!(s, "xe\u{0323}\u{0302}"),
+            Cow::Borrowed(_) => panic!("marks out of order must be reordered"),
+        }
+        // Marks split by a starter are not reordered
+        let separated = "q\u{0302}q\u{0323}";
+        assert!(is_hfs_nfd(separated));
+        assert!(is_hfs_composed(separated));
+        assert_eq!(decompose_into_hfs_nfd(separated), separated);
+    }
 
     #[cfg(feature = "bench")]
     fn join_all_materials() -> String {
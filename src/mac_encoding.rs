@@ -0,0 +1,235 @@
+//! Conversion from the legacy Mac OS text encodings, used for names on HFS (standard) volumes and indicated by the
+//! `textEncoding` hint of HFS+ catalog records
+//!
+//! MacJapanese is supported for the single-byte characters and JIS X 0208; Apple's vendor extensions (e.g. the
+//! vertical forms) are not.
+
+#[cfg(feature = "alloc")]
+use super::decomposer::Decomposer;
+#[cfg(feature = "alloc")]
+use super::error::Error;
+#[cfg(feature = "alloc")]
+use super::mac_encoding_table::*;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Legacy Mac OS text encodings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MacEncoding {
+    /// MacRoman
+    Roman,
+    /// MacJapanese
+    Japanese,
+    /// MacGreek
+    Greek,
+    /// MacCyrillic
+    Cyrillic,
+    /// MacCentralEurRoman
+    CentralEurRoman,
+    /// MacTurkish
+    Turkish,
+    /// MacCroatian
+    Croatian,
+    /// MacIcelandic
+    Icelandic,
+    /// MacRomanian
+    Romanian,
+    /// MacUkrainian, decoded in the same way as MacCyrillic
+    Ukrainian,
+}
+
+impl MacEncoding {
+    /// Looks up the encoding from the value of the `textEncoding` field of HFS+ catalog records
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hfs_nfd::MacEncoding;
+    /// assert_eq!(MacEncoding::from_text_encoding(0), Some(MacEncoding::Roman));
+    /// assert_eq!(MacEncoding::from_text_encoding(29), Some(MacEncoding::CentralEurRoman));
+    /// assert_eq!(MacEncoding::from_text_encoding(3), None); // MacKorean is not supported
+    /// ```
+    pub fn from_text_encoding(value: u32) -> Option<Self> {
+        Some(match value {
+            0 => MacEncoding::Roman,
+            1 => MacEncoding::Japanese,
+            6 => MacEncoding::Greek,
+            7 => MacEncoding::Cyrillic,
+            29 => MacEncoding::CentralEurRoman,
+            35 => MacEncoding::Turkish,
+            36 => MacEncoding::Croatian,
+            37 => MacEncoding::Icelandic,
+            38 => MacEncoding::Romanian,
+            152 => MacEncoding::Ukrainian,
+            _ => return None,
+        })
+    }
+
+    /// Returns the value of the `textEncoding` field of HFS+ catalog records
+    pub fn text_encoding(self) -> u32 {
+        match self {
+            MacEncoding::Roman => 0,
+            MacEncoding::Japanese => 1,
+            MacEncoding::Greek => 6,
+            MacEncoding::Cyrillic => 7,
+            MacEncoding::CentralEurRoman => 29,
+            MacEncoding::Turkish => 35,
+            MacEncoding::Croatian => 36,
+            MacEncoding::Icelandic => 37,
+            MacEncoding::Romanian => 38,
+            MacEncoding::Ukrainian => 152,
+        }
+    }
+
+    /// Returns the upper half of the table of a single-byte encoding
+    #[cfg(feature = "alloc")]
+    fn single_byte_table(self) -> Option<&'static [char; 128]> {
+        Some(match self {
+            MacEncoding::Roman => &MAC_ROMAN,
+            MacEncoding::Japanese => return None,
+            MacEncoding::Greek => &MAC_GREEK,
+            MacEncoding::Cyrillic | MacEncoding::Ukrainian => &MAC_CYRILLIC,
+            MacEncoding::CentralEurRoman => &MAC_CENTRAL_EUR_ROMAN,
+            MacEncoding::Turkish => &MAC_TURKISH,
+            MacEncoding::Croatian => &MAC_CROATIAN,
+            MacEncoding::Icelandic => &MAC_ICELANDIC,
+            MacEncoding::Romanian => &MAC_ROMANIAN,
+        })
+    }
+}
+
+/// Decodes a character of MacJapanese and returns it with the number of bytes consumed
+#[cfg(feature = "alloc")]
+fn decode_japanese_char(input: &[u8]) -> Option<(char, usize)> {
+    let c = match input[0] {
+        0x5C => '\u{00A5}',
+        byte @ 0x00..=0x7F => byte as char,
+        0x80 => '\\',
+        0xA0 => '\u{00A0}',
+        byte @ 0xA1..=0xDF => core::char::from_u32(0xFF61 + (byte - 0xA1) as u32)?,
+        0xFD => '\u{00A9}',
+        0xFE => '\u{2122}',
+        0xFF => '\u{2026}',
+        lead => {
+            let code = u16::from_be_bytes([lead, *input.get(1)?]);
+            let index = MAC_JAPANESE_DOUBLE_BYTE
+                .binary_search_by_key(&code, |&(code, _)| code)
+                .ok()?;
+            return Some((MAC_JAPANESE_DOUBLE_BYTE[index].1, 2));
+        }
+    };
+    Some((c, 1))
+}
+
+/// Converts a name in a legacy Mac OS text encoding into Unicode applied the decomposition similar to NFD used in HFS+
+///
+/// # Arguments
+///
+/// * `input` - Bytes encoded in `encoding`
+/// * `encoding` - The encoding of `input`
+///
+/// # Errors
+///
+/// Returns [`Error::UnmappableMacEncoding`] if `input` contains a byte sequence not defined in `encoding`.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decode_mac_into_hfs_nfd, MacEncoding};
+/// assert_eq!(decode_mac_into_hfs_nfd(b"caf\x8E", MacEncoding::Roman).unwrap(), "cafe\u{0301}");
+/// assert_eq!(
+///     decode_mac_into_hfs_nfd(b"\x83p\x83X", MacEncoding::Japanese).unwrap(),
+///     "\u{30CF}\u{309A}\u{30B9}"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_mac_into_hfs_nfd(input: &[u8], encoding: MacEncoding) -> Result<String, Error> {
+    let mut result = String::with_capacity(input.len());
+    let mut decomposer = Decomposer::new();
+    let mut position = 0;
+    while position < input.len() {
+        let (c, len) = match encoding.single_byte_table() {
+            Some(table) => match input[position] {
+                byte @ 0x00..=0x7F => (byte as char, 1),
+                byte => (table[(byte - 0x80) as usize], 1),
+            },
+            None => decode_japanese_char(&input[position..])
+                .ok_or(Error::UnmappableMacEncoding(position))?,
+        };
+        decomposer
+            .push(c, &mut result)
+            .expect("writing to a String never fails");
+        position += len;
+    }
+    decomposer
+        .finish(&mut result)
+        .expect("writing to a String never fails");
+    Ok(result)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mac_encoding_fixed_strings_test() {
+        let examinee: &[(&[u8], MacEncoding, &str)] = &[
+            (b"Pok\x8Emon", MacEncoding::Roman, "Poke\u{0301}mon"),
+            (b"\xDB\xF0", MacEncoding::Roman, "\u{20AC}\u{F8FF}"),
+            (
+                b"\x89\x8B",
+                MacEncoding::CentralEurRoman,
+                "C\u{030C}c\u{030C}",
+            ),
+            (b"\xE1\xE2", MacEncoding::Greek, "\u{03B1}\u{03B2}"),
+            (
+                b"\x80\xDF\xA2",
+                MacEncoding::Ukrainian,
+                "\u{0410}\u{044F}\u{0490}",
+            ),
+            (b"\xD9\xDA", MacEncoding::Turkish, "Y\u{0308}G\u{0306}"),
+            (
+                b"\x83p\x83X\x83\x8F\x81[\x83h",
+                MacEncoding::Japanese,
+                "\u{30CF}\u{309A}\u{30B9}\u{30EF}\u{30FC}\u{30C8}\u{3099}",
+            ),
+            (
+                b"\\\x80\xB6\xDE\xFD",
+                MacEncoding::Japanese,
+                "\u{00A5}\\\u{FF76}\u{FF9E}\u{00A9}",
+            ),
+            (
+                b"\x8A\xBF\x8E\x9A",
+                MacEncoding::Japanese,
+                "\u{6F22}\u{5B57}",
+            ),
+        ];
+        for (input, encoding, expected) in examinee {
+            assert_eq!(
+                decode_mac_into_hfs_nfd(input, *encoding).as_deref(),
+                Ok(*expected)
+            );
+        }
+    }
+
+    #[test]
+    fn mac_japanese_invalid_test() {
+        assert_eq!(
+            decode_mac_into_hfs_nfd(b"ab\x83", MacEncoding::Japanese),
+            Err(Error::UnmappableMacEncoding(2))
+        );
+        assert_eq!(
+            decode_mac_into_hfs_nfd(b"\x85\x40", MacEncoding::Japanese),
+            Err(Error::UnmappableMacEncoding(0))
+        );
+    }
+
+    #[test]
+    fn text_encoding_test() {
+        for value in 0..256 {
+            if let Some(encoding) = MacEncoding::from_text_encoding(value) {
+                assert_eq!(encoding.text_encoding(), value);
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! HFS+ normalization of paths and OS strings
+//!
+//! Path separators are never changed by the conversion because they are neither decomposed nor composed with any
+//! other character, so converting a whole path is the same as converting each component. Segments not encoded in
+//! Unicode (invalid UTF-8 on Unix, unpaired surrogates on Windows) are left untouched.
+
+#[cfg(not(unix))]
+use super::{compose_from_hfs_nfd_cow, decompose_into_hfs_nfd_cow};
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+/// Direction of the conversion applied to names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Apply the decomposition like [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd)
+    Decompose,
+    /// Restore the commonly encoded form like [`compose_from_hfs_nfd`](crate::compose_from_hfs_nfd)
+    Compose,
+}
+
+impl Direction {
+    #[cfg(not(unix))]
+    fn convert_str(self, input: &str) -> Cow<'_, str> {
+        match self {
+            Direction::Decompose => decompose_into_hfs_nfd_cow(input),
+            Direction::Compose => compose_from_hfs_nfd_cow(input),
+        }
+    }
+
+    #[cfg(unix)]
+    fn convert_bytes(self, input: &[u8]) -> Cow<'_, [u8]> {
+        match self {
+            Direction::Decompose => crate::decompose_bytes(input),
+            Direction::Compose => crate::compose_bytes(input),
+        }
+    }
+
+    #[cfg(windows)]
+    fn convert_wide(self, input: &[u16]) -> Vec<u16> {
+        match self {
+            Direction::Decompose => crate::decompose_utf16(input),
+            Direction::Compose => crate::compose_utf16(input),
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn cow_str_into_os_str(s: Cow<'_, str>) -> Cow<'_, OsStr> {
+    match s {
+        Cow::Borrowed(s) => Cow::Borrowed(OsStr::new(s)),
+        Cow::Owned(s) => Cow::Owned(OsString::from(s)),
+    }
+}
+
+#[cfg(unix)]
+fn convert_os_str(input: &OsStr, direction: Direction) -> Cow<'_, OsStr> {
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+    match direction.convert_bytes(input.as_bytes()) {
+        Cow::Borrowed(bytes) => Cow::Borrowed(OsStr::from_bytes(bytes)),
+        Cow::Owned(bytes) => Cow::Owned(OsString::from_vec(bytes)),
+    }
+}
+
+#[cfg(windows)]
+fn convert_os_str(input: &OsStr, direction: Direction) -> Cow<'_, OsStr> {
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+    if let Some(s) = input.to_str() {
+        return cow_str_into_os_str(direction.convert_str(s));
+    }
+    let wide = input.encode_wide().collect::<Vec<_>>();
+    let converted = direction.convert_wide(&wide);
+    if converted == wide {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(OsString::from_wide(&converted))
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn convert_os_str(input: &OsStr, direction: Direction) -> Cow<'_, OsStr> {
+    match input.to_str() {
+        Some(s) => cow_str_into_os_str(direction.convert_str(s)),
+        None => Cow::Borrowed(input),
+    }
+}
+
+fn convert_path(input: &Path, direction: Direction) -> Cow<'_, Path> {
+    match convert_os_str(input.as_os_str(), direction) {
+        Cow::Borrowed(s) => Cow::Borrowed(Path::new(s)),
+        Cow::Owned(s) => Cow::Owned(PathBuf::from(s)),
+    }
+}
+
+/// Methods to apply the HFS+ decomposition and composition to [`Path`] and [`OsStr`], borrowing them if they are left
+/// unchanged
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::HfsPathNormalization;
+/// use std::borrow::Cow;
+/// use std::path::Path;
+/// let path = Path::new("/Users/Pok\u{00E9}mon/Pikachu.txt");
+/// assert_eq!(path.to_hfs_nfd(), Path::new("/Users/Poke\u{0301}mon/Pikachu.txt"));
+/// assert_eq!(path.to_hfs_nfd().from_hfs_nfd(), path);
+/// assert!(matches!(path.from_hfs_nfd(), Cow::Borrowed(_)));
+/// ```
+pub trait HfsPathNormalization: ToOwned {
+    /// Applies the decomposition like [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd)
+    fn to_hfs_nfd(&self) -> Cow<'_, Self>;
+    /// Restores the commonly encoded form like [`compose_from_hfs_nfd`](crate::compose_from_hfs_nfd)
+    #[allow(clippy::wrong_self_convention)]
+    fn from_hfs_nfd(&self) -> Cow<'_, Self>;
+}
+
+impl HfsPathNormalization for OsStr {
+    fn to_hfs_nfd(&self) -> Cow<'_, OsStr> {
+        convert_os_str(self, Direction::Decompose)
+    }
+
+    fn from_hfs_nfd(&self) -> Cow<'_, OsStr> {
+        convert_os_str(self, Direction::Compose)
+    }
+}
+
+impl HfsPathNormalization for Path {
+    fn to_hfs_nfd(&self) -> Cow<'_, Path> {
+        convert_path(self, Direction::Decompose)
+    }
+
+    fn from_hfs_nfd(&self) -> Cow<'_, Path> {
+        convert_path(self, Direction::Compose)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn path_fixed_strings_test() {
+        let composed = Path::new("caf\u{00E9}/\u{D55C}\u{AE00}/Pok\u{00E9}mon.txt");
+        let decomposed = Path::new(
+            "cafe\u{0301}/\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}/Poke\u{0301}mon.txt",
+        );
+        assert_eq!(composed.to_hfs_nfd(), decomposed);
+        assert_eq!(decomposed.from_hfs_nfd(), composed);
+        assert!(matches!(decomposed.to_hfs_nfd(), Cow::Borrowed(_)));
+        assert!(matches!(composed.from_hfs_nfd(), Cow::Borrowed(_)));
+        assert_eq!(composed.as_os_str().to_hfs_nfd(), decomposed.as_os_str());
+        assert_eq!(decomposed.as_os_str().from_hfs_nfd(), composed.as_os_str());
+        assert_eq!(
+            composed.to_hfs_nfd().components().count(),
+            composed.components().count()
+        );
+    }
+
+    #[test]
+    fn separator_not_composed_test() {
+        // Combining marks after a separator are not attached to the previous component
+        let path = Path::new("e/\u{0301}");
+        assert!(matches!(path.from_hfs_nfd(), Cow::Borrowed(_)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_path_test() {
+        use std::os::unix::ffi::OsStrExt;
+        let path = Path::new(OsStr::from_bytes(b"\xFF/caf\xC3\xA9/\xE3\x81"));
+        assert_eq!(
+            path.to_hfs_nfd().as_os_str().as_bytes(),
+            b"\xFF/cafe\xCC\x81/\xE3\x81"
+        );
+        assert_eq!(path.to_hfs_nfd().from_hfs_nfd(), path);
+    }
+}
@@ -0,0 +1,89 @@
+//! Translation between HFS+ catalog names and POSIX path components
+//!
+//! HFS+ catalog names may contain `/` but not `:`, which was the path separator of the classic Mac OS. macOS presents
+//! `/` in catalog names as `:` in POSIX paths, and vice versa.
+
+use super::iter::HfsNormalization;
+use alloc::string::String;
+
+/// Swaps `:` and `/`
+fn swap_separator(c: char) -> char {
+    match c {
+        ':' => '/',
+        '/' => ':',
+        _ => c,
+    }
+}
+
+/// Swaps `:` and `/` and applies the decomposition, which is the conversion in both directions
+fn swap_separators(name: &str) -> String {
+    name.chars().map(swap_separator).hfs_nfd_chars().collect()
+}
+
+/// Converts a POSIX path component into the name stored in the HFS+ catalog, swapping `:` and `/` and applying the
+/// decomposition like [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd)
+///
+/// The swap is its own inverse, so this is the same mapping as [`hfs_name_to_posix`]; both exist to show the
+/// direction at the call sites.
+///
+/// # Arguments
+///
+/// * `name` - A path component as presented by macOS
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::posix_name_to_hfs;
+/// assert_eq!(posix_name_to_hfs("caf\u{00E9}: 1/2"), "cafe\u{0301}/ 1:2");
+/// ```
+pub fn posix_name_to_hfs(name: &str) -> String {
+    swap_separators(name)
+}
+
+/// Converts a name stored in the HFS+ catalog into the POSIX path component presented by macOS, swapping `/` and `:`
+/// and applying the decomposition like [`decompose_into_hfs_nfd`](crate::decompose_into_hfs_nfd)
+///
+/// The swap is its own inverse, so this is the same mapping as [`posix_name_to_hfs`], and either function undoes the
+/// other (up to the decomposition).
+///
+/// # Arguments
+///
+/// * `name` - A name in the HFS+ catalog
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::hfs_name_to_posix;
+/// assert_eq!(hfs_name_to_posix("AC/DC"), "AC:DC");
+/// ```
+pub fn hfs_name_to_posix(name: &str) -> String {
+    swap_separators(name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::HfsUniStr255;
+
+    #[test]
+    fn separator_swap_test() {
+        let examinee = [
+            ("a:b/c", "a/b:c"),
+            ("Pok\u{00E9}mon: 2/3", "Poke\u{0301}mon/ 2:3"),
+            ("::", "//"),
+        ];
+        for (posix, hfs) in examinee {
+            assert_eq!(posix_name_to_hfs(posix), hfs);
+            assert_eq!(hfs_name_to_posix(hfs), crate::decompose_into_hfs_nfd(posix));
+            let name = HfsUniStr255::from_posix_name(posix).unwrap();
+            assert_eq!(name, HfsUniStr255::new(hfs).unwrap());
+            assert_eq!(name.to_posix_name(), crate::decompose_into_hfs_nfd(posix));
+            // Involution: converting back restores the (decomposed) original
+            assert_eq!(
+                hfs_name_to_posix(&posix_name_to_hfs(posix)),
+                crate::decompose_into_hfs_nfd(posix)
+            );
+            assert_eq!(posix_name_to_hfs(&hfs_name_to_posix(hfs)), hfs);
+        }
+    }
+}
@@ -0,0 +1,736 @@
+//! Selectable decomposition tables reproducing the names written by each version of the OS
+
+use super::canonical_order::{
+    canonical_combining_class, hfs_combining_class, unicode32_combining_class,
+};
+#[cfg(feature = "alloc")]
+use super::composer::Composer;
+#[cfg(feature = "alloc")]
+use super::decomposer::Decomposer;
+use super::hangul::{
+    is_hangul_composable_pair, is_hangul_conjoinable_jamo, is_hangul_precomposed_syllable,
+};
+use super::reverse_tree::{CharMap, ReverseTreeNode};
+use super::{code_table, code_table_unicode32, code_table_unicode_latest};
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use core::fmt::{self, Write};
+
+/// Set of the decomposition rules
+///
+/// Every profile decomposes Hangul syllables algorithmically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NormalizationProfile {
+    /// The table in TN1150, used by the functions without the profile parameter
+    #[default]
+    HfsPlus,
+    /// NFD of Unicode 3.2, used by HFS+ since Mac OS X 10.3
+    ///
+    /// Combining marks are sorted by their combining classes in Unicode 3.2, where the marks added later are starters.
+    Unicode32,
+    /// NFD of the latest Unicode supported by this crate (14.0), as used by APFS
+    ///
+    /// Like HFS+, the characters in U+2000–U+2FFF, U+F900–U+FAFF and U+2F800–U+2FAFF are not decomposed.
+    UnicodeLatest,
+}
+
+impl NormalizationProfile {
+    /// Returns the map from composed characters to decomposed sequences
+    pub(crate) fn decomposition_table(self) -> CharMap<&'static str> {
+        match self {
+            NormalizationProfile::HfsPlus => code_table::MAP_TO_HFS,
+            NormalizationProfile::Unicode32 => code_table_unicode32::MAP_TO_HFS,
+            NormalizationProfile::UnicodeLatest => code_table_unicode_latest::MAP_TO_HFS,
+        }
+    }
+
+    /// Returns the root of the tree from decomposed sequences to composed characters
+    pub(crate) fn composition_table(self) -> CharMap<ReverseTreeNode> {
+        match self {
+            NormalizationProfile::HfsPlus => code_table::MAP_TO_NORMAL,
+            NormalizationProfile::Unicode32 => code_table_unicode32::MAP_TO_NORMAL,
+            NormalizationProfile::UnicodeLatest => code_table_unicode_latest::MAP_TO_NORMAL,
+        }
+    }
+
+    /// Returns the combining class used to sort combining marks
+    #[inline]
+    pub(crate) fn combining_class(self, c: char) -> u8 {
+        match self {
+            NormalizationProfile::HfsPlus => hfs_combining_class(c),
+            NormalizationProfile::Unicode32 => unicode32_combining_class(c),
+            NormalizationProfile::UnicodeLatest => canonical_combining_class(c),
+        }
+    }
+}
+
+/// Applies the Unicode decomposition according to `profile`
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_into_hfs_nfd_with_profile, NormalizationProfile};
+/// // TN1150 decomposes the Greek tonos into U+030D
+/// assert_eq!(
+///     decompose_into_hfs_nfd_with_profile("\u{03AC}", NormalizationProfile::HfsPlus),
+///     "\u{03B1}\u{030D}"
+/// );
+/// assert_eq!(
+///     decompose_into_hfs_nfd_with_profile("\u{03AC}", NormalizationProfile::Unicode32),
+///     "\u{03B1}\u{0301}"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decompose_into_hfs_nfd_with_profile(input: &str, profile: NormalizationProfile) -> String {
+    let mut result = String::with_capacity(input.len());
+    decompose_into_hfs_nfd_into_with_profile(input, &mut result, profile);
+    result
+}
+
+/// Applies the Unicode decomposition according to `profile`, borrowing the input if it is left unchanged
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_into_hfs_nfd_cow_with_profile, NormalizationProfile};
+/// use std::borrow::Cow;
+/// // U+01F8 is not in TN1150
+/// let input = "\u{01F8}";
+/// assert!(matches!(
+///     decompose_into_hfs_nfd_cow_with_profile(input, NormalizationProfile::HfsPlus),
+///     Cow::Borrowed(_)
+/// ));
+/// assert_eq!(
+///     decompose_into_hfs_nfd_cow_with_profile(input, NormalizationProfile::Unicode32),
+///     "N\u{0300}"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decompose_into_hfs_nfd_cow_with_profile(
+    input: &str,
+    profile: NormalizationProfile,
+) -> Cow<'_, str> {
+    match find_first_decomposable(input, profile) {
+        None => Cow::Borrowed(input),
+        Some(index) => {
+            let mut result = String::with_capacity(input.len() * 2);
+            result += &input[..index];
+            decompose_into_hfs_nfd_into_with_profile(&input[index..], &mut result, profile);
+            Cow::Owned(result)
+        }
+    }
+}
+
+/// Finds the byte index of the beginning of the first combining sequence changed by the decomposition
+///
+/// A sequence is changed if it contains a character to be decomposed or combining marks out of the canonical order.
+pub(crate) fn find_first_decomposable(input: &str, profile: NormalizationProfile) -> Option<usize> {
+    let table = profile.decomposition_table();
+    let mut sequence_start = 0;
+    let mut last_class = 0;
+    for (index, c) in input.char_indices() {
+        let class = profile.combining_class(c);
+        if table.contains_key(&c)
+            || is_hangul_precomposed_syllable(c)
+            || (class != 0 && last_class > class)
+        {
+            return Some(sequence_start);
+        }
+        if class == 0 {
+            sequence_start = index;
+        }
+        last_class = class;
+    }
+    None
+}
+
+/// Checks whether a string is left unchanged by the decomposition according to `profile` without allocating
+///
+/// # Arguments
+///
+/// * `input` - A string to be checked
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{is_hfs_nfd_with_profile, NormalizationProfile};
+/// // TN1150 decomposes the Greek tonos into U+030D
+/// assert!(is_hfs_nfd_with_profile("\u{03B1}\u{030D}", NormalizationProfile::HfsPlus));
+/// assert!(!is_hfs_nfd_with_profile("\u{03AC}", NormalizationProfile::UnicodeLatest));
+/// ```
+pub fn is_hfs_nfd_with_profile(input: &str, profile: NormalizationProfile) -> bool {
+    find_first_decomposable(input, profile).is_none()
+}
+
+/// Applies the Unicode decomposition according to `profile` and appends the result to an existing buffer
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+/// * `output` - A buffer the decomposed string is appended to
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_into_hfs_nfd_into_with_profile, NormalizationProfile};
+/// let mut buffer = String::from("/Users/");
+/// decompose_into_hfs_nfd_into_with_profile("\u{03AC}", &mut buffer, NormalizationProfile::Unicode32);
+/// assert_eq!(buffer, "/Users/\u{03B1}\u{0301}");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decompose_into_hfs_nfd_into_with_profile(
+    input: &str,
+    output: &mut String,
+    profile: NormalizationProfile,
+) {
+    decompose_into_hfs_nfd_write_with_profile(input, output, profile)
+        .expect("writing to a String never fails");
+}
+
+/// Applies the Unicode decomposition according to `profile` and writes the result to any [`fmt::Write`]
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+/// * `output` - A destination the decomposed string is written to
+/// * `profile` - The rules of the decomposition
+///
+/// # Errors
+///
+/// Returns an error if `output` fails to be written.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_into_hfs_nfd_write_with_profile, NormalizationProfile};
+/// let mut buffer = String::new();
+/// decompose_into_hfs_nfd_write_with_profile("\u{03AC}", &mut buffer, NormalizationProfile::HfsPlus)
+///     .unwrap();
+/// assert_eq!(buffer, "\u{03B1}\u{030D}");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decompose_into_hfs_nfd_write_with_profile<W: Write + ?Sized>(
+    input: &str,
+    output: &mut W,
+    profile: NormalizationProfile,
+) -> fmt::Result {
+    let mut decomposer = Decomposer::with_profile(profile);
+    for c in input.chars() {
+        decomposer.push(c, output)?;
+    }
+    decomposer.finish(output)
+}
+
+/// Restores a commonly encoded string from one applied the Unicode decomposition according to `profile`
+///
+/// # Arguments
+///
+/// * `input` - A string to be restored from
+/// * `profile` - The rules of the decomposition applied to `input`
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_from_hfs_nfd_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     compose_from_hfs_nfd_with_profile("\u{03B1}\u{0301}", NormalizationProfile::UnicodeLatest),
+///     "\u{03AC}"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compose_from_hfs_nfd_with_profile(input: &str, profile: NormalizationProfile) -> String {
+    let mut result = String::with_capacity(input.len());
+    compose_from_hfs_nfd_into_with_profile(input, &mut result, profile);
+    result
+}
+
+/// Restores a commonly encoded string like [`compose_from_hfs_nfd_with_profile`], borrowing the input if it is left
+/// unchanged
+///
+/// # Arguments
+///
+/// * `input` - A string to be restored from
+/// * `profile` - The rules of the decomposition applied to `input`
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_from_hfs_nfd_cow_with_profile, NormalizationProfile};
+/// use std::borrow::Cow;
+/// // U+030D is the Greek tonos only in TN1150
+/// let input = "\u{03B1}\u{030D}";
+/// assert_eq!(
+///     compose_from_hfs_nfd_cow_with_profile(input, NormalizationProfile::HfsPlus),
+///     "\u{03AC}"
+/// );
+/// assert!(matches!(
+///     compose_from_hfs_nfd_cow_with_profile(input, NormalizationProfile::Unicode32),
+///     Cow::Borrowed(_)
+/// ));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compose_from_hfs_nfd_cow_with_profile(
+    input: &str,
+    profile: NormalizationProfile,
+) -> Cow<'_, str> {
+    match find_first_composable(input, profile) {
+        None => Cow::Borrowed(input),
+        Some(index) => {
+            let mut result = String::with_capacity(input.len());
+            result += &input[..index];
+            compose_from_hfs_nfd_into_with_profile(&input[index..], &mut result, profile);
+            Cow::Owned(result)
+        }
+    }
+}
+
+/// Finds the byte index of the first character that starts a sequence which may be composed or reordered
+///
+/// The composition state is reset at every character before the returned index, so the input can be
+/// split there without affecting the result.
+#[cfg(feature = "alloc")]
+fn find_first_composable(input: &str, profile: NormalizationProfile) -> Option<usize> {
+    let root_dict = profile.composition_table();
+    let mut it = input.char_indices().peekable();
+    let mut sequence_start = 0;
+    while let Some((index, c)) = it.next() {
+        let class = profile.combining_class(c);
+        if class == 0 {
+            sequence_start = index;
+        }
+        let next = match it.peek() {
+            Some(&(_, next)) => next,
+            None => break,
+        };
+        let next_class = profile.combining_class(next);
+        if next_class != 0 && class > next_class {
+            return Some(sequence_start);
+        }
+        let composable = match root_dict.get(&c) {
+            Some(ReverseTreeNode {
+                current: _,
+                next: Some(sub_dict),
+            }) => sub_dict.contains_key(&next),
+            _ => is_hangul_composable_pair(c, next),
+        };
+        if composable {
+            return Some(sequence_start);
+        }
+    }
+    None
+}
+
+/// Checks whether a string is left unchanged by the composition according to `profile`
+///
+/// The dictionary walk of the composition is followed without buffering the characters, so no allocation takes
+/// place.
+///
+/// # Arguments
+///
+/// * `input` - A string to be checked
+/// * `profile` - The rules of the decomposition the composition reverts
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{is_hfs_composed_with_profile, NormalizationProfile};
+/// // U+030D is the Greek tonos only in TN1150
+/// assert!(!is_hfs_composed_with_profile("\u{03B1}\u{030D}", NormalizationProfile::HfsPlus));
+/// assert!(is_hfs_composed_with_profile("\u{03B1}\u{030D}", NormalizationProfile::Unicode32));
+/// ```
+pub fn is_hfs_composed_with_profile(input: &str, profile: NormalizationProfile) -> bool {
+    let root_dict = profile.composition_table();
+    let mut referencing_dict = root_dict;
+    // Whether the walk has consumed characters not composed yet, which are written as they are if it fails
+    let mut walking = false;
+    // The last conjoinable jamo out of the dictionary not followed by the other characters
+    let mut last_jamo = None;
+    let mut last_class = 0;
+    for c in input.chars() {
+        // Marks out of the canonical order are reordered
+        let class = profile.combining_class(c);
+        if class != 0 && last_class > class {
+            return false;
+        }
+        last_class = class;
+        loop {
+            match referencing_dict.get(&c) {
+                None
+                | Some(ReverseTreeNode {
+                    current: None,
+                    next: None,
+                }) => {
+                    referencing_dict = root_dict;
+                    if walking {
+                        // Retried from the root like the composer does
+                        walking = false;
+                        last_jamo = None;
+                        continue;
+                    }
+                    if !is_hangul_conjoinable_jamo(c) {
+                        last_jamo = None;
+                    } else if last_jamo.is_some_and(|last| is_hangul_composable_pair(last, c)) {
+                        return false;
+                    } else {
+                        last_jamo = Some(c);
+                    }
+                    break;
+                }
+                Some(ReverseTreeNode {
+                    current: None,
+                    next: Some(sub_dict),
+                }) => {
+                    referencing_dict = *sub_dict;
+                    walking = true;
+                    break;
+                }
+                // Any character consumed in the walk is replaced with the composed one
+                Some(ReverseTreeNode {
+                    current: Some(_), ..
+                }) => return false,
+            }
+        }
+    }
+    true
+}
+
+/// Restores a commonly encoded string like [`compose_from_hfs_nfd_with_profile`] and appends the result to an
+/// existing buffer
+///
+/// # Arguments
+///
+/// * `input` - A string to be restored from
+/// * `output` - A buffer the restored string is appended to
+/// * `profile` - The rules of the decomposition applied to `input`
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_from_hfs_nfd_into_with_profile, NormalizationProfile};
+/// let mut buffer = String::from("/Users/");
+/// compose_from_hfs_nfd_into_with_profile("N\u{0300}", &mut buffer, NormalizationProfile::Unicode32);
+/// assert_eq!(buffer, "/Users/\u{01F8}");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compose_from_hfs_nfd_into_with_profile(
+    input: &str,
+    output: &mut String,
+    profile: NormalizationProfile,
+) {
+    compose_from_hfs_nfd_write_with_profile(input, output, profile)
+        .expect("writing to a String never fails");
+}
+
+/// Restores a commonly encoded string like [`compose_from_hfs_nfd_with_profile`] and writes the result to any
+/// [`fmt::Write`]
+///
+/// # Arguments
+///
+/// * `input` - A string to be restored from
+/// * `output` - A destination the restored string is written to
+/// * `profile` - The rules of the decomposition applied to `input`
+///
+/// # Errors
+///
+/// Returns an error if `output` fails to be written.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_from_hfs_nfd_write_with_profile, NormalizationProfile};
+/// let mut buffer = String::new();
+/// compose_from_hfs_nfd_write_with_profile("N\u{0300}", &mut buffer, NormalizationProfile::HfsPlus)
+///     .unwrap();
+/// assert_eq!(buffer, "N\u{0300}");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn compose_from_hfs_nfd_write_with_profile<W: Write + ?Sized>(
+    input: &str,
+    output: &mut W,
+    profile: NormalizationProfile,
+) -> fmt::Result {
+    let mut composer = Composer::with_profile(profile);
+    for c in input.chars() {
+        composer.push(c, output)?;
+    }
+    composer.finish(output)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::{compose_from_hfs_nfd, decompose_into_hfs_nfd};
+
+    const PROFILES: [NormalizationProfile; 3] = [
+        NormalizationProfile::HfsPlus,
+        NormalizationProfile::Unicode32,
+        NormalizationProfile::UnicodeLatest,
+    ];
+
+    #[test]
+    fn profile_fixed_strings_test() {
+        // (input, HFS+, Unicode 3.2, latest)
+        let examinee = [
+            (
+                "Pok\u{00E9}mon",
+                "Poke\u{0301}mon",
+                "Poke\u{0301}mon",
+                "Poke\u{0301}mon",
+            ),
+            (
+                "\u{03AC}\u{1F71}",
+                "\u{03B1}\u{030D}\u{03B1}\u{0301}",
+                "\u{03B1}\u{0301}\u{03B1}\u{0301}",
+                "\u{03B1}\u{0301}\u{03B1}\u{0301}",
+            ),
+            // Not in TN1150
+            ("\u{01F8}", "\u{01F8}", "N\u{0300}", "N\u{0300}"),
+            // Added in Unicode 5.1
+            (
+                "\u{1E9E}\u{0CF1}",
+                "\u{1E9E}\u{0CF1}",
+                "\u{1E9E}\u{0CF1}",
+                "\u{1E9E}\u{0CF1}",
+            ),
+            ("\u{1B06}", "\u{1B06}", "\u{1B06}", "\u{1B05}\u{1B35}"),
+            // Excluded ranges
+            (
+                "\u{212B}\u{F900}",
+                "\u{212B}\u{F900}",
+                "\u{212B}\u{F900}",
+                "\u{212B}\u{F900}",
+            ),
+            (
+                "\u{D55C}",
+                "\u{1112}\u{1161}\u{11AB}",
+                "\u{1112}\u{1161}\u{11AB}",
+                "\u{1112}\u{1161}\u{11AB}",
+            ),
+            // U+0345 is sorted after the other marks except for TN1150
+            (
+                "\u{1FB4}",
+                "\u{03B1}\u{0345}\u{0301}",
+                "\u{03B1}\u{0301}\u{0345}",
+                "\u{03B1}\u{0301}\u{0345}",
+            ),
+        ];
+        for (input, hfs_plus, unicode32, latest) in examinee {
+            for (profile, expected) in PROFILES.iter().zip([hfs_plus, unicode32, latest]) {
+                assert_eq!(
+                    decompose_into_hfs_nfd_with_profile(input, *profile),
+                    expected,
+                    "{:?} {:?}",
+                    input,
+                    profile
+                );
+            }
+        }
+        assert_eq!(
+            compose_from_hfs_nfd_with_profile(
+                "\u{03B1}\u{0301}\u{03B1}\u{030D}",
+                NormalizationProfile::HfsPlus
+            ),
+            "\u{1F71}\u{03AC}"
+        );
+        assert_eq!(
+            compose_from_hfs_nfd_with_profile("N\u{0300}\u{0340}", NormalizationProfile::Unicode32),
+            "\u{01F8}\u{0340}"
+        );
+    }
+
+    #[test]
+    fn profile_round_trip_test() {
+        for profile in PROFILES {
+            for &(composed, _) in profile.decomposition_table() {
+                let composed = composed.to_string();
+                let decomposed = decompose_into_hfs_nfd_with_profile(&composed, profile);
+                let restored = compose_from_hfs_nfd_with_profile(&decomposed, profile);
+                assert_eq!(
+                    decompose_into_hfs_nfd_with_profile(&restored, profile),
+                    decomposed,
+                    "{:?} {:?}",
+                    composed,
+                    profile
+                );
+            }
+        }
+        let s = "Pok\u{00E9}mon \u{1F8A}\u{30DD}";
+        assert_eq!(
+            decompose_into_hfs_nfd_with_profile(s, NormalizationProfile::HfsPlus),
+            decompose_into_hfs_nfd(s)
+        );
+        assert_eq!(
+            compose_from_hfs_nfd_with_profile(
+                &decompose_into_hfs_nfd(s),
+                NormalizationProfile::HfsPlus
+            ),
+            compose_from_hfs_nfd(&decompose_into_hfs_nfd(s))
+        );
+    }
+
+    #[test]
+    fn profile_conversion_paths_test() {
+        use crate::{
+            apfs_name_hash, apfs_name_hash_with_profile, compose_from_hfs_nfd_utf16_with_profile,
+            decompose_into_hfs_nfd_utf16_with_profile, decompose_utf16_with_profile,
+            hfs_cmp_with_profile, hfs_eq_with_profile, HfsNormalization,
+        };
+        use core::cmp::Ordering;
+
+        for profile in PROFILES {
+            let input = "Pok\u{00E9}mon \u{01F8}\u{03AC}\u{1B06}\u{D55C}";
+            let decomposed = decompose_into_hfs_nfd_with_profile(input, profile);
+            let composed = compose_from_hfs_nfd_with_profile(&decomposed, profile);
+            let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+
+            let mut output = String::new();
+            decompose_into_hfs_nfd_into_with_profile(input, &mut output, profile);
+            assert_eq!(output, decomposed, "{:?}", profile);
+            output.clear();
+            decompose_into_hfs_nfd_write_with_profile(input, &mut output, profile).unwrap();
+            assert_eq!(output, decomposed, "{:?}", profile);
+            assert_eq!(
+                decompose_into_hfs_nfd_cow_with_profile(input, profile),
+                decomposed
+            );
+            assert_eq!(
+                input
+                    .hfs_nfd_chars_with_profile(profile)
+                    .collect::<String>(),
+                decomposed
+            );
+            assert_eq!(
+                decompose_into_hfs_nfd_utf16_with_profile(input, profile),
+                utf16(&decomposed)
+            );
+            assert_eq!(
+                decompose_utf16_with_profile(&utf16(input), profile),
+                utf16(&decomposed)
+            );
+            assert!(
+                is_hfs_nfd_with_profile(&decomposed, profile),
+                "{:?}",
+                profile
+            );
+            assert!(matches!(
+                decompose_into_hfs_nfd_cow_with_profile(&decomposed, profile),
+                Cow::Borrowed(_)
+            ));
+
+            output.clear();
+            compose_from_hfs_nfd_into_with_profile(&decomposed, &mut output, profile);
+            assert_eq!(output, composed, "{:?}", profile);
+            output.clear();
+            compose_from_hfs_nfd_write_with_profile(&decomposed, &mut output, profile).unwrap();
+            assert_eq!(output, composed, "{:?}", profile);
+            assert_eq!(
+                compose_from_hfs_nfd_cow_with_profile(&decomposed, profile),
+                composed
+            );
+            assert_eq!(
+                decomposed
+                    .hfs_nfc_chars_with_profile(profile)
+                    .collect::<String>(),
+                composed
+            );
+            assert_eq!(
+                compose_from_hfs_nfd_utf16_with_profile(&utf16(&decomposed), profile),
+                composed
+            );
+            assert!(
+                is_hfs_composed_with_profile(&composed, profile),
+                "{:?}",
+                profile
+            );
+
+            assert!(
+                hfs_eq_with_profile(input, &decomposed, profile),
+                "{:?}",
+                profile
+            );
+            assert_eq!(
+                hfs_cmp_with_profile(input, &composed, profile),
+                Ordering::Equal
+            );
+        }
+        assert!(!is_hfs_nfd_with_profile(
+            "\u{01F8}",
+            NormalizationProfile::Unicode32
+        ));
+        assert!(is_hfs_nfd_with_profile(
+            "\u{01F8}",
+            NormalizationProfile::HfsPlus
+        ));
+        assert!(!is_hfs_composed_with_profile(
+            "N\u{0300}",
+            NormalizationProfile::Unicode32
+        ));
+        assert!(is_hfs_composed_with_profile(
+            "N\u{0300}",
+            NormalizationProfile::HfsPlus
+        ));
+        assert!(!hfs_eq_with_profile(
+            "\u{03AC}",
+            "\u{1F71}",
+            NormalizationProfile::HfsPlus
+        ));
+        assert!(hfs_eq_with_profile(
+            "\u{03AC}",
+            "\u{1F71}",
+            NormalizationProfile::UnicodeLatest
+        ));
+        assert_eq!(
+            apfs_name_hash_with_profile(
+                "Pok\u{00E9}mon",
+                true,
+                NormalizationProfile::UnicodeLatest
+            ),
+            apfs_name_hash("Pok\u{00E9}mon", true)
+        );
+    }
+
+    #[test]
+    fn profile_unicode32_order_test() {
+        // U+1DC0 was added in Unicode 5.0, so it is a starter in Unicode 3.2
+        let input = "a\u{1DC0}\u{0316}";
+        assert_eq!(
+            decompose_into_hfs_nfd_with_profile(input, NormalizationProfile::Unicode32),
+            input
+        );
+        assert!(is_hfs_nfd_with_profile(
+            input,
+            NormalizationProfile::Unicode32
+        ));
+        assert_eq!(
+            decompose_into_hfs_nfd_with_profile(input, NormalizationProfile::UnicodeLatest),
+            "a\u{0316}\u{1DC0}"
+        );
+        assert!(!is_hfs_nfd_with_profile(
+            input,
+            NormalizationProfile::UnicodeLatest
+        ));
+        // U+0316 and U+0301 have kept their classes since Unicode 3.2
+        assert_eq!(
+            decompose_into_hfs_nfd_with_profile(
+                "a\u{0301}\u{0316}",
+                NormalizationProfile::Unicode32
+            ),
+            "a\u{0316}\u{0301}"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! Recursive renaming of directory trees between the HFS+ decomposition and the commonly encoded form, like `convmv`
+//!
+//! Typical use is to restore names left decomposed by copying files from a Mac to a Linux server.
+
+use super::path::{Direction, HfsPathNormalization};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// What has been done (or would be done) for a name to be converted
+#[derive(Debug)]
+pub enum RenameStatus {
+    /// The entry has been renamed
+    Renamed,
+    /// The entry would be renamed, but nothing has been done because of the dry run
+    DryRun,
+    /// The entry has been left as is because another entry in the same directory already has, or would get, the
+    /// converted name
+    Collision,
+    /// Renaming the entry failed
+    Failed(io::Error),
+    /// The entries in the directory have been left as they are because the directory could not be read
+    ReadFailed(io::Error),
+}
+
+/// Record of a rename performed or planned by [`TreeRenamer`]
+#[derive(Debug)]
+pub struct RenameRecord {
+    /// The original path
+    pub from: PathBuf,
+    /// The path after the conversion, or the same as `from` for [`RenameStatus::ReadFailed`]
+    pub to: PathBuf,
+    /// The result
+    pub status: RenameStatus,
+}
+
+impl RenameRecord {
+    /// Whether the entry has been renamed or would be renamed in the dry run
+    pub fn is_success(&self) -> bool {
+        matches!(self.status, RenameStatus::Renamed | RenameStatus::DryRun)
+    }
+}
+
+impl fmt::Display for RenameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (from, to) = (self.from.display(), self.to.display());
+        match &self.status {
+            RenameStatus::Renamed => write!(f, "renamed: {} -> {}", from, to),
+            RenameStatus::DryRun => write!(f, "would rename: {} -> {}", from, to),
+            RenameStatus::Collision => write!(f, "skipped (name collision): {} -> {}", from, to),
+            RenameStatus::Failed(e) => write!(f, "failed ({}): {} -> {}", e, from, to),
+            RenameStatus::ReadFailed(e) => write!(f, "failed to read ({}): {}", e, from),
+        }
+    }
+}
+
+/// Walker renaming all the files and directories under a directory
+///
+/// Entries in a directory are renamed after its descendants, and the directory given as the root is never renamed.
+/// Symbolic links are renamed but not followed. A name is left unchanged if another entry in the same directory already
+/// has the converted name or would be converted to the same name, since renaming it would overwrite that entry. A
+/// subdirectory that cannot be read is recorded in the log, and the walk goes on with the rest of the tree.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hfs_nfd::{Direction, TreeRenamer};
+/// let records = TreeRenamer::new(Direction::Compose)
+///     .dry_run(true)
+///     .rename("/srv/share/from-mac")
+///     .unwrap();
+/// for record in records {
+///     println!("{}", record);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TreeRenamer {
+    direction: Direction,
+    dry_run: bool,
+}
+
+impl TreeRenamer {
+    /// Create a walker converting names in `direction`.
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            dry_run: false,
+        }
+    }
+
+    /// Sets whether only the plan is reported without renaming anything.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Renames the entries under `root` and returns the log of the names to be converted
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `root` cannot be read. Failures of reading the subdirectories and of renaming are recorded
+    /// in the log instead.
+    pub fn rename<P: AsRef<Path>>(&self, root: P) -> io::Result<Vec<RenameRecord>> {
+        let root = root.as_ref();
+        let entries = read_entries(root)?;
+        let mut records = Vec::new();
+        self.rename_entries(root, &entries, &mut records);
+        Ok(records)
+    }
+
+    fn convert<'a>(&self, name: &'a OsStr) -> Cow<'a, OsStr> {
+        match self.direction {
+            Direction::Decompose => name.to_hfs_nfd(),
+            Direction::Compose => name.from_hfs_nfd(),
+        }
+    }
+
+    fn rename_children(&self, dir: &Path, records: &mut Vec<RenameRecord>) {
+        match read_entries(dir) {
+            Ok(entries) => self.rename_entries(dir, &entries, records),
+            Err(e) => records.push(RenameRecord {
+                from: dir.to_path_buf(),
+                to: dir.to_path_buf(),
+                status: RenameStatus::ReadFailed(e),
+            }),
+        }
+    }
+
+    /// Renames `entries`, the names in `dir` sorted with whether they are directories, after their descendants
+    fn rename_entries(
+        &self,
+        dir: &Path,
+        entries: &[(OsString, bool)],
+        records: &mut Vec<RenameRecord>,
+    ) {
+        let mut target_counts = HashMap::<OsString, usize>::new();
+        for (name, _) in entries {
+            *target_counts
+                .entry(self.convert(name).into_owned())
+                .or_default() += 1;
+        }
+        for (name, is_dir) in entries {
+            let from = dir.join(name);
+            if *is_dir {
+                self.rename_children(&from, records);
+            }
+            let target = match self.convert(name) {
+                Cow::Borrowed(_) => continue,
+                Cow::Owned(target) => target,
+            };
+            let collides = target_counts[&target] > 1
+                || entries
+                    .binary_search_by(|(n, _)| n.as_os_str().cmp(&target))
+                    .is_ok();
+            let to = dir.join(&target);
+            let status = if collides {
+                RenameStatus::Collision
+            } else if self.dry_run {
+                RenameStatus::DryRun
+            } else {
+                match fs::rename(&from, &to) {
+                    Ok(()) => RenameStatus::Renamed,
+                    Err(e) => RenameStatus::Failed(e),
+                }
+            };
+            records.push(RenameRecord { from, to, status });
+        }
+    }
+}
+
+/// Lists the names in `dir` sorted with whether they are directories
+fn read_entries(dir: &Path) -> io::Result<Vec<(OsString, bool)>> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        entries.push((entry.file_name(), entry.file_type()?.is_dir()));
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    /// Temporary directory removed when dropped
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("hfs_nfd-{}-{}", name, process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn names(dir: &Path) -> Vec<String> {
+        let mut names = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn rename_tree_test() {
+        let temp = TempDir::new("rename");
+        let root = &temp.0;
+        fs::create_dir_all(root.join("Pokc\u{0327}\u{0301}").join("cafe\u{0301}")).unwrap();
+        fs::write(
+            root.join("Pokc\u{0327}\u{0301}")
+                .join("cafe\u{0301}")
+                .join("e\u{0301}.txt"),
+            "",
+        )
+        .unwrap();
+        fs::write(root.join("ascii.txt"), "").unwrap();
+
+        let records = TreeRenamer::new(Direction::Compose)
+            .dry_run(true)
+            .rename(root)
+            .unwrap();
+        assert_eq!(records.len(), 3);
+        assert!(records
+            .iter()
+            .all(|r| matches!(r.status, RenameStatus::DryRun)));
+        assert_eq!(names(root), vec!["Pokc\u{0327}\u{0301}", "ascii.txt"]);
+
+        let records = TreeRenamer::new(Direction::Compose).rename(root).unwrap();
+        assert!(records.iter().all(RenameRecord::is_success));
+        // Descendants are renamed before their ancestors
+        assert_eq!(records.last().unwrap().to, root.join("Pok\u{1E09}"));
+        assert_eq!(names(root), vec!["Pok\u{1E09}", "ascii.txt"]);
+        assert_eq!(
+            names(&root.join("Pok\u{1E09}").join("caf\u{00E9}")),
+            vec!["\u{00E9}.txt"]
+        );
+
+        TreeRenamer::new(Direction::Decompose).rename(root).unwrap();
+        assert_eq!(names(root), vec!["Pokc\u{0327}\u{0301}", "ascii.txt"]);
+    }
+
+    #[test]
+    fn rename_collision_test() {
+        let temp = TempDir::new("collision");
+        let root = &temp.0;
+        for name in &[
+            "caf\u{00E9}",
+            "cafe\u{0301}",
+            "e\u{0301}\u{0323}",
+            "e\u{0323}\u{0301}",
+        ] {
+            fs::write(root.join(name), name).unwrap();
+        }
+        let records = TreeRenamer::new(Direction::Compose).rename(root).unwrap();
+        assert_eq!(records.len(), 3);
+        assert!(records
+            .iter()
+            .all(|r| matches!(r.status, RenameStatus::Collision)));
+        assert_eq!(
+            fs::read_to_string(root.join("caf\u{00E9}")).unwrap(),
+            "caf\u{00E9}"
+        );
+    }
+
+    #[test]
+    fn read_failure_test() {
+        let temp = TempDir::new("read-failure");
+        let root = &temp.0;
+        assert!(TreeRenamer::new(Direction::Compose)
+            .rename(root.join("missing"))
+            .is_err());
+
+        let mut records = Vec::new();
+        TreeRenamer::new(Direction::Compose).rename_children(&root.join("missing"), &mut records);
+        assert_eq!(records.len(), 1);
+        assert!(matches!(records[0].status, RenameStatus::ReadFailed(_)));
+        assert!(!records[0].is_success());
+        assert!(records[0].to_string().starts_with("failed to read ("));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unreadable_directory_test() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new("unreadable");
+        let root = &temp.0;
+        let locked = root.join("locke\u{0301}");
+        fs::create_dir_all(locked.join("cafe\u{0301}")).unwrap();
+        fs::create_dir_all(root.join("ope\u{0301}n").join("e\u{0301}")).unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+        if fs::read_dir(&locked).is_ok() {
+            // Permissions do not apply to the superuser
+            fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+            return;
+        }
+        let records = TreeRenamer::new(Direction::Compose).rename(root);
+        let renamed = root.join("lock\u{00E9}");
+        fs::set_permissions(&renamed, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let records = records.unwrap();
+        assert_eq!(records.len(), 4);
+        assert!(matches!(records[0].status, RenameStatus::ReadFailed(_)));
+        assert_eq!(records[0].from, locked);
+        // The siblings and the unreadable directory itself are still renamed
+        assert!(records[1..].iter().all(RenameRecord::is_success));
+        assert_eq!(names(root), vec!["lock\u{00E9}", "op\u{00E9}n"]);
+        assert_eq!(names(&root.join("op\u{00E9}n")), vec!["\u{00E9}"]);
+    }
+}
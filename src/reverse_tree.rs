@@ -1,19 +1,103 @@
-use ahash::AHashMap;
+/// Number of the low bits of code points ignored to determine the buckets of [`CharMap`]
+pub const BUCKET_SHIFT: u32 = 5;
+
+/// Dictionary from characters built at compile time
+///
+/// The entries are sorted by the keys so that they can be binary-searched. Large dictionaries also have the index of
+/// the buckets of 2<sup>[`BUCKET_SHIFT`]</sup> code points to narrow the range to be searched.
+pub struct CharMap<V: 'static> {
+    /// Entries sorted by the keys
+    pub entries: &'static [(char, V)],
+    /// Index in `entries` of the first entry of each bucket, followed by the number of the entries
+    ///
+    /// Empty if the entries are searched as a whole.
+    pub buckets: &'static [u16],
+}
+
+impl<V> Clone for CharMap<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for CharMap<V> {}
+
+impl<V> CharMap<V> {
+    /// Returns the value corresponding to the key.
+    pub fn get(&self, c: &char) -> Option<&'static V> {
+        let entries = if self.buckets.is_empty() {
+            self.entries
+        } else {
+            let bucket = (*c as u32 >> BUCKET_SHIFT) as usize;
+            match (self.buckets.get(bucket), self.buckets.get(bucket + 1)) {
+                (Some(&start), Some(&end)) => &self.entries[start as usize..end as usize],
+                _ => return None,
+            }
+        };
+        entries
+            .binary_search_by_key(c, |&(key, _)| key)
+            .ok()
+            .map(|index| &entries[index].1)
+    }
+
+    /// Checks whether the dictionary contains the key.
+    pub fn contains_key(&self, c: &char) -> bool {
+        self.get(c).is_some()
+    }
+}
+
+impl<V> IntoIterator for CharMap<V> {
+    type Item = &'static (char, V);
+    type IntoIter = core::slice::Iter<'static, (char, V)>;
+
+    /// Iterates over the entries in the order of the keys.
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
 
 /// Tree node struct for reverse (HFS+ to normal) dictionary
 pub struct ReverseTreeNode {
     /// A character composed of the subcomponents used for the search
     pub current: Option<char>,
     /// Subdictionary for trailing characters
-    pub next: Option<Box<AHashMap<char, ReverseTreeNode>>>,
+    pub next: Option<CharMap<ReverseTreeNode>>,
 }
 
 impl ReverseTreeNode {
     /// Create a node instance.
-    pub fn new(current: Option<char>, next: Option<Box<AHashMap<char, ReverseTreeNode>>>) -> Self {
-        return Self {
-            current: current,
-            next: next,
-        };
+    pub const fn new(current: Option<char>, next: Option<CharMap<ReverseTreeNode>>) -> Self {
+        Self { current, next }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static MAP: CharMap<u32> = CharMap {
+        entries: &[('a', 1), ('c', 3), ('\u{1F600}', 4)],
+        buckets: &[],
+    };
+
+    static BUCKETED_MAP: CharMap<u32> = CharMap {
+        entries: &[('\u{0000}', 0), ('\u{001F}', 1), ('a', 2), ('\u{0080}', 3)],
+        // 0x00-0x1F, 0x20-0x3F, 0x40-0x5F, 0x60-0x7F, 0x80-0x9F
+        buckets: &[0, 2, 2, 2, 3, 4],
+    };
+
+    #[test]
+    fn char_map_test() {
+        assert_eq!(MAP.get(&'a'), Some(&1));
+        assert_eq!(MAP.get(&'\u{1F600}'), Some(&4));
+        assert_eq!(MAP.get(&'b'), None);
+        assert!(MAP.contains_key(&'c'));
+        for (index, &(c, _)) in BUCKETED_MAP.entries.iter().enumerate() {
+            assert_eq!(BUCKETED_MAP.get(&c), Some(&(index as u32)));
+        }
+        assert_eq!(BUCKETED_MAP.get(&'\u{0020}'), None);
+        assert_eq!(BUCKETED_MAP.get(&'b'), None);
+        assert_eq!(BUCKETED_MAP.get(&'\u{00A0}'), None);
+        assert_eq!(BUCKETED_MAP.get(&'\u{1F600}'), None);
     }
 }
@@ -0,0 +1,179 @@
+//! Mapping of the characters illegal on NTFS to the Private Use Area used by Services for Macintosh (SFM)
+//!
+//! Mac SMB clients and Samba with `vfs_fruit` (`fruit:encoding = private`) store these characters as U+F001–U+F029:
+//!
+//! - U+0001–U+001F → U+F001–U+F01F
+//! - `"` `*` `:` `<` `>` `?` `\` `|` → U+F020–U+F027
+//! - ` ` and `.` at the end of a name → U+F028 and U+F029
+
+use super::{compose_from_hfs_nfd, decompose_into_hfs_nfd};
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+/// Maps a character to the Private Use Area, except for the trailing space and period
+fn encode_char(c: char) -> Option<char> {
+    let encoded = match c {
+        '\u{01}'..='\u{1F}' => 0xF000 + c as u32,
+        '"' => 0xF020,
+        '*' => 0xF021,
+        ':' => 0xF022,
+        '<' => 0xF023,
+        '>' => 0xF024,
+        '?' => 0xF025,
+        '\\' => 0xF026,
+        '|' => 0xF027,
+        _ => return None,
+    };
+    core::char::from_u32(encoded)
+}
+
+fn encode_last_char(c: char) -> Option<char> {
+    match c {
+        ' ' => Some('\u{F028}'),
+        '.' => Some('\u{F029}'),
+        _ => encode_char(c),
+    }
+}
+
+fn decode_char(c: char) -> Option<char> {
+    let decoded = match c {
+        '\u{F001}'..='\u{F01F}' => c as u32 - 0xF000,
+        '\u{F020}' => '"' as u32,
+        '\u{F021}' => '*' as u32,
+        '\u{F022}' => ':' as u32,
+        '\u{F023}' => '<' as u32,
+        '\u{F024}' => '>' as u32,
+        '\u{F025}' => '?' as u32,
+        '\u{F026}' => '\\' as u32,
+        '\u{F027}' => '|' as u32,
+        '\u{F028}' => ' ' as u32,
+        '\u{F029}' => '.' as u32,
+        _ => return None,
+    };
+    core::char::from_u32(decoded)
+}
+
+/// Maps the characters illegal on NTFS in a name to the Private Use Area in the way SFM does
+///
+/// # Arguments
+///
+/// * `name` - A file name (not a path, because `\` is mapped)
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::encode_sfm;
+/// assert_eq!(encode_sfm("a:b?. "), "a\u{F022}b\u{F025}.\u{F028}");
+/// assert_eq!(encode_sfm("README.md"), "README.md");
+/// ```
+pub fn encode_sfm(name: &str) -> Cow<'_, str> {
+    let last_index = match name.char_indices().next_back() {
+        Some((index, _)) => index,
+        None => return Cow::Borrowed(name),
+    };
+    let encode = |(index, c)| {
+        if index == last_index {
+            encode_last_char(c)
+        } else {
+            encode_char(c)
+        }
+    };
+    if name.char_indices().all(|ic| encode(ic).is_none()) {
+        return Cow::Borrowed(name);
+    }
+    Cow::Owned(
+        name.char_indices()
+            .map(|(index, c)| encode((index, c)).unwrap_or(c))
+            .collect(),
+    )
+}
+
+/// Restores the characters mapped to the Private Use Area by SFM in a name
+///
+/// # Arguments
+///
+/// * `name` - A file name read from an SMB share written by Macs
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decode_sfm;
+/// assert_eq!(decode_sfm("a\u{F022}b\u{F025}.\u{F028}"), "a:b?. ");
+/// ```
+pub fn decode_sfm(name: &str) -> Cow<'_, str> {
+    if name.chars().all(|c| decode_char(c).is_none()) {
+        return Cow::Borrowed(name);
+    }
+    Cow::Owned(name.chars().map(|c| decode_char(c).unwrap_or(c)).collect())
+}
+
+/// Applies the Unicode decomposition similar to NFD used in HFS+ and the SFM mapping, as Mac SMB clients write names
+///
+/// # Arguments
+///
+/// * `name` - A file name
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decompose_into_sfm;
+/// assert_eq!(decompose_into_sfm("caf\u{00E9}?"), "cafe\u{0301}\u{F025}");
+/// ```
+pub fn decompose_into_sfm(name: &str) -> String {
+    encode_sfm(&decompose_into_hfs_nfd(name)).into_owned()
+}
+
+/// Fully restores a commonly encoded name written by Mac SMB clients, reverting the SFM mapping and the Unicode
+/// decomposition similar to NFD used in HFS+
+///
+/// # Arguments
+///
+/// * `name` - A file name read from an SMB share written by Macs
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_from_sfm;
+/// assert_eq!(compose_from_sfm("cafe\u{0301}\u{F025}"), "caf\u{00E9}?");
+/// ```
+pub fn compose_from_sfm(name: &str) -> String {
+    compose_from_hfs_nfd(&decode_sfm(name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sfm_all_chars_test() {
+        for code in 0xF001..=0xF029 {
+            let encoded = core::char::from_u32(code).unwrap();
+            let decoded = decode_char(encoded).unwrap();
+            assert_eq!(encode_last_char(decoded), Some(encoded));
+            let name = format!("{}x", decoded);
+            assert_eq!(decode_sfm(&encode_sfm(&name)), name);
+        }
+        assert_eq!(decode_char('\u{F000}'), None);
+        assert_eq!(decode_char('\u{F02A}'), None);
+        assert_eq!(encode_char('\0'), None);
+        assert_eq!(encode_char('/'), None);
+    }
+
+    #[test]
+    fn sfm_fixed_strings_test() {
+        assert_eq!(encode_sfm(" . x. "), " . x.\u{F028}");
+        assert_eq!(encode_sfm("x."), "x\u{F029}");
+        assert_eq!(encode_sfm("\u{00E9}|\t"), "\u{00E9}\u{F027}\u{F009}");
+        assert!(matches!(encode_sfm("Pok\u{00E9}mon"), Cow::Borrowed(_)));
+        assert!(matches!(encode_sfm(""), Cow::Borrowed(_)));
+        assert!(matches!(decode_sfm("Pok\u{00E9}mon"), Cow::Borrowed(_)));
+        assert_eq!(
+            compose_from_sfm("Poke\u{0301}mon\u{F022} \u{F021}\u{F029}"),
+            "Pok\u{00E9}mon: *."
+        );
+        assert_eq!(
+            decompose_into_sfm("Pok\u{00E9}mon: *."),
+            "Poke\u{0301}mon\u{F022} \u{F021}\u{F029}"
+        );
+    }
+}
@@ -0,0 +1,582 @@
+//! Streaming `io::Read`/`io::Write` adaptors applying the HFS+ decomposition and composition
+//!
+//! UTF-8 sequences and combining sequences split across buffer boundaries are carried over to the next call.
+
+use super::composer::Composer;
+use super::decomposer::Decomposer;
+use super::profile::NormalizationProfile;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::str;
+
+/// Size of chunks read from the underlying reader at once
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Stateful conversion applied to a stream
+trait Converter {
+    /// Converts the next part of the stream
+    fn push_str(&mut self, input: &str, output: &mut String);
+    /// Flushes the characters withheld for the following part
+    fn finish(&mut self, output: &mut String);
+}
+
+impl Converter for Decomposer {
+    fn push_str(&mut self, input: &str, output: &mut String) {
+        for c in input.chars() {
+            self.push(c, output)
+                .expect("writing to a String never fails");
+        }
+    }
+
+    fn finish(&mut self, output: &mut String) {
+        Decomposer::finish(self, output).expect("writing to a String never fails");
+    }
+}
+
+impl Converter for Composer {
+    fn push_str(&mut self, input: &str, output: &mut String) {
+        for c in input.chars() {
+            self.push(c, output)
+                .expect("writing to a String never fails");
+        }
+    }
+
+    fn finish(&mut self, output: &mut String) {
+        Composer::finish(self, output).expect("writing to a String never fails");
+    }
+}
+
+/// Splits `bytes` into the longest valid UTF-8 prefix and an incomplete sequence at the end
+fn split_utf8(bytes: &[u8]) -> io::Result<(&str, &[u8])> {
+    match str::from_utf8(bytes) {
+        Ok(s) => Ok((s, &[])),
+        Err(e) if e.error_len().is_none() => {
+            let (valid, rest) = bytes.split_at(e.valid_up_to());
+            Ok((str::from_utf8(valid).expect("validated above"), rest))
+        }
+        Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+    }
+}
+
+fn incomplete_utf8_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "stream did not end at a UTF-8 character boundary",
+    )
+}
+
+struct ConvertingReader<R, C> {
+    inner: R,
+    converter: C,
+    /// Bytes read from `inner` but not converted yet
+    input: Vec<u8>,
+    /// Converted bytes not yet returned
+    output: String,
+    position: usize,
+    eof: bool,
+}
+
+impl<R: Read, C: Converter> ConvertingReader<R, C> {
+    fn new(inner: R, converter: C) -> Self {
+        Self {
+            inner,
+            converter,
+            input: Vec::new(),
+            output: String::new(),
+            position: 0,
+            eof: false,
+        }
+    }
+
+    /// Reads and converts chunks until some output is available or the end of the stream is reached
+    fn fill_output(&mut self) -> io::Result<()> {
+        self.output.clear();
+        self.position = 0;
+        while self.output.is_empty() && !self.eof {
+            let filled = self.input.len();
+            self.input.resize(filled + CHUNK_SIZE, 0);
+            let read = match self.inner.read(&mut self.input[filled..]) {
+                Ok(read) => read,
+                Err(e) => {
+                    self.input.truncate(filled);
+                    return Err(e);
+                }
+            };
+            self.input.truncate(filled + read);
+            if read == 0 {
+                // Not marked as the end so that the following reads keep failing instead of reporting a clean end
+                if !self.input.is_empty() {
+                    return Err(incomplete_utf8_error());
+                }
+                self.eof = true;
+                self.converter.finish(&mut self.output);
+                break;
+            }
+            let (valid, rest) = split_utf8(&self.input)?;
+            self.converter.push_str(valid, &mut self.output);
+            let consumed = self.input.len() - rest.len();
+            self.input.drain(..consumed);
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read, C: Converter> Read for ConvertingReader<R, C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.output.len() {
+            self.fill_output()?;
+        }
+        let available = &self.output.as_bytes()[self.position..];
+        let length = available.len().min(buf.len());
+        buf[..length].copy_from_slice(&available[..length]);
+        self.position += length;
+        Ok(length)
+    }
+}
+
+struct ConvertingWriter<W: Write, C: Converter> {
+    /// `None` only after [`ConvertingWriter::finish`] has taken it
+    inner: Option<W>,
+    converter: C,
+    /// Incomplete UTF-8 sequence at the end of the last written buffer
+    incomplete: Vec<u8>,
+    /// Converted text not passed to `inner` yet
+    output: String,
+    /// Number of the bytes at the beginning of `output` already passed to `inner`
+    written: usize,
+}
+
+impl<W: Write, C: Converter> ConvertingWriter<W, C> {
+    fn new(inner: W, converter: C) -> Self {
+        Self {
+            inner: Some(inner),
+            converter,
+            incomplete: Vec::new(),
+            output: String::new(),
+            written: 0,
+        }
+    }
+
+    fn get_ref(&self) -> &W {
+        self.inner.as_ref().expect("writer already finished")
+    }
+
+    /// Passes the converted text to `inner`
+    ///
+    /// The text not accepted by `inner` is kept until the next call.
+    fn write_output(&mut self) -> io::Result<()> {
+        let inner = self.inner.as_mut().expect("writer already finished");
+        while self.written < self.output.len() {
+            match inner.write(&self.output.as_bytes()[self.written..]) {
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write the converted text",
+                    ))
+                }
+                Ok(length) => self.written += length,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.output.clear();
+        self.written = 0;
+        Ok(())
+    }
+
+    fn finish_inner(&mut self) -> io::Result<()> {
+        if !self.incomplete.is_empty() {
+            return Err(incomplete_utf8_error());
+        }
+        self.converter.finish(&mut self.output);
+        self.write_output()?;
+        self.inner
+            .as_mut()
+            .expect("writer already finished")
+            .flush()
+    }
+
+    fn finish(mut self) -> io::Result<W> {
+        self.finish_inner()?;
+        Ok(self.inner.take().expect("writer already finished"))
+    }
+}
+
+impl<W: Write, C: Converter> Write for ConvertingWriter<W, C> {
+    /// Converts `buf` and passes the result to the underlying writer
+    ///
+    /// Once `buf` is consumed, errors of the underlying writer are reported by the next call of `write`, `flush` or
+    /// `finish`, which retries passing the text left.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_output()?;
+        if self.incomplete.is_empty() {
+            let (valid, rest) = split_utf8(buf)?;
+            self.converter.push_str(valid, &mut self.output);
+            self.incomplete.extend_from_slice(rest);
+        } else {
+            let previous_length = self.incomplete.len();
+            self.incomplete.extend_from_slice(buf);
+            let consumed = match split_utf8(&self.incomplete) {
+                Ok((valid, rest)) => {
+                    self.converter.push_str(valid, &mut self.output);
+                    self.incomplete.len() - rest.len()
+                }
+                Err(e) => {
+                    self.incomplete.truncate(previous_length);
+                    return Err(e);
+                }
+            };
+            self.incomplete.drain(..consumed);
+        }
+        // Reported by the next call as `buf` has been consumed
+        let _ = self.write_output();
+        Ok(buf.len())
+    }
+
+    /// Flushes the underlying writer
+    ///
+    /// Characters that may still be composed with the following input are withheld until more input is written or
+    /// the writer is finished.
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_output()?;
+        self.inner
+            .as_mut()
+            .expect("writer already finished")
+            .flush()
+    }
+}
+
+impl<W: Write, C: Converter> Drop for ConvertingWriter<W, C> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            // Errors can't be reported here; call `finish` to handle them.
+            let _ = self.finish_inner();
+        }
+    }
+}
+
+macro_rules! define_reader {
+    ($(#[$attr:meta])* $name:ident, $converter:ident) => {
+        $(#[$attr])*
+        pub struct $name<R: Read>(ConvertingReader<R, $converter>);
+
+        impl<R: Read> $name<R> {
+            /// Wraps a reader providing UTF-8 text.
+            pub fn new(inner: R) -> Self {
+                Self::with_profile(inner, NormalizationProfile::HfsPlus)
+            }
+
+            /// Wraps a reader providing UTF-8 text, converting it according to `profile`.
+            pub fn with_profile(inner: R, profile: NormalizationProfile) -> Self {
+                Self(ConvertingReader::new(inner, $converter::with_profile(profile)))
+            }
+
+            /// Gets a reference to the underlying reader.
+            pub fn get_ref(&self) -> &R {
+                &self.0.inner
+            }
+
+            /// Unwraps this reader, discarding the data read from the underlying reader but not converted yet.
+            pub fn into_inner(self) -> R {
+                self.0.inner
+            }
+        }
+
+        impl<R: Read> Read for $name<R> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.0.read(buf)
+            }
+        }
+
+        impl<R: Read> fmt::Debug for $name<R> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name)).finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+macro_rules! define_writer {
+    ($(#[$attr:meta])* $name:ident, $converter:ident) => {
+        $(#[$attr])*
+        pub struct $name<W: Write>(ConvertingWriter<W, $converter>);
+
+        impl<W: Write> $name<W> {
+            /// Wraps a writer accepting UTF-8 text.
+            pub fn new(inner: W) -> Self {
+                Self::with_profile(inner, NormalizationProfile::HfsPlus)
+            }
+
+            /// Wraps a writer accepting UTF-8 text, converting it according to `profile`.
+            pub fn with_profile(inner: W, profile: NormalizationProfile) -> Self {
+                Self(ConvertingWriter::new(inner, $converter::with_profile(profile)))
+            }
+
+            /// Gets a reference to the underlying writer.
+            pub fn get_ref(&self) -> &W {
+                self.0.get_ref()
+            }
+
+            /// Writes all the withheld characters, flushes the underlying writer and returns it.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the written data ends in the middle of a UTF-8 sequence or the underlying writer
+            /// fails.
+            pub fn finish(self) -> io::Result<W> {
+                self.0.finish()
+            }
+        }
+
+        impl<W: Write> Write for $name<W> {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.flush()
+            }
+        }
+
+        impl<W: Write> fmt::Debug for $name<W> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name)).finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+define_reader!(
+    /// A reader applying the HFS+ decomposition to UTF-8 text read from another reader
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hfs_nfd::HfsNfdReader;
+    /// use std::io::Read;
+    /// let mut reader = HfsNfdReader::new("Pok\u{00E9}mon".as_bytes());
+    /// let mut decomposed = String::new();
+    /// reader.read_to_string(&mut decomposed).unwrap();
+    /// assert_eq!(decomposed, "Poke\u{0301}mon");
+    /// ```
+    HfsNfdReader,
+    Decomposer
+);
+
+define_reader!(
+    /// A reader restoring UTF-8 text read from another reader from the HFS+ decomposition
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hfs_nfd::HfsNfcReader;
+    /// use std::io::Read;
+    /// let mut reader = HfsNfcReader::new("Poke\u{0301}mon".as_bytes());
+    /// let mut composed = String::new();
+    /// reader.read_to_string(&mut composed).unwrap();
+    /// assert_eq!(composed, "Pok\u{00E9}mon");
+    /// ```
+    HfsNfcReader,
+    Composer
+);
+
+define_writer!(
+    /// A writer applying the HFS+ decomposition to UTF-8 text before passing it to another writer
+    ///
+    /// Call [`HfsNfdWriter::finish`] to detect errors; dropping the writer finishes it ignoring errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hfs_nfd::HfsNfdWriter;
+    /// use std::io::Write;
+    /// let mut writer = HfsNfdWriter::new(Vec::new());
+    /// writer.write_all("Pok\u{00E9}mon".as_bytes()).unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "Poke\u{0301}mon".as_bytes());
+    /// ```
+    HfsNfdWriter,
+    Decomposer
+);
+
+define_writer!(
+    /// A writer restoring UTF-8 text from the HFS+ decomposition before passing it to another writer
+    ///
+    /// The last characters may be withheld until more text is written, as they may be composed with it. Call
+    /// [`HfsNfcWriter::finish`] to write them and detect errors; dropping the writer finishes it ignoring errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hfs_nfd::HfsNfcWriter;
+    /// use std::io::Write;
+    /// let mut writer = HfsNfcWriter::new(Vec::new());
+    /// writer.write_all("Poke".as_bytes()).unwrap();
+    /// writer.write_all("\u{0301}mon".as_bytes()).unwrap();
+    /// assert_eq!(writer.finish().unwrap(), "Pok\u{00E9}mon".as_bytes());
+    /// ```
+    HfsNfcWriter,
+    Composer
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Reader returning at most `limit` bytes at once to split sequences across calls
+    struct Trickle<'a> {
+        data: &'a [u8],
+        limit: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let length = self.data.len().min(self.limit).min(buf.len());
+            buf[..length].copy_from_slice(&self.data[..length]);
+            self.data = &self.data[length..];
+            Ok(length)
+        }
+    }
+
+    static EXAMINEE: &[(&str, &str)] = &[
+        ("Pok\u{00E9}mon", "Poke\u{0301}mon"),
+        (
+            "\u{1F8A}\u{30DD}",
+            "\u{0391}\u{0345}\u{0313}\u{0300}\u{30DB}\u{309A}",
+        ),
+        (
+            "\u{D55C}\u{C8FC}\u{B9AC}",
+            "\u{1112}\u{1161}\u{11AB}\u{110C}\u{116E}\u{1105}\u{1175}",
+        ),
+    ];
+
+    #[test]
+    fn reader_split_test() {
+        for (composed, decomposed) in EXAMINEE {
+            for limit in 1..=4 {
+                let mut s = String::new();
+                HfsNfdReader::new(Trickle {
+                    data: composed.as_bytes(),
+                    limit,
+                })
+                .read_to_string(&mut s)
+                .unwrap();
+                assert_eq!(&s, decomposed);
+                s.clear();
+                HfsNfcReader::new(Trickle {
+                    data: decomposed.as_bytes(),
+                    limit,
+                })
+                .read_to_string(&mut s)
+                .unwrap();
+                assert_eq!(&s, composed);
+            }
+        }
+    }
+
+    #[test]
+    fn writer_split_test() {
+        for (composed, decomposed) in EXAMINEE {
+            for limit in 1..=4 {
+                let mut writer = HfsNfdWriter::new(Vec::new());
+                for chunk in composed.as_bytes().chunks(limit) {
+                    writer.write_all(chunk).unwrap();
+                }
+                assert_eq!(writer.finish().unwrap(), decomposed.as_bytes());
+                let mut writer = HfsNfcWriter::new(Vec::new());
+                for chunk in decomposed.as_bytes().chunks(limit) {
+                    writer.write_all(chunk).unwrap();
+                }
+                assert_eq!(writer.finish().unwrap(), composed.as_bytes());
+            }
+        }
+    }
+
+    /// Writer failing every other call to check the text is retained across errors
+    struct Flaky {
+        data: Vec<u8>,
+        fail: bool,
+    }
+
+    impl Write for Flaky {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.fail = !self.fail;
+            if self.fail {
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            // Accepts one byte at most to split sequences
+            self.data.extend_from_slice(&buf[..buf.len().min(1)]);
+            Ok(buf.len().min(1))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writer_retry_test() {
+        for (composed, decomposed) in EXAMINEE {
+            let mut writer = HfsNfcWriter::new(Flaky {
+                data: Vec::new(),
+                fail: false,
+            });
+            for chunk in decomposed.as_bytes().chunks(2) {
+                // Retries the same chunk as callers do after `WouldBlock`
+                while writer.write(chunk).is_err() {}
+            }
+            while writer.flush().is_err() {}
+            let inner = loop {
+                match writer.0.finish_inner() {
+                    Ok(()) => break writer.0.inner.take().unwrap(),
+                    Err(e) => assert_eq!(e.kind(), io::ErrorKind::WouldBlock),
+                }
+            };
+            assert_eq!(inner.data, composed.as_bytes());
+        }
+    }
+
+    #[test]
+    fn profile_test() {
+        let profile = NormalizationProfile::Unicode32;
+        let mut output = String::new();
+        HfsNfdReader::with_profile("\u{01F8}\u{03AC}".as_bytes(), profile)
+            .read_to_string(&mut output)
+            .unwrap();
+        assert_eq!(output, "N\u{0300}\u{03B1}\u{0301}");
+        output.clear();
+        HfsNfcReader::with_profile("N\u{0300}".as_bytes(), profile)
+            .read_to_string(&mut output)
+            .unwrap();
+        assert_eq!(output, "\u{01F8}");
+
+        let mut writer = HfsNfdWriter::with_profile(Vec::new(), profile);
+        writer.write_all("\u{01F8}".as_bytes()).unwrap();
+        assert_eq!(writer.finish().unwrap(), "N\u{0300}".as_bytes());
+        let mut writer = HfsNfcWriter::with_profile(Vec::new(), profile);
+        writer.write_all("N\u{0300}".as_bytes()).unwrap();
+        assert_eq!(writer.finish().unwrap(), "\u{01F8}".as_bytes());
+    }
+
+    #[test]
+    fn invalid_utf8_test() {
+        let mut s = String::new();
+        let error = HfsNfdReader::new(&b"caf\xC3"[..])
+            .read_to_string(&mut s)
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let mut reader = HfsNfdReader::new(&b"caf\xC3"[..]);
+        let mut buf = [0; 16];
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        for _ in 0..2 {
+            assert_eq!(
+                reader.read(&mut buf).unwrap_err().kind(),
+                io::ErrorKind::InvalidData
+            );
+        }
+        let mut writer = HfsNfcWriter::new(Vec::new());
+        assert!(writer.write_all(b"caf\xFF").is_err());
+        let mut writer = HfsNfcWriter::new(Vec::new());
+        writer.write_all(b"caf\xC3").unwrap();
+        assert!(writer.finish().is_err());
+    }
+}
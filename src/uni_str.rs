@@ -0,0 +1,266 @@
+//! `HFSUniStr255`, the structure of names stored in HFS+ catalog records
+
+use super::compare::fast_unicode_compare;
+use super::error::Error;
+use super::posix::{hfs_name_to_posix, posix_name_to_hfs};
+use super::utf16::{compose_from_hfs_nfd_utf16, decompose_into_hfs_nfd_utf16, decompose_utf16};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::char::decode_utf16;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+
+/// A name decomposed in the HFS+ way that fits in `HFSUniStr255`
+///
+/// On disk, it is laid out as the big-endian 16-bit length followed by the big-endian UTF-16 code units.
+///
+/// It does not implement [`Ord`], as the order of the code units is not that of the catalog B-tree; use
+/// [`HfsUniStr255::catalog_cmp`] to sort catalog keys.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::HfsUniStr255;
+/// let name = HfsUniStr255::new("Pok\u{00E9}mon").unwrap();
+/// assert_eq!(name.len(), 8);
+/// assert_eq!(name.to_composed_string(), "Pok\u{00E9}mon");
+/// let bytes = name.to_be_bytes();
+/// assert_eq!(&bytes[..4], &[0x00, 0x08, 0x00, b'P']);
+/// assert_eq!(HfsUniStr255::from_be_bytes(&bytes).unwrap(), name);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct HfsUniStr255 {
+    units: Vec<u16>,
+}
+
+impl HfsUniStr255 {
+    /// The maximum number of UTF-16 code units
+    pub const MAX_LENGTH: usize = 255;
+
+    /// Decomposes a name and stores it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NameTooLong`] if the decomposed name exceeds [`HfsUniStr255::MAX_LENGTH`] code units.
+    pub fn new(name: &str) -> Result<Self, Error> {
+        Self::from_decomposed_units(decompose_into_hfs_nfd_utf16(name))
+    }
+
+    /// Decomposes a UTF-16 name and stores it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NameTooLong`] if the decomposed name exceeds [`HfsUniStr255::MAX_LENGTH`] code units.
+    pub fn from_utf16(name: &[u16]) -> Result<Self, Error> {
+        Self::from_decomposed_units(decompose_utf16(name))
+    }
+
+    /// Converts a POSIX path component into the catalog name, swapping `:` and `/`, and stores it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NameTooLong`] if the decomposed name exceeds [`HfsUniStr255::MAX_LENGTH`] code units.
+    pub fn from_posix_name(name: &str) -> Result<Self, Error> {
+        Self::new(&posix_name_to_hfs(name))
+    }
+
+    fn from_decomposed_units(units: Vec<u16>) -> Result<Self, Error> {
+        if units.len() > Self::MAX_LENGTH {
+            return Err(Error::NameTooLong(units.len()));
+        }
+        Ok(Self { units })
+    }
+
+    /// Reads the on-disk layout at the beginning of `bytes`.
+    ///
+    /// The code units are stored as they are, without being decomposed again. The bytes after the structure are
+    /// ignored; use [`HfsUniStr255::serialized_len`] to find where it ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TruncatedHfsUniStr255`] if `bytes` is too short, or [`Error::NameTooLong`] if the length
+    /// field exceeds [`HfsUniStr255::MAX_LENGTH`].
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let length = match bytes {
+            [high, low, ..] => u16::from_be_bytes([*high, *low]) as usize,
+            _ => {
+                return Err(Error::TruncatedHfsUniStr255 {
+                    expected: 2,
+                    actual: bytes.len(),
+                })
+            }
+        };
+        if length > Self::MAX_LENGTH {
+            return Err(Error::NameTooLong(length));
+        }
+        let expected = 2 + length * 2;
+        if bytes.len() < expected {
+            return Err(Error::TruncatedHfsUniStr255 {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+        let units = bytes[2..expected]
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        Ok(Self { units })
+    }
+
+    /// Appends the on-disk layout to `output`.
+    pub fn write_be_bytes(&self, output: &mut Vec<u8>) {
+        output.reserve(self.serialized_len());
+        output.extend_from_slice(&(self.units.len() as u16).to_be_bytes());
+        for unit in &self.units {
+            output.extend_from_slice(&unit.to_be_bytes());
+        }
+    }
+
+    /// Returns the on-disk layout.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.write_be_bytes(&mut result);
+        result
+    }
+
+    /// Returns the number of bytes of the on-disk layout.
+    pub fn serialized_len(&self) -> usize {
+        2 + self.units.len() * 2
+    }
+
+    /// Returns the number of UTF-16 code units.
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Returns `true` if the name is empty.
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Returns the decomposed UTF-16 code units.
+    pub fn as_utf16(&self) -> &[u16] {
+        &self.units
+    }
+
+    /// Compares the names in the order of the catalog B-tree of case-insensitive HFS+ volumes.
+    ///
+    /// See [`fast_unicode_compare`] for the details. Names differing only in case or ignorable code points are
+    /// [`Ordering::Equal`] though they are not `==`.
+    pub fn catalog_cmp(&self, other: &Self) -> Ordering {
+        fast_unicode_compare(&self.units, &other.units)
+    }
+
+    /// Restores a commonly encoded string, replacing unpaired surrogates with U+FFFD.
+    pub fn to_composed_string(&self) -> String {
+        compose_from_hfs_nfd_utf16(&self.units)
+    }
+
+    /// Returns the decomposed POSIX path component presented by macOS, swapping `/` and `:` and replacing unpaired
+    /// surrogates with U+FFFD.
+    pub fn to_posix_name(&self) -> String {
+        hfs_name_to_posix(&self.to_string())
+    }
+}
+
+/// Formats the decomposed name, replacing unpaired surrogates with U+FFFD
+impl fmt::Display for HfsUniStr255 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in decode_utf16(self.units.iter().copied()) {
+            f.write_char(result.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for HfsUniStr255 {
+    type Error = Error;
+
+    fn try_from(name: &str) -> Result<Self, Error> {
+        Self::new(name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn length_limit_test() {
+        let ascii = "a".repeat(255);
+        assert_eq!(HfsUniStr255::new(&ascii).unwrap().len(), 255);
+        assert_eq!(
+            HfsUniStr255::new(&(ascii + "b")),
+            Err(Error::NameTooLong(256))
+        );
+        // 200 characters in NFC, but 400 code units after the decomposition
+        let accented = "\u{00E9}".repeat(200);
+        assert_eq!(HfsUniStr255::new(&accented), Err(Error::NameTooLong(400)));
+        let hangul = "\u{D55C}".repeat(85);
+        assert_eq!(HfsUniStr255::new(&hangul).unwrap().len(), 255);
+        assert!(HfsUniStr255::new(&(hangul + "\u{D55C}")).is_err());
+    }
+
+    #[test]
+    fn serialization_test() {
+        let name = HfsUniStr255::new("\u{D55C} \u{00E9}\u{1F600}").unwrap();
+        let bytes = name.to_be_bytes();
+        assert_eq!(bytes.len(), name.serialized_len());
+        assert_eq!(
+            bytes,
+            vec![
+                0x00, 0x08, 0x11, 0x12, 0x11, 0x61, 0x11, 0xAB, 0x00, 0x20, 0x00, 0x65, 0x03, 0x01,
+                0xD8, 0x3D, 0xDE, 0x00
+            ]
+        );
+        let mut with_trailing = bytes.clone();
+        with_trailing.extend_from_slice(&[0xFF, 0xFF]);
+        assert_eq!(HfsUniStr255::from_be_bytes(&with_trailing).unwrap(), name);
+        assert_eq!(
+            name.to_string(),
+            "\u{1112}\u{1161}\u{11AB} e\u{0301}\u{1F600}"
+        );
+        assert_eq!(name.to_composed_string(), "\u{D55C} \u{00E9}\u{1F600}");
+    }
+
+    #[test]
+    fn catalog_order_test() {
+        let names = ["b", "A", "\u{00E9}", "a\u{200D}", "Z"]
+            .iter()
+            .map(|name| HfsUniStr255::new(name).unwrap())
+            .collect::<Vec<_>>();
+        let mut sorted = names.clone();
+        sorted.sort_by(HfsUniStr255::catalog_cmp);
+        let sorted = sorted
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        // Sorted by the code units, "Z" would precede "b" and "a\u{200D}"
+        assert_eq!(sorted, ["A", "a\u{200D}", "b", "e\u{0301}", "Z"]);
+        assert_eq!(names[1].catalog_cmp(&names[3]), Ordering::Equal);
+        assert_ne!(names[1], names[3]);
+    }
+
+    #[test]
+    fn invalid_bytes_test() {
+        assert_eq!(
+            HfsUniStr255::from_be_bytes(&[0x00]),
+            Err(Error::TruncatedHfsUniStr255 {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            HfsUniStr255::from_be_bytes(&[0x00, 0x02, 0x00, 0x41, 0x00]),
+            Err(Error::TruncatedHfsUniStr255 {
+                expected: 6,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            HfsUniStr255::from_be_bytes(&[0x01, 0x00]),
+            Err(Error::NameTooLong(256))
+        );
+    }
+}
@@ -0,0 +1,510 @@
+//! Conversion of UTF-16 names as stored in HFS+ catalog records (`HFSUniStr255`)
+//!
+//! Unpaired surrogates, which may be stored in HFS+ names, are passed through as is when the output is UTF-16.
+
+use super::composer::Composer;
+use super::decomposer::Decomposer;
+use super::error::Error;
+use super::profile::NormalizationProfile;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::char::decode_utf16;
+use core::fmt::{self, Write};
+
+/// Destination of converted UTF-16 names
+trait Utf16Output: Write {
+    fn write_unpaired_surrogate(&mut self, unit: u16);
+}
+
+/// Native-endian UTF-16 output
+struct Utf16Writer<'a>(&'a mut Vec<u16>);
+
+impl Write for Utf16Writer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.extend(s.encode_utf16());
+        Ok(())
+    }
+}
+
+impl Utf16Output for Utf16Writer<'_> {
+    fn write_unpaired_surrogate(&mut self, unit: u16) {
+        self.0.push(unit);
+    }
+}
+
+/// Big-endian UTF-16 output
+struct Utf16BeWriter<'a>(&'a mut Vec<u8>);
+
+impl Write for Utf16BeWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for unit in s.encode_utf16() {
+            self.write_unpaired_surrogate(unit);
+        }
+        Ok(())
+    }
+}
+
+impl Utf16Output for Utf16BeWriter<'_> {
+    fn write_unpaired_surrogate(&mut self, unit: u16) {
+        self.0.extend_from_slice(&unit.to_be_bytes());
+    }
+}
+
+/// UTF-8 output replacing unpaired surrogates with U+FFFD
+impl Utf16Output for String {
+    fn write_unpaired_surrogate(&mut self, _unit: u16) {
+        self.push(char::REPLACEMENT_CHARACTER);
+    }
+}
+
+fn decompose_units<I: IntoIterator<Item = u16>, O: Utf16Output>(
+    input: I,
+    output: &mut O,
+    profile: NormalizationProfile,
+) {
+    let mut decomposer = Decomposer::with_profile(profile);
+    for result in decode_utf16(input) {
+        match result {
+            Ok(c) => decomposer.push(c, output),
+            Err(e) => decomposer.finish(output).map(|_| {
+                output.write_unpaired_surrogate(e.unpaired_surrogate());
+            }),
+        }
+        .expect("writing to a buffer never fails");
+    }
+    decomposer
+        .finish(output)
+        .expect("writing to a buffer never fails");
+}
+
+fn compose_units<I: IntoIterator<Item = u16>, O: Utf16Output>(
+    input: I,
+    output: &mut O,
+    profile: NormalizationProfile,
+) {
+    let mut composer = Composer::with_profile(profile);
+    for result in decode_utf16(input) {
+        match result {
+            Ok(c) => composer.push(c, output),
+            Err(e) => composer.finish(output).map(|_| {
+                output.write_unpaired_surrogate(e.unpaired_surrogate());
+            }),
+        }
+        .expect("writing to a buffer never fails");
+    }
+    composer
+        .finish(output)
+        .expect("writing to a buffer never fails");
+}
+
+/// Reads big-endian UTF-16 code units
+fn be_units(input: &[u8]) -> Result<impl Iterator<Item = u16> + '_, Error> {
+    let pairs = input.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(Error::OddUtf16Length(input.len()));
+    }
+    Ok(pairs.map(|pair| u16::from_be_bytes([pair[0], pair[1]])))
+}
+
+/// Applies the Unicode decomposition similar to NFD used in HFS+ to a UTF-16 string
+///
+/// # Arguments
+///
+/// * `input` - UTF-16 code units to be decomposed
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decompose_utf16;
+/// assert_eq!(decompose_utf16(&[0x0063, 0x00E9]), vec![0x0063, 0x0065, 0x0301]);
+/// ```
+pub fn decompose_utf16(input: &[u16]) -> Vec<u16> {
+    decompose_utf16_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Applies the Unicode decomposition according to `profile` to a UTF-16 string
+///
+/// # Arguments
+///
+/// * `input` - UTF-16 code units to be decomposed
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_utf16_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     decompose_utf16_with_profile(&[0x01F8], NormalizationProfile::Unicode32),
+///     vec![0x004E, 0x0300]
+/// );
+/// ```
+pub fn decompose_utf16_with_profile(input: &[u16], profile: NormalizationProfile) -> Vec<u16> {
+    let mut result = Vec::with_capacity(input.len());
+    decompose_units(
+        input.iter().copied(),
+        &mut Utf16Writer(&mut result),
+        profile,
+    );
+    result
+}
+
+/// Restores a commonly encoded UTF-16 string from the Unicode decomposition similar to NFD used in HFS+
+///
+/// # Arguments
+///
+/// * `input` - UTF-16 code units to be restored from
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_utf16;
+/// assert_eq!(compose_utf16(&[0x0063, 0x0065, 0x0301]), vec![0x0063, 0x00E9]);
+/// ```
+pub fn compose_utf16(input: &[u16]) -> Vec<u16> {
+    compose_utf16_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Restores a commonly encoded UTF-16 string from the Unicode decomposition according to `profile`
+///
+/// # Arguments
+///
+/// * `input` - UTF-16 code units to be restored from
+/// * `profile` - The rules of the decomposition applied to `input`
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_utf16_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     compose_utf16_with_profile(&[0x004E, 0x0300], NormalizationProfile::Unicode32),
+///     vec![0x01F8]
+/// );
+/// ```
+pub fn compose_utf16_with_profile(input: &[u16], profile: NormalizationProfile) -> Vec<u16> {
+    let mut result = Vec::with_capacity(input.len());
+    compose_units(
+        input.iter().copied(),
+        &mut Utf16Writer(&mut result),
+        profile,
+    );
+    result
+}
+
+/// Applies the Unicode decomposition similar to NFD used in HFS+ to a big-endian UTF-16 byte sequence, the encoding
+/// of names on HFS+ volumes
+///
+/// # Arguments
+///
+/// * `input` - Big-endian UTF-16 bytes to be decomposed
+///
+/// # Errors
+///
+/// Returns [`Error::OddUtf16Length`] if `input` has an odd length.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decompose_utf16_be;
+/// assert_eq!(decompose_utf16_be(&[0x00, 0xE9]).unwrap(), vec![0x00, 0x65, 0x03, 0x01]);
+/// ```
+pub fn decompose_utf16_be(input: &[u8]) -> Result<Vec<u8>, Error> {
+    decompose_utf16_be_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Applies the Unicode decomposition according to `profile` to a big-endian UTF-16 byte sequence
+///
+/// # Arguments
+///
+/// * `input` - Big-endian UTF-16 bytes to be decomposed
+/// * `profile` - The rules of the decomposition
+///
+/// # Errors
+///
+/// Returns [`Error::OddUtf16Length`] if `input` has an odd length.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_utf16_be_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     decompose_utf16_be_with_profile(&[0x01, 0xF8], NormalizationProfile::Unicode32).unwrap(),
+///     vec![0x00, 0x4E, 0x03, 0x00]
+/// );
+/// ```
+pub fn decompose_utf16_be_with_profile(
+    input: &[u8],
+    profile: NormalizationProfile,
+) -> Result<Vec<u8>, Error> {
+    let mut result = Vec::with_capacity(input.len());
+    decompose_units(be_units(input)?, &mut Utf16BeWriter(&mut result), profile);
+    Ok(result)
+}
+
+/// Restores a commonly encoded big-endian UTF-16 byte sequence from the Unicode decomposition similar to NFD used in
+/// HFS+
+///
+/// # Arguments
+///
+/// * `input` - Big-endian UTF-16 bytes to be restored from
+///
+/// # Errors
+///
+/// Returns [`Error::OddUtf16Length`] if `input` has an odd length.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_utf16_be;
+/// assert_eq!(compose_utf16_be(&[0x00, 0x65, 0x03, 0x01]).unwrap(), vec![0x00, 0xE9]);
+/// ```
+pub fn compose_utf16_be(input: &[u8]) -> Result<Vec<u8>, Error> {
+    compose_utf16_be_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Restores a commonly encoded big-endian UTF-16 byte sequence from the Unicode decomposition according to
+/// `profile`
+///
+/// # Arguments
+///
+/// * `input` - Big-endian UTF-16 bytes to be restored from
+/// * `profile` - The rules of the decomposition applied to `input`
+///
+/// # Errors
+///
+/// Returns [`Error::OddUtf16Length`] if `input` has an odd length.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_utf16_be_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     compose_utf16_be_with_profile(&[0x00, 0x4E, 0x03, 0x00], NormalizationProfile::Unicode32).unwrap(),
+///     vec![0x01, 0xF8]
+/// );
+/// ```
+pub fn compose_utf16_be_with_profile(
+    input: &[u8],
+    profile: NormalizationProfile,
+) -> Result<Vec<u8>, Error> {
+    let mut result = Vec::with_capacity(input.len());
+    compose_units(be_units(input)?, &mut Utf16BeWriter(&mut result), profile);
+    Ok(result)
+}
+
+/// Applies the Unicode decomposition similar to NFD used in HFS+ and encodes the result in UTF-16
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::decompose_into_hfs_nfd_utf16;
+/// assert_eq!(decompose_into_hfs_nfd_utf16("c\u{00E9}"), vec![0x0063, 0x0065, 0x0301]);
+/// ```
+pub fn decompose_into_hfs_nfd_utf16(input: &str) -> Vec<u16> {
+    decompose_into_hfs_nfd_utf16_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Applies the Unicode decomposition according to `profile` and encodes the result in UTF-16
+///
+/// # Arguments
+///
+/// * `input` - A string to be decomposed
+/// * `profile` - The rules of the decomposition
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{decompose_into_hfs_nfd_utf16_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     decompose_into_hfs_nfd_utf16_with_profile("\u{03AC}", NormalizationProfile::HfsPlus),
+///     vec![0x03B1, 0x030D]
+/// );
+/// ```
+pub fn decompose_into_hfs_nfd_utf16_with_profile(
+    input: &str,
+    profile: NormalizationProfile,
+) -> Vec<u16> {
+    let mut result = Vec::with_capacity(input.len());
+    decompose_units(input.encode_utf16(), &mut Utf16Writer(&mut result), profile);
+    result
+}
+
+/// Restores a commonly encoded string from a UTF-16 one applied the Unicode decomposition similar to NFD used in HFS+
+///
+/// Unpaired surrogates are replaced with U+FFFD.
+///
+/// # Arguments
+///
+/// * `input` - UTF-16 code units to be restored from
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_from_hfs_nfd_utf16;
+/// assert_eq!(compose_from_hfs_nfd_utf16(&[0x0063, 0x0065, 0x0301]), "c\u{00E9}");
+/// ```
+pub fn compose_from_hfs_nfd_utf16(input: &[u16]) -> String {
+    compose_from_hfs_nfd_utf16_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Restores a commonly encoded string from a UTF-16 one applied the Unicode decomposition according to `profile`
+///
+/// Unpaired surrogates are replaced with U+FFFD.
+///
+/// # Arguments
+///
+/// * `input` - UTF-16 code units to be restored from
+/// * `profile` - The rules of the decomposition applied to `input`
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_from_hfs_nfd_utf16_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     compose_from_hfs_nfd_utf16_with_profile(&[0x03B1, 0x030D], NormalizationProfile::HfsPlus),
+///     "\u{03AC}"
+/// );
+/// ```
+pub fn compose_from_hfs_nfd_utf16_with_profile(
+    input: &[u16],
+    profile: NormalizationProfile,
+) -> String {
+    let mut result = String::with_capacity(input.len());
+    compose_units(input.iter().copied(), &mut result, profile);
+    result
+}
+
+/// Restores a commonly encoded string from a big-endian UTF-16 byte sequence applied the Unicode decomposition
+/// similar to NFD used in HFS+, e.g. the name in a catalog record
+///
+/// Unpaired surrogates are replaced with U+FFFD.
+///
+/// # Arguments
+///
+/// * `input` - Big-endian UTF-16 bytes to be restored from
+///
+/// # Errors
+///
+/// Returns [`Error::OddUtf16Length`] if `input` has an odd length.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::compose_from_hfs_nfd_utf16_be;
+/// assert_eq!(compose_from_hfs_nfd_utf16_be(&[0x00, 0x65, 0x03, 0x01]).unwrap(), "\u{00E9}");
+/// ```
+pub fn compose_from_hfs_nfd_utf16_be(input: &[u8]) -> Result<String, Error> {
+    compose_from_hfs_nfd_utf16_be_with_profile(input, NormalizationProfile::HfsPlus)
+}
+
+/// Restores a commonly encoded string from a big-endian UTF-16 byte sequence applied the Unicode decomposition
+/// according to `profile`
+///
+/// Unpaired surrogates are replaced with U+FFFD.
+///
+/// # Arguments
+///
+/// * `input` - Big-endian UTF-16 bytes to be restored from
+/// * `profile` - The rules of the decomposition applied to `input`
+///
+/// # Errors
+///
+/// Returns [`Error::OddUtf16Length`] if `input` has an odd length.
+///
+/// # Examples
+///
+/// ```
+/// use hfs_nfd::{compose_from_hfs_nfd_utf16_be_with_profile, NormalizationProfile};
+/// assert_eq!(
+///     compose_from_hfs_nfd_utf16_be_with_profile(&[0x03, 0xB1, 0x03, 0x0D], NormalizationProfile::HfsPlus)
+///         .unwrap(),
+///     "\u{03AC}"
+/// );
+/// ```
+pub fn compose_from_hfs_nfd_utf16_be_with_profile(
+    input: &[u8],
+    profile: NormalizationProfile,
+) -> Result<String, Error> {
+    let mut result = String::with_capacity(input.len());
+    compose_units(be_units(input)?, &mut result, profile);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{compose_from_hfs_nfd, decompose_into_hfs_nfd};
+
+    static EXAMINEE: &[(&str, &str)] = &[
+        ("Pok\u{00E9}mon", "Poke\u{0301}mon"),
+        (
+            "\u{1F8A}\u{30DD}",
+            "\u{0391}\u{0345}\u{0313}\u{0300}\u{30DB}\u{309A}",
+        ),
+        (
+            "\u{D55C}\u{AE00}",
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}",
+        ),
+        ("\u{1F600}\u{00E9}", "\u{1F600}e\u{0301}"),
+    ];
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    fn utf16_be(s: &str) -> Vec<u8> {
+        s.encode_utf16().flat_map(u16::to_be_bytes).collect()
+    }
+
+    #[test]
+    fn utf16_fixed_strings_test() {
+        for (composed, decomposed) in EXAMINEE {
+            assert_eq!(decompose_utf16(&utf16(composed)), utf16(decomposed));
+            assert_eq!(compose_utf16(&utf16(decomposed)), utf16(composed));
+            assert_eq!(decompose_into_hfs_nfd_utf16(composed), utf16(decomposed));
+            assert_eq!(&compose_from_hfs_nfd_utf16(&utf16(decomposed)), composed);
+            assert_eq!(
+                decompose_utf16_be(&utf16_be(composed)).unwrap(),
+                utf16_be(decomposed)
+            );
+            assert_eq!(
+                compose_utf16_be(&utf16_be(decomposed)).unwrap(),
+                utf16_be(composed)
+            );
+            assert_eq!(
+                &compose_from_hfs_nfd_utf16_be(&utf16_be(decomposed)).unwrap(),
+                composed
+            );
+            assert_eq!(&decompose_into_hfs_nfd(composed), decomposed);
+            assert_eq!(&compose_from_hfs_nfd(decomposed), composed);
+        }
+    }
+
+    #[test]
+    fn unpaired_surrogate_test() {
+        assert_eq!(
+            decompose_utf16(&[0x00E9, 0xD800, 0x00E9]),
+            vec![0x0065, 0x0301, 0xD800, 0x0065, 0x0301]
+        );
+        assert_eq!(
+            compose_utf16(&[0x0065, 0xDC00, 0x0301]),
+            vec![0x0065, 0xDC00, 0x0301]
+        );
+        assert_eq!(
+            compose_from_hfs_nfd_utf16(&[0x0065, 0x0301, 0xDC00]),
+            "\u{00E9}\u{FFFD}"
+        );
+    }
+
+    #[test]
+    fn odd_length_test() {
+        assert_eq!(
+            decompose_utf16_be(&[0x00, 0x65, 0x03]),
+            Err(Error::OddUtf16Length(3))
+        );
+        assert_eq!(compose_utf16_be(&[0x00]), Err(Error::OddUtf16Length(1)));
+    }
+}